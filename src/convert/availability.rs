@@ -0,0 +1,218 @@
+use crate::types::Species;
+
+// The species each game can hold, with their DLC and what HOME can bring in.
+// Forms are not tracked, so a species counts as available if any of its forms
+// is.
+
+#[rustfmt::skip]
+pub(super) const SWSH: &[Species] = &[
+    Species::Bulbasaur, Species::Ivysaur, Species::Venusaur, Species::Charmander, Species::Charmeleon, Species::Charizard, Species::Squirtle, Species::Wartortle,
+    Species::Blastoise, Species::Caterpie, Species::Metapod, Species::Butterfree, Species::Pikachu, Species::Raichu, Species::Sandshrew, Species::Sandslash,
+    Species::NidoranF, Species::Nidorina, Species::Nidoqueen, Species::NidoranM, Species::Nidorino, Species::Nidoking, Species::Clefairy, Species::Clefable,
+    Species::Vulpix, Species::Ninetales, Species::Jigglypuff, Species::Wigglytuff, Species::Zubat, Species::Golbat, Species::Oddish, Species::Gloom,
+    Species::Vileplume, Species::Diglett, Species::Dugtrio, Species::Meowth, Species::Persian, Species::Psyduck, Species::Golduck, Species::Growlithe,
+    Species::Arcanine, Species::Poliwag, Species::Poliwhirl, Species::Poliwrath, Species::Abra, Species::Kadabra, Species::Alakazam, Species::Machop,
+    Species::Machoke, Species::Machamp, Species::Tentacool, Species::Tentacruel, Species::Ponyta, Species::Rapidash, Species::Slowpoke, Species::Slowbro,
+    Species::Magnemite, Species::Magneton, Species::FarfetchD, Species::Shellder, Species::Cloyster, Species::Gastly, Species::Haunter, Species::Gengar,
+    Species::Onix, Species::Krabby, Species::Kingler, Species::Exeggcute, Species::Exeggutor, Species::Cubone, Species::Marowak, Species::Hitmonlee,
+    Species::Hitmonchan, Species::Lickitung, Species::Koffing, Species::Weezing, Species::Rhyhorn, Species::Rhydon, Species::Chansey, Species::Tangela,
+    Species::Kangaskhan, Species::Horsea, Species::Seadra, Species::Goldeen, Species::Seaking, Species::Staryu, Species::Starmie, Species::MrMime,
+    Species::Scyther, Species::Jynx, Species::Electabuzz, Species::Magmar, Species::Pinsir, Species::Tauros, Species::Magikarp, Species::Gyarados,
+    Species::Lapras, Species::Ditto, Species::Eevee, Species::Vaporeon, Species::Jolteon, Species::Flareon, Species::Porygon, Species::Omanyte,
+    Species::Omastar, Species::Kabuto, Species::Kabutops, Species::Aerodactyl, Species::Snorlax, Species::Articuno, Species::Zapdos, Species::Moltres,
+    Species::Dratini, Species::Dragonair, Species::Dragonite, Species::Mewtwo, Species::Mew, Species::Hoothoot, Species::Noctowl, Species::Crobat,
+    Species::Chinchou, Species::Lanturn, Species::Pichu, Species::Cleffa, Species::Igglybuff, Species::Togepi, Species::Togetic, Species::Natu,
+    Species::Xatu, Species::Bellossom, Species::Marill, Species::Azumarill, Species::Sudowoodo, Species::Politoed, Species::Wooper, Species::Quagsire,
+    Species::Espeon, Species::Umbreon, Species::Slowking, Species::Wobbuffet, Species::Steelix, Species::Qwilfish, Species::Scizor, Species::Shuckle,
+    Species::Heracross, Species::Sneasel, Species::Swinub, Species::Piloswine, Species::Corsola, Species::Remoraid, Species::Octillery, Species::Delibird,
+    Species::Mantine, Species::Skarmory, Species::Kingdra, Species::Porygon2, Species::Tyrogue, Species::Hitmontop, Species::Smoochum, Species::Elekid,
+    Species::Magby, Species::Blissey, Species::Raikou, Species::Entei, Species::Suicune, Species::Larvitar, Species::Pupitar, Species::Tyranitar,
+    Species::Lugia, Species::HoOh, Species::Celebi, Species::Treecko, Species::Grovyle, Species::Sceptile, Species::Torchic, Species::Combusken,
+    Species::Blaziken, Species::Mudkip, Species::Marshtomp, Species::Swampert, Species::Zigzagoon, Species::Linoone, Species::Lotad, Species::Lombre,
+    Species::Ludicolo, Species::Seedot, Species::Nuzleaf, Species::Shiftry, Species::Wingull, Species::Pelipper, Species::Ralts, Species::Kirlia,
+    Species::Gardevoir, Species::Nincada, Species::Ninjask, Species::Shedinja, Species::Azurill, Species::Sableye, Species::Mawile, Species::Aron,
+    Species::Lairon, Species::Aggron, Species::Roselia, Species::Carvanha, Species::Sharpedo, Species::Wailmer, Species::Wailord, Species::Torkoal,
+    Species::Trapinch, Species::Vibrava, Species::Flygon, Species::Zangoose, Species::Seviper, Species::Lunatone, Species::Solrock, Species::Barboach,
+    Species::Whiscash, Species::Corphish, Species::Crawdaunt, Species::Baltoy, Species::Claydol, Species::Lileep, Species::Cradily, Species::Anorith,
+    Species::Armaldo, Species::Feebas, Species::Milotic, Species::Duskull, Species::Dusclops, Species::Absol, Species::Wynaut, Species::Snorunt,
+    Species::Glalie, Species::Spheal, Species::Sealeo, Species::Walrein, Species::Clamperl, Species::Huntail, Species::Gorebyss, Species::Relicanth,
+    Species::Luvdisc, Species::Bagon, Species::Shelgon, Species::Salamence, Species::Beldum, Species::Metang, Species::Metagross, Species::Regirock,
+    Species::Regice, Species::Registeel, Species::Latias, Species::Latios, Species::Kyogre, Species::Groudon, Species::Rayquaza, Species::Jirachi,
+    Species::Budew, Species::Roserade, Species::Combee, Species::Vespiquen, Species::Cherubi, Species::Cherrim, Species::Shellos, Species::Gastrodon,
+    Species::Drifloon, Species::Drifblim, Species::Stunky, Species::Skuntank, Species::Bronzor, Species::Bronzong, Species::Bonsly, Species::MimeJr,
+    Species::Happiny, Species::Spiritomb, Species::Gible, Species::Gabite, Species::Garchomp, Species::Munchlax, Species::Riolu, Species::Lucario,
+    Species::Hippopotas, Species::Hippowdon, Species::Skorupi, Species::Drapion, Species::Croagunk, Species::Toxicroak, Species::Mantyke, Species::Snover,
+    Species::Abomasnow, Species::Weavile, Species::Magnezone, Species::Lickilicky, Species::Rhyperior, Species::Tangrowth, Species::Electivire, Species::Magmortar,
+    Species::Togekiss, Species::Leafeon, Species::Glaceon, Species::Mamoswine, Species::PorygonZ, Species::Gallade, Species::Dusknoir, Species::Froslass,
+    Species::Rotom, Species::Uxie, Species::Mesprit, Species::Azelf, Species::Dialga, Species::Palkia, Species::Heatran, Species::Regigigas,
+    Species::Giratina, Species::Cresselia, Species::Purrloin, Species::Liepard, Species::Pansage, Species::Simisage, Species::Pansear, Species::Simisear,
+    Species::Panpour, Species::Simipour, Species::Munna, Species::Musharna, Species::Pidove, Species::Tranquill, Species::Unfezant, Species::Roggenrola,
+    Species::Boldore, Species::Gigalith, Species::Woobat, Species::Swoobat, Species::Drilbur, Species::Excadrill, Species::Audino, Species::Timburr,
+    Species::Gurdurr, Species::Conkeldurr, Species::Tympole, Species::Palpitoad, Species::Seismitoad, Species::Throh, Species::Sawk, Species::Cottonee,
+    Species::Whimsicott, Species::Petilil, Species::Lilligant, Species::Basculin, Species::Sandile, Species::Krokorok, Species::Krookodile, Species::Darumaka,
+    Species::Darmanitan, Species::Maractus, Species::Dwebble, Species::Crustle, Species::Scraggy, Species::Scrafty, Species::Sigilyph, Species::Yamask,
+    Species::Cofagrigus, Species::Tirtouga, Species::Carracosta, Species::Archen, Species::Archeops, Species::Trubbish, Species::Garbodor, Species::Zorua,
+    Species::Zoroark, Species::Minccino, Species::Cinccino, Species::Gothita, Species::Gothorita, Species::Gothitelle, Species::Solosis, Species::Duosion,
+    Species::Reuniclus, Species::Vanillite, Species::Vanillish, Species::Vanilluxe, Species::Karrablast, Species::Escavalier, Species::Foongus, Species::Amoonguss,
+    Species::Frillish, Species::Jellicent, Species::Alomomola, Species::Joltik, Species::Galvantula, Species::Ferroseed, Species::Ferrothorn, Species::Klink,
+    Species::Klang, Species::Klinklang, Species::Tynamo, Species::Eelektrik, Species::Eelektross, Species::Elgyem, Species::Beheeyem, Species::Litwick,
+    Species::Lampent, Species::Chandelure, Species::Axew, Species::Fraxure, Species::Haxorus, Species::Cubchoo, Species::Beartic, Species::Cryogonal,
+    Species::Shelmet, Species::Accelgor, Species::Stunfisk, Species::Mienfoo, Species::Mienshao, Species::Druddigon, Species::Golett, Species::Golurk,
+    Species::Pawniard, Species::Bisharp, Species::Bouffalant, Species::Rufflet, Species::Braviary, Species::Vullaby, Species::Mandibuzz, Species::Heatmor,
+    Species::Durant, Species::Deino, Species::Zweilous, Species::Hydreigon, Species::Larvesta, Species::Volcarona, Species::Cobalion, Species::Terrakion,
+    Species::Virizion, Species::Tornadus, Species::Thundurus, Species::Reshiram, Species::Zekrom, Species::Landorus, Species::Kyurem, Species::Keldeo,
+    Species::Pancham, Species::Pangoro, Species::Espurr, Species::Meowstic, Species::Honedge, Species::Doublade, Species::Aegislash, Species::Spritzee,
+    Species::Aromatisse, Species::Swirlix, Species::Slurpuff, Species::Inkay, Species::Malamar, Species::Binacle, Species::Barbaracle, Species::Skrelp,
+    Species::Dragalge, Species::Clauncher, Species::Clawitzer, Species::Tyrunt, Species::Tyrantrum, Species::Amaura, Species::Aurorus, Species::Sylveon,
+    Species::Hawlucha, Species::Dedenne, Species::Carbink, Species::Goomy, Species::Sliggoo, Species::Goodra, Species::Klefki, Species::Phantump,
+    Species::Trevenant, Species::Pumpkaboo, Species::Gourgeist, Species::Bergmite, Species::Avalugg, Species::Noibat, Species::Noivern, Species::Xerneas,
+    Species::Yveltal, Species::Zygarde, Species::Rowlet, Species::Dartrix, Species::Decidueye, Species::Litten, Species::Torracat, Species::Incineroar,
+    Species::Popplio, Species::Brionne, Species::Primarina, Species::Grubbin, Species::Charjabug, Species::Vikavolt, Species::Cutiefly, Species::Ribombee,
+    Species::Rockruff, Species::Lycanroc, Species::Wishiwashi, Species::Mareanie, Species::Toxapex, Species::Mudbray, Species::Mudsdale, Species::Dewpider,
+    Species::Araquanid, Species::Fomantis, Species::Lurantis, Species::Morelull, Species::Shiinotic, Species::Salandit, Species::Salazzle, Species::Stufful,
+    Species::Bewear, Species::Bounsweet, Species::Steenee, Species::Tsareena, Species::Comfey, Species::Oranguru, Species::Passimian, Species::Wimpod,
+    Species::Golisopod, Species::Sandygast, Species::Palossand, Species::Pyukumuku, Species::TypeNull, Species::Silvally, Species::Minior, Species::Turtonator,
+    Species::Togedemaru, Species::Mimikyu, Species::Bruxish, Species::Drampa, Species::Dhelmise, Species::JangmoO, Species::HakamoO, Species::KommoO,
+    Species::TapuKoko, Species::TapuLele, Species::TapuBulu, Species::TapuFini, Species::Cosmog, Species::Cosmoem, Species::Solgaleo, Species::Lunala,
+    Species::Nihilego, Species::Buzzwole, Species::Pheromosa, Species::Xurkitree, Species::Celesteela, Species::Kartana, Species::Guzzlord, Species::Necrozma,
+    Species::Magearna, Species::Marshadow, Species::Poipole, Species::Naganadel, Species::Stakataka, Species::Blacephalon, Species::Zeraora, Species::Meltan,
+    Species::Melmetal, Species::Grookey, Species::Thwackey, Species::Rillaboom, Species::Scorbunny, Species::Raboot, Species::Cinderace, Species::Sobble,
+    Species::Drizzile, Species::Inteleon, Species::Skwovet, Species::Greedent, Species::Rookidee, Species::Corvisquire, Species::Corviknight, Species::Blipbug,
+    Species::Dottler, Species::Orbeetle, Species::Nickit, Species::Thievul, Species::Gossifleur, Species::Eldegoss, Species::Wooloo, Species::Dubwool,
+    Species::Chewtle, Species::Drednaw, Species::Yamper, Species::Boltund, Species::Rolycoly, Species::Carkol, Species::Coalossal, Species::Applin,
+    Species::Flapple, Species::Appletun, Species::Silicobra, Species::Sandaconda, Species::Cramorant, Species::Arrokuda, Species::Barraskewda, Species::Toxel,
+    Species::Toxtricity, Species::Sizzlipede, Species::Centiskorch, Species::Clobbopus, Species::Grapploct, Species::Sinistea, Species::Polteageist, Species::Hatenna,
+    Species::Hattrem, Species::Hatterene, Species::Impidimp, Species::Morgrem, Species::Grimmsnarl, Species::Obstagoon, Species::Perrserker, Species::Cursola,
+    Species::SirfetchD, Species::MrRime, Species::Runerigus, Species::Milcery, Species::Alcremie, Species::Falinks, Species::Pincurchin, Species::Snom,
+    Species::Frosmoth, Species::Stonjourner, Species::Eiscue, Species::Indeedee, Species::Morpeko, Species::Cufant, Species::Copperajah, Species::Dracozolt,
+    Species::Arctozolt, Species::Dracovish, Species::Arctovish, Species::Duraludon, Species::Dreepy, Species::Drakloak, Species::Dragapult, Species::Zacian,
+    Species::Zamazenta, Species::Eternatus, Species::Kubfu, Species::Urshifu, Species::Zarude, Species::Regieleki, Species::Regidrago, Species::Glastrier,
+    Species::Spectrier, Species::Calyrex,];
+
+#[rustfmt::skip]
+pub(super) const LA: &[Species] = &[
+    Species::Pikachu, Species::Raichu, Species::Clefairy, Species::Clefable, Species::Zubat, Species::Golbat, Species::Paras, Species::Parasect,
+    Species::Psyduck, Species::Golduck, Species::Growlithe, Species::Arcanine, Species::Abra, Species::Kadabra, Species::Alakazam, Species::Tentacool,
+    Species::Tentacruel, Species::Geodude, Species::Graveler, Species::Golem, Species::Ponyta, Species::Rapidash, Species::Magnemite, Species::Magneton,
+    Species::Gastly, Species::Haunter, Species::Gengar, Species::Onix, Species::Voltorb, Species::Electrode, Species::Lickitung, Species::Rhyhorn,
+    Species::Rhydon, Species::Chansey, Species::Tangela, Species::MrMime, Species::Scyther, Species::Electabuzz, Species::Magmar, Species::Magikarp,
+    Species::Gyarados, Species::Eevee, Species::Vaporeon, Species::Jolteon, Species::Flareon, Species::Porygon, Species::Snorlax, Species::Cyndaquil,
+    Species::Quilava, Species::Typhlosion, Species::Crobat, Species::Pichu, Species::Cleffa, Species::Togepi, Species::Togetic, Species::Sudowoodo,
+    Species::Aipom, Species::Yanma, Species::Wooper, Species::Quagsire, Species::Espeon, Species::Umbreon, Species::Murkrow, Species::Misdreavus,
+    Species::Unown, Species::Gligar, Species::Steelix, Species::Qwilfish, Species::Scizor, Species::Heracross, Species::Sneasel, Species::Teddiursa,
+    Species::Ursaring, Species::Swinub, Species::Piloswine, Species::Remoraid, Species::Octillery, Species::Mantine, Species::Porygon2, Species::Stantler,
+    Species::Blissey, Species::Wurmple, Species::Silcoon, Species::Beautifly, Species::Cascoon, Species::Dustox, Species::Ralts, Species::Kirlia,
+    Species::Gardevoir, Species::Nosepass, Species::Roselia, Species::Duskull, Species::Dusclops, Species::Chimecho, Species::Snorunt, Species::Glalie,
+    Species::Spheal, Species::Sealeo, Species::Walrein, Species::Turtwig, Species::Grotle, Species::Torterra, Species::Chimchar, Species::Monferno,
+    Species::Infernape, Species::Piplup, Species::Prinplup, Species::Empoleon, Species::Starly, Species::Staravia, Species::Staraptor, Species::Bidoof,
+    Species::Bibarel, Species::Kricketot, Species::Kricketune, Species::Shinx, Species::Luxio, Species::Luxray, Species::Budew, Species::Roserade,
+    Species::Cranidos, Species::Rampardos, Species::Shieldon, Species::Bastiodon, Species::Burmy, Species::Wormadam, Species::Mothim, Species::Combee,
+    Species::Vespiquen, Species::Pachirisu, Species::Buizel, Species::Floatzel, Species::Cherubi, Species::Cherrim, Species::Shellos, Species::Gastrodon,
+    Species::Ambipom, Species::Drifloon, Species::Drifblim, Species::Buneary, Species::Lopunny, Species::Mismagius, Species::Honchkrow, Species::Glameow,
+    Species::Purugly, Species::Chingling, Species::Stunky, Species::Skuntank, Species::Bronzor, Species::Bronzong, Species::Bonsly, Species::MimeJr,
+    Species::Happiny, Species::Chatot, Species::Spiritomb, Species::Gible, Species::Gabite, Species::Garchomp, Species::Munchlax, Species::Riolu,
+    Species::Lucario, Species::Hippopotas, Species::Hippowdon, Species::Skorupi, Species::Drapion, Species::Croagunk, Species::Toxicroak, Species::Carnivine,
+    Species::Finneon, Species::Lumineon, Species::Mantyke, Species::Snover, Species::Abomasnow, Species::Weavile, Species::Magnezone, Species::Lickilicky,
+    Species::Rhyperior, Species::Tangrowth, Species::Electivire, Species::Magmortar, Species::Togekiss, Species::Yanmega, Species::Leafeon, Species::Glaceon,
+    Species::Gliscor, Species::Mamoswine, Species::PorygonZ, Species::Gallade, Species::Probopass, Species::Dusknoir, Species::Froslass, Species::Rotom,
+    Species::Uxie, Species::Mesprit, Species::Azelf, Species::Dialga, Species::Palkia, Species::Heatran, Species::Regigigas, Species::Giratina,
+    Species::Cresselia, Species::Phione, Species::Manaphy, Species::Darkrai, Species::Shaymin, Species::Arceus, Species::Oshawott, Species::Dewott,
+    Species::Samurott, Species::Petilil, Species::Lilligant, Species::Basculin, Species::Zorua, Species::Zoroark, Species::Rufflet, Species::Braviary,
+    Species::Tornadus, Species::Thundurus, Species::Landorus, Species::Sylveon, Species::Goomy, Species::Sliggoo, Species::Goodra, Species::Bergmite,
+    Species::Avalugg, Species::Rowlet, Species::Dartrix, Species::Decidueye, Species::Wyrdeer, Species::Kleavor, Species::Ursaluna, Species::Basculegion,
+    Species::Sneasler, Species::Overqwil, Species::Enamorus,];
+
+#[rustfmt::skip]
+pub(super) const SV: &[Species] = &[
+    Species::Bulbasaur, Species::Ivysaur, Species::Venusaur, Species::Charmander, Species::Charmeleon, Species::Charizard, Species::Squirtle, Species::Wartortle,
+    Species::Blastoise, Species::Ekans, Species::Arbok, Species::Pikachu, Species::Raichu, Species::Sandshrew, Species::Sandslash, Species::Clefairy,
+    Species::Clefable, Species::Vulpix, Species::Ninetales, Species::Jigglypuff, Species::Wigglytuff, Species::Oddish, Species::Gloom, Species::Vileplume,
+    Species::Venonat, Species::Venomoth, Species::Diglett, Species::Dugtrio, Species::Meowth, Species::Persian, Species::Psyduck, Species::Golduck,
+    Species::Mankey, Species::Primeape, Species::Growlithe, Species::Arcanine, Species::Poliwag, Species::Poliwhirl, Species::Poliwrath, Species::Abra,
+    Species::Kadabra, Species::Alakazam, Species::Bellsprout, Species::Weepinbell, Species::Victreebel, Species::Tentacool, Species::Tentacruel, Species::Geodude,
+    Species::Graveler, Species::Golem, Species::Ponyta, Species::Rapidash, Species::Slowpoke, Species::Slowbro, Species::Magnemite, Species::Magneton,
+    Species::Doduo, Species::Dodrio, Species::Seel, Species::Dewgong, Species::Grimer, Species::Muk, Species::Shellder, Species::Cloyster,
+    Species::Gastly, Species::Haunter, Species::Gengar, Species::Onix, Species::Drowzee, Species::Hypno, Species::Voltorb, Species::Electrode,
+    Species::Exeggcute, Species::Exeggutor, Species::Cubone, Species::Marowak, Species::Koffing, Species::Weezing, Species::Rhyhorn, Species::Rhydon,
+    Species::Chansey, Species::Tangela, Species::Horsea, Species::Seadra, Species::Staryu, Species::Starmie, Species::Scyther, Species::Electabuzz,
+    Species::Magmar, Species::Tauros, Species::Magikarp, Species::Gyarados, Species::Lapras, Species::Ditto, Species::Eevee, Species::Vaporeon,
+    Species::Jolteon, Species::Flareon, Species::Porygon, Species::Articuno, Species::Zapdos, Species::Moltres, Species::Dratini, Species::Dragonair,
+    Species::Dragonite, Species::Mewtwo, Species::Mew, Species::Chikorita, Species::Bayleef, Species::Meganium, Species::Cyndaquil, Species::Quilava,
+    Species::Typhlosion, Species::Totodile, Species::Croconaw, Species::Feraligatr, Species::Sentret, Species::Furret, Species::Hoothoot, Species::Noctowl,
+    Species::Spinarak, Species::Ariados, Species::Chinchou, Species::Lanturn, Species::Pichu, Species::Cleffa, Species::Igglybuff, Species::Mareep,
+    Species::Flaaffy, Species::Ampharos, Species::Marill, Species::Azumarill, Species::Sudowoodo, Species::Politoed, Species::Hoppip, Species::Skiploom,
+    Species::Jumpluff, Species::Sunkern, Species::Sunflora, Species::Wooper, Species::Quagsire, Species::Espeon, Species::Umbreon, Species::Murkrow,
+    Species::Slowking, Species::Misdreavus, Species::Girafarig, Species::Pineco, Species::Forretress, Species::Dunsparce, Species::Gligar, Species::Steelix,
+    Species::Qwilfish, Species::Scizor, Species::Shuckle, Species::Heracross, Species::Sneasel, Species::Teddiursa, Species::Ursaring, Species::Slugma,
+    Species::Magcargo, Species::Swinub, Species::Piloswine, Species::Delibird, Species::Skarmory, Species::Houndour, Species::Houndoom, Species::Kingdra,
+    Species::Phanpy, Species::Donphan, Species::Porygon2, Species::Elekid, Species::Magby, Species::Blissey, Species::Raikou, Species::Entei,
+    Species::Suicune, Species::Larvitar, Species::Pupitar, Species::Tyranitar, Species::Lugia, Species::HoOh, Species::Treecko, Species::Grovyle,
+    Species::Sceptile, Species::Torchic, Species::Combusken, Species::Blaziken, Species::Mudkip, Species::Marshtomp, Species::Swampert, Species::Poochyena,
+    Species::Mightyena, Species::Lotad, Species::Lombre, Species::Ludicolo, Species::Seedot, Species::Nuzleaf, Species::Shiftry, Species::Wingull,
+    Species::Pelipper, Species::Ralts, Species::Kirlia, Species::Gardevoir, Species::Surskit, Species::Masquerain, Species::Shroomish, Species::Breloom,
+    Species::Slakoth, Species::Vigoroth, Species::Slaking, Species::Makuhita, Species::Hariyama, Species::Azurill, Species::Sableye, Species::Plusle,
+    Species::Minun, Species::Volbeat, Species::Illumise, Species::Roselia, Species::Gulpin, Species::Swalot, Species::Numel, Species::Camerupt,
+    Species::Torkoal, Species::Spoink, Species::Grumpig, Species::Trapinch, Species::Vibrava, Species::Flygon, Species::Cacnea, Species::Cacturne,
+    Species::Swablu, Species::Altaria, Species::Zangoose, Species::Seviper, Species::Barboach, Species::Whiscash, Species::Corphish, Species::Crawdaunt,
+    Species::Feebas, Species::Milotic, Species::Duskull, Species::Dusclops, Species::Chimecho, Species::Absol, Species::Wynaut, Species::Snorunt,
+    Species::Glalie, Species::Bagon, Species::Shelgon, Species::Salamence, Species::Beldum, Species::Metang, Species::Metagross, Species::Latias,
+    Species::Latios, Species::Kyogre, Species::Groudon, Species::Rayquaza, Species::Turtwig, Species::Grotle, Species::Torterra, Species::Chimchar,
+    Species::Monferno, Species::Infernape, Species::Piplup, Species::Prinplup, Species::Empoleon, Species::Starly, Species::Staravia, Species::Staraptor,
+    Species::Shinx, Species::Luxio, Species::Luxray, Species::Budew, Species::Roserade, Species::Combee, Species::Vespiquen, Species::Pachirisu,
+    Species::Buizel, Species::Floatzel, Species::Shellos, Species::Gastrodon, Species::Drifloon, Species::Drifblim, Species::Mismagius, Species::Honchkrow,
+    Species::Chingling, Species::Stunky, Species::Skuntank, Species::Bronzor, Species::Bronzong, Species::Bonsly, Species::Happiny, Species::Spiritomb,
+    Species::Gible, Species::Gabite, Species::Garchomp, Species::Riolu, Species::Lucario, Species::Hippopotas, Species::Hippowdon, Species::Croagunk,
+    Species::Toxicroak, Species::Finneon, Species::Lumineon, Species::Snover, Species::Abomasnow, Species::Weavile, Species::Magnezone, Species::Rhyperior,
+    Species::Electivire, Species::Magmortar, Species::Leafeon, Species::Glaceon, Species::Gliscor, Species::Mamoswine, Species::PorygonZ, Species::Gallade,
+    Species::Dusknoir, Species::Froslass, Species::Rotom, Species::Snivy, Species::Servine, Species::Serperior, Species::Tepig, Species::Pignite,
+    Species::Emboar, Species::Oshawott, Species::Dewott, Species::Samurott, Species::Timburr, Species::Gurdurr, Species::Conkeldurr, Species::Petilil,
+    Species::Lilligant, Species::Basculin, Species::Sandile, Species::Krokorok, Species::Krookodile, Species::Zorua, Species::Zoroark, Species::Minccino,
+    Species::Cinccino, Species::Deerling, Species::Sawsbuck, Species::Foongus, Species::Amoonguss, Species::Alomomola, Species::Tynamo, Species::Eelektrik,
+    Species::Eelektross, Species::Litwick, Species::Lampent, Species::Chandelure, Species::Axew, Species::Fraxure, Species::Haxorus, Species::Cubchoo,
+    Species::Beartic, Species::Cryogonal, Species::Mienfoo, Species::Mienshao, Species::Druddigon, Species::Pawniard, Species::Bisharp, Species::Rufflet,
+    Species::Braviary, Species::Vullaby, Species::Mandibuzz, Species::Deino, Species::Zweilous, Species::Hydreigon, Species::Larvesta, Species::Volcarona,
+    Species::Cobalion, Species::Terrakion, Species::Virizion, Species::Reshiram, Species::Zekrom, Species::Kyurem, Species::Chespin, Species::Quilladin,
+    Species::Chesnaught, Species::Fennekin, Species::Braixen, Species::Delphox, Species::Froakie, Species::Frogadier, Species::Greninja, Species::Fletchling,
+    Species::Fletchinder, Species::Talonflame, Species::Scatterbug, Species::Spewpa, Species::Vivillon, Species::Flabebe, Species::Floette, Species::Florges,
+    Species::Skiddo, Species::Gogoat, Species::Skrelp, Species::Dragalge, Species::Sylveon, Species::Hawlucha, Species::Dedenne, Species::Goomy,
+    Species::Sliggoo, Species::Goodra, Species::Klefki, Species::Bergmite, Species::Avalugg, Species::Noibat, Species::Noivern, Species::Rowlet,
+    Species::Dartrix, Species::Decidueye, Species::Litten, Species::Torracat, Species::Incineroar, Species::Popplio, Species::Brionne, Species::Primarina,
+    Species::Crabrawler, Species::Crabominable, Species::Oricorio, Species::Rockruff, Species::Lycanroc, Species::Mareanie, Species::Toxapex, Species::Mudbray,
+    Species::Mudsdale, Species::Fomantis, Species::Lurantis, Species::Salandit, Species::Salazzle, Species::Bounsweet, Species::Steenee, Species::Tsareena,
+    Species::Comfey, Species::Oranguru, Species::Passimian, Species::Sandygast, Species::Palossand, Species::Minior, Species::Komala, Species::Mimikyu,
+    Species::Bruxish, Species::Drampa, Species::JangmoO, Species::HakamoO, Species::KommoO, Species::TapuKoko, Species::TapuLele, Species::TapuBulu,
+    Species::TapuFini, Species::Solgaleo, Species::Lunala, Species::Necrozma, Species::Grookey, Species::Thwackey, Species::Rillaboom, Species::Scorbunny,
+    Species::Raboot, Species::Cinderace, Species::Sobble, Species::Drizzile, Species::Inteleon, Species::Skwovet, Species::Greedent, Species::Rookidee,
+    Species::Corvisquire, Species::Corviknight, Species::Chewtle, Species::Drednaw, Species::Yamper, Species::Boltund, Species::Rolycoly, Species::Carkol,
+    Species::Coalossal, Species::Applin, Species::Flapple, Species::Appletun, Species::Silicobra, Species::Sandaconda, Species::Cramorant, Species::Arrokuda,
+    Species::Barraskewda, Species::Toxel, Species::Toxtricity, Species::Sizzlipede, Species::Centiskorch, Species::Sinistea, Species::Polteageist, Species::Hatenna,
+    Species::Hattrem, Species::Hatterene, Species::Impidimp, Species::Morgrem, Species::Grimmsnarl, Species::Falinks, Species::Pincurchin, Species::Snom,
+    Species::Frosmoth, Species::Eiscue, Species::Indeedee, Species::Cufant, Species::Copperajah, Species::Duraludon, Species::Dreepy, Species::Drakloak,
+    Species::Dragapult, Species::Kubfu, Species::Urshifu, Species::Glastrier, Species::Spectrier, Species::Wyrdeer, Species::Kleavor, Species::Ursaluna,
+    Species::Basculegion, Species::Sneasler, Species::Overqwil, Species::Sprigatito, Species::Floragato, Species::Meowscarada, Species::Fuecoco, Species::Crocalor,
+    Species::Skeledirge, Species::Quaxly, Species::Quaxwell, Species::Quaquaval, Species::Lechonk, Species::Oinkologne, Species::Dudunsparce, Species::Tarountula,
+    Species::Spidops, Species::Nymble, Species::Lokix, Species::Rellor, Species::Rabsca, Species::Greavard, Species::Houndstone, Species::Flittle,
+    Species::Espathra, Species::Farigiraf, Species::Wiglett, Species::Wugtrio, Species::Dondozo, Species::Veluza, Species::Finizen, Species::Palafin,
+    Species::Smoliv, Species::Dolliv, Species::Arboliva, Species::Capsakid, Species::Scovillain, Species::Tadbulb, Species::Bellibolt, Species::Varoom,
+    Species::Revavroom, Species::Orthworm, Species::Tandemaus, Species::Maushold, Species::Cetoddle, Species::Cetitan, Species::Frigibax, Species::Arctibax,
+    Species::Baxcalibur, Species::Tatsugiri, Species::Cyclizar, Species::Pawmi, Species::Pawmo, Species::Pawmot, Species::Wattrel, Species::Kilowattrel,
+    Species::Bombirdier, Species::Squawkabilly, Species::Flamigo, Species::Klawf, Species::Nacli, Species::Naclstack, Species::Garganacl, Species::Glimmet,
+    Species::Glimmora, Species::Shroodle, Species::Grafaiai, Species::Fidough, Species::Dachsbun, Species::Maschiff, Species::Mabosstiff, Species::Bramblin,
+    Species::Brambleghast, Species::Gimmighoul, Species::Gholdengo, Species::GreatTusk, Species::BruteBonnet, Species::Aketuban, Species::SandyShocks, Species::ScreamTail,
+    Species::FlutterMane, Species::SlitherWing, Species::RoaringMoon, Species::IronTreads, Species::Bketuban, Species::IronMoth, Species::IronHands, Species::IronJugulis,
+    Species::IronThorns, Species::IronBundle, Species::IronValiant, Species::TingLu, Species::ChienPao, Species::WoChien, Species::ChiYu, Species::Koraidon,
+    Species::Miraidon, Species::Tinkatink, Species::Tinkatuff, Species::Tinkaton, Species::Charcadet, Species::Armarouge, Species::Ceruledge, Species::Toedscool,
+    Species::Toedscruel, Species::Kingambit, Species::Clodsire, Species::Annihilape,];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::personal::size_info_la;
+
+    #[test]
+    fn should_know_the_size_of_every_hisui_species() {
+        for &species in LA {
+            assert!(size_info_la(species, 0).is_some(), "{species:?}");
+        }
+    }
+}
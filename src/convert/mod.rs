@@ -0,0 +1,130 @@
+mod availability;
+mod species;
+mod to_pa8;
+mod to_pk6;
+mod to_pk7;
+mod to_pk8;
+mod to_pk9;
+
+pub use species::*;
+pub use to_pk6::*;
+pub use to_pk7::*;
+
+use crate::pkx::{Pkx, PkxMut};
+use crate::species_names;
+use crate::types::{Language, Species};
+use alloc::string::{String, ToString};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConvertError {
+    /// The species, as a national dex number, doesn't exist in the target game.
+    UnavailableSpecies(u16),
+    /// Eggs have to hatch before they can be transferred.
+    Egg,
+    /// The species has no personal data, such as an out of range species.
+    UnknownSpecies(Species),
+}
+
+/// Checks that a species, as a national dex number, is in a game's dex.
+fn check_species(species: u16, dex: &[Species]) -> Result<u16, ConvertError> {
+    if species == 0 || !dex.contains(&Species::from(species)) {
+        return Err(ConvertError::UnavailableSpecies(species));
+    }
+
+    Ok(species)
+}
+
+/// The name a Pokemon that isn't nicknamed gets when it leaves the Game Boy
+/// and DS games, which wrote names in capitals.  Languages without name data
+/// keep their old name.
+fn species_name(species: Species, language: u8, old_name: &str) -> String {
+    species_names::species_name(species, Language::from(language))
+        .map_or_else(|| old_name.to_string(), ToString::to_string)
+}
+
+/// Copies the fields every format shares.  Fields are set one by one so they
+/// end up at the target's offsets, and ribbons the target has no room for are
+/// dropped.  The species is copied as is, so callers converting to or from a
+/// format with its own species numbering need to fix it up.
+fn copy_common<S: Pkx, D: PkxMut>(src: &S, dst: &mut D) {
+    dst.set_encryption_constant(src.encryption_constant());
+    dst.set_pid(src.pid());
+    dst.set_species(src.species());
+    dst.set_form(src.form());
+    dst.set_held_item(src.held_item());
+    dst.set_tid16(src.tid16());
+    dst.set_sid16(src.sid16());
+    dst.set_exp(src.exp());
+    dst.set_ability(src.ability());
+    dst.set_ability_number(src.ability_number());
+    dst.set_nature(src.nature());
+    dst.set_gender(src.gender());
+
+    dst.set_ev_hp(src.ev_hp());
+    dst.set_ev_atk(src.ev_atk());
+    dst.set_ev_def(src.ev_def());
+    dst.set_ev_spe(src.ev_spe());
+    dst.set_ev_spa(src.ev_spa());
+    dst.set_ev_spd(src.ev_spd());
+    // The egg and nickname flags live in the IV bits, so this copies them too.
+    dst.set_iv32(src.iv32());
+
+    dst.set_move1(src.move1());
+    dst.set_move2(src.move2());
+    dst.set_move3(src.move3());
+    dst.set_move4(src.move4());
+    dst.set_move1_pp(src.move1_pp());
+    dst.set_move2_pp(src.move2_pp());
+    dst.set_move3_pp(src.move3_pp());
+    dst.set_move4_pp(src.move4_pp());
+    dst.set_move1_pp_ups(src.move1_pp_ups());
+    dst.set_move2_pp_ups(src.move2_pp_ups());
+    dst.set_move3_pp_ups(src.move3_pp_ups());
+    dst.set_move4_pp_ups(src.move4_pp_ups());
+    dst.set_relearn_move1(src.relearn_move1());
+    dst.set_relearn_move2(src.relearn_move2());
+    dst.set_relearn_move3(src.relearn_move3());
+    dst.set_relearn_move4(src.relearn_move4());
+
+    dst.set_nickname(&src.nickname());
+    dst.set_ot_name(&src.ot_name());
+    dst.set_ht_name(&src.ht_name());
+    dst.set_ot_gender(src.ot_gender());
+    dst.set_language(src.language());
+    dst.set_version(src.version());
+    dst.set_ball(src.ball());
+    dst.set_met_level(src.met_level());
+    dst.set_met_location(src.met_location());
+    dst.set_egg_location(src.egg_location());
+
+    dst.set_current_handler(src.current_handler());
+    dst.set_ot_friendship(src.ot_friendship());
+    dst.set_ht_friendship(src.ht_friendship());
+    dst.set_ot_memory(src.ot_memory());
+    dst.set_ot_intensity(src.ot_intensity());
+    dst.set_ot_text_var(src.ot_text_var());
+    dst.set_ot_feeling(src.ot_feeling());
+    dst.set_ht_memory(src.ht_memory());
+    dst.set_ht_intensity(src.ht_intensity());
+    dst.set_ht_text_var(src.ht_text_var());
+    dst.set_ht_feeling(src.ht_feeling());
+
+    for ribbon in src.ribbons() {
+        dst.set_ribbon(ribbon, true);
+    }
+    dst.set_ribbon_count_memory_contest(src.ribbon_count_memory_contest());
+    dst.set_ribbon_count_memory_battle(src.ribbon_count_memory_battle());
+}
+
+/// HOME doesn't keep the memories made on the 3DS when moving a Pokemon to the
+/// Switch.
+fn clear_memories<T: PkxMut>(pkm: &mut T) {
+    pkm.set_ot_memory(0);
+    pkm.set_ot_intensity(0);
+    pkm.set_ot_text_var(0);
+    pkm.set_ot_feeling(0);
+    pkm.set_ht_memory(0);
+    pkm.set_ht_intensity(0);
+    pkm.set_ht_text_var(0);
+    pkm.set_ht_feeling(0);
+}
@@ -0,0 +1,75 @@
+/// National dex numbers for SV's internal species 906 onwards, which are
+/// ordered by development rather than by the Paldea dex.  The two cut species
+/// SV left in its table have no national dex number and are stored as 0.
+#[rustfmt::skip]
+const GEN9_NATIONAL: [u16; 105] = [
+    906, 907, 908, 909, 910, 911, 912, 913, 914, 915, 916, 982, 917, 918, 919,
+    920, 953, 954, 971, 972, 955, 956, 981, 960, 961, 977, 976, 963, 964, 928,
+    929, 930, 951, 952, 938, 939, 965, 966, 968, 924, 925, 974, 975, 996, 997,
+    998, 978, 967, 921, 922, 923, 940, 941, 962, 931, 973, 950, 932, 933, 934,
+    969, 970, 944, 945, 926, 927, 942, 943, 946, 947, 999, 1000, 984, 986, 0,
+    989, 985, 987, 988, 1005, 990, 0, 994, 992, 993, 995, 991, 1006, 1003, 1002,
+    1001, 1004, 1007, 1008, 957, 958, 959, 935, 936, 937, 948, 949, 983, 980, 979,
+];
+
+const GEN9_FIRST_REMAPPED: u16 = 906;
+
+/// The species SV stores for a national dex number.  `None` for species past
+/// the ones the crate knows SV's numbering for.
+pub fn species_to_gen9(national: u16) -> Option<u16> {
+    if national < GEN9_FIRST_REMAPPED {
+        return Some(national);
+    }
+
+    GEN9_NATIONAL
+        .iter()
+        .position(|species| *species == national)
+        .map(|index| GEN9_FIRST_REMAPPED + index as u16)
+}
+
+/// The national dex number of a species stored by SV.
+pub fn species_from_gen9(internal: u16) -> Option<u16> {
+    if internal < GEN9_FIRST_REMAPPED {
+        return Some(internal);
+    }
+
+    GEN9_NATIONAL
+        .get((internal - GEN9_FIRST_REMAPPED) as usize)
+        .copied()
+        .filter(|species| *species != 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::Species;
+
+    #[test]
+    fn should_keep_older_species() {
+        assert_eq!(species_to_gen9(905), Some(905));
+        assert_eq!(species_from_gen9(Species::Eevee as u16), Some(133));
+    }
+
+    #[test]
+    fn should_remap_paldea_species() {
+        assert_eq!(species_from_gen9(Species::Dudunsparce as u16), Some(982));
+        assert_eq!(species_from_gen9(Species::Kingambit as u16), Some(983));
+        assert_eq!(species_to_gen9(921), Some(Species::Pawmi as u16));
+        assert_eq!(species_to_gen9(1008), Some(Species::Miraidon as u16));
+    }
+
+    #[test]
+    fn should_round_trip() {
+        for national in 1..=1008 {
+            let internal = species_to_gen9(national).unwrap();
+            assert_eq!(species_from_gen9(internal), Some(national));
+        }
+    }
+
+    #[test]
+    fn should_reject_unknown_species() {
+        assert_eq!(species_from_gen9(Species::Aketuban as u16), None);
+        assert_eq!(species_from_gen9(1011), None);
+        assert_eq!(species_to_gen9(1009), None);
+    }
+}
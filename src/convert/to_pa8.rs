@@ -0,0 +1,145 @@
+use super::availability::LA;
+use super::{check_species, copy_common, species_from_gen9, ConvertError};
+use crate::pa8::Pa8;
+use crate::personal::size_info_la;
+use crate::pk8::Pk8;
+use crate::pk9::Pk9;
+use crate::pkx::{Pkx, PkxMut};
+
+/// The effort level HOME gives a stat so the Pokemon keeps up with its IV.
+fn effort_level_floor(iv: u8) -> u8 {
+    match iv {
+        31.. => 3,
+        26.. => 2,
+        20.. => 1,
+        _ => 0,
+    }
+}
+
+/// Fills in what only Legends: Arceus keeps.  Effort levels start at the
+/// lowest level the IVs allow, and the height and weight are worked out from
+/// the size scalars.
+fn set_legends_data(pa8: &mut Pa8) -> Result<(), ConvertError> {
+    let species = pa8.species_t();
+    let size = size_info_la(species, pa8.form()).ok_or(ConvertError::UnknownSpecies(species))?;
+    pa8.set_height_absolute(size.height_absolute(pa8.height_scalar()));
+    pa8.set_weight_absolute(size.weight_absolute(pa8.height_scalar(), pa8.weight_scalar()));
+
+    pa8.set_gv_hp(effort_level_floor(pa8.iv_hp()));
+    pa8.set_gv_atk(effort_level_floor(pa8.iv_atk()));
+    pa8.set_gv_def(effort_level_floor(pa8.iv_def()));
+    pa8.set_gv_spe(effort_level_floor(pa8.iv_spe()));
+    pa8.set_gv_spa(effort_level_floor(pa8.iv_spa()));
+    pa8.set_gv_spd(effort_level_floor(pa8.iv_spd()));
+    Ok(())
+}
+
+/// Moves a Pokemon from Sword and Shield into Legends: Arceus through HOME.
+impl TryFrom<&Pk8> for Pa8 {
+    type Error = ConvertError;
+
+    fn try_from(pk8: &Pk8) -> Result<Self, Self::Error> {
+        check_species(pk8.species(), LA)?;
+
+        let mut pa8 = Pa8::default();
+        copy_common(pk8, &mut pa8);
+        pa8.set_minted_nature(pk8.minted_nature_t() as u8);
+        pa8.set_height_scalar(pk8.height_scalar());
+        pa8.set_weight_scalar(pk8.weight_scalar());
+        set_legends_data(&mut pa8)?;
+
+        pa8.refresh_checksum();
+        Ok(pa8)
+    }
+}
+
+/// Moves a Pokemon from Scarlet and Violet into Legends: Arceus through HOME.
+impl TryFrom<&Pk9> for Pa8 {
+    type Error = ConvertError;
+
+    fn try_from(pk9: &Pk9) -> Result<Self, Self::Error> {
+        let species = species_from_gen9(pk9.species())
+            .ok_or(ConvertError::UnavailableSpecies(pk9.species()))?;
+        check_species(species, LA)?;
+
+        let mut pa8 = Pa8::default();
+        copy_common(pk9, &mut pa8);
+        pa8.set_species(species);
+        pa8.set_minted_nature(pk9.minted_nature_t() as u8);
+        pa8.set_height_scalar(pk9.height_scalar());
+        pa8.set_weight_scalar(pk9.weight_scalar());
+        set_legends_data(&mut pa8)?;
+
+        pa8.refresh_checksum();
+        Ok(pa8)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::Species;
+
+    #[test]
+    fn should_set_effort_levels_from_ivs() {
+        let mut pk8 = Pk8::default();
+        pk8.set_species(Species::Eevee as u16);
+        pk8.set_iv_hp(31);
+        pk8.set_iv_atk(26);
+        pk8.set_iv_def(25);
+        pk8.set_iv_spe(20);
+        pk8.set_iv_spa(19);
+        pk8.set_move1(33);
+        pk8.refresh_checksum();
+
+        let pa8 = Pa8::try_from(&pk8).unwrap();
+        assert!(pa8.is_valid());
+        assert_eq!(pa8.move1(), 33);
+        assert_eq!(
+            [
+                pa8.gv_hp(),
+                pa8.gv_atk(),
+                pa8.gv_def(),
+                pa8.gv_spe(),
+                pa8.gv_spa(),
+                pa8.gv_spd()
+            ],
+            [3, 2, 1, 1, 0, 0]
+        );
+    }
+
+    #[test]
+    fn should_calculate_absolute_sizes() {
+        let mut pk8 = Pk8::default();
+        pk8.set_species(Species::Eevee as u16);
+        pk8.set_height_scalar(0);
+        pk8.set_weight_scalar(255);
+
+        let pa8 = Pa8::try_from(&pk8).unwrap();
+        assert_eq!(pa8.height_absolute(), 24.0);
+        assert_eq!(pa8.weight_absolute(), 62.4);
+    }
+
+    #[test]
+    fn should_convert_from_pk9() {
+        let mut pk9 = Pk9::default();
+        pk9.set_species(Species::Sneasel as u16);
+        pk9.set_form(1);
+        pk9.set_height_scalar(255);
+        pk9.set_weight_scalar(0);
+        pk9.set_iv_spe(31);
+
+        let pa8 = Pa8::try_from(&pk9).unwrap();
+        assert!(pa8.is_valid());
+        assert_eq!(pa8.species_t(), Species::Sneasel);
+        assert_eq!(pa8.form(), 1);
+        assert_eq!(pa8.gv_spe(), 3);
+        assert_eq!(pa8.height_absolute(), 108.00001);
+
+        pk9.set_species(Species::Wooloo as u16);
+        assert_eq!(
+            Pa8::try_from(&pk9).err(),
+            Some(ConvertError::UnavailableSpecies(831))
+        );
+    }
+}
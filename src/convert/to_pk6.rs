@@ -0,0 +1,326 @@
+use super::{species_name, ConvertError};
+use crate::personal::{personal_info, PersonalInfo};
+use crate::pk5::Pk5;
+use crate::pk6::Pk6;
+use crate::pkx::PkxMut;
+use crate::reader::Reader;
+use crate::types::{Ribbon, TrainerInfo};
+
+/// The met location Poke Transfer gives every Pokemon it moves.
+pub const POKE_TRANSFER_LOCATION: u16 = 30001;
+
+/// Gen4 ribbons Gen5 keeps in its first ribbon set, by bit.
+const SINNOH_RIBBONS: [(usize, Ribbon); 22] = [
+    (0, Ribbon::ChampionSinnoh),
+    (7, Ribbon::Alert),
+    (8, Ribbon::Shock),
+    (9, Ribbon::Downcast),
+    (10, Ribbon::Careless),
+    (11, Ribbon::Relax),
+    (12, Ribbon::Snooze),
+    (13, Ribbon::Smile),
+    (14, Ribbon::Gorgeous),
+    (15, Ribbon::Royal),
+    (16, Ribbon::GorgeousRoyal),
+    (17, Ribbon::Footprint),
+    (18, Ribbon::Record),
+    (19, Ribbon::Event),
+    (20, Ribbon::Legend),
+    (21, Ribbon::ChampionWorld),
+    (22, Ribbon::Birthday),
+    (23, Ribbon::Special),
+    (24, Ribbon::Souvenir),
+    (25, Ribbon::Wishing),
+    (26, Ribbon::Classic),
+    (27, Ribbon::Premier),
+];
+
+/// Gen3 ribbons, after the 20 contest ribbons.
+const HOENN_RIBBONS: [(usize, Ribbon); 10] = [
+    (20, Ribbon::ChampionG3),
+    (23, Ribbon::Artist),
+    (24, Ribbon::Effort),
+    (25, Ribbon::ChampionBattle),
+    (26, Ribbon::ChampionRegional),
+    (27, Ribbon::ChampionNational),
+    (28, Ribbon::Country),
+    (29, Ribbon::National),
+    (30, Ribbon::Earth),
+    (31, Ribbon::World),
+];
+
+/// The Sinnoh ability ribbons and the Hoenn Winning and Victory ribbons
+/// become the battle memory ribbon.
+const SINNOH_BATTLE_RIBBON_BITS: [usize; 6] = [1, 2, 3, 4, 5, 6];
+const HOENN_BATTLE_RIBBON_BITS: [usize; 2] = [21, 22];
+const CONTEST_RIBBON_COUNT: usize = 20;
+
+const SINNOH_RIBBON_OFFSET: u64 = 0x24;
+const HOENN_RIBBON_OFFSET: u64 = 0x3c;
+const SINNOH_CONTEST_RIBBON_OFFSET: u64 = 0x60;
+
+/// Gen5 only sets a flag for hidden abilities, so the ability number is worked
+/// out from the species' abilities.  Abilities the species can't have fall
+/// back to its first one.
+fn ability_number(pk5: &Pk5, personal: &PersonalInfo) -> u8 {
+    if pk5.has_hidden_ability() {
+        return 4;
+    }
+
+    match personal.abilities[1] as u16 == u16::from(pk5.ability()) {
+        true if personal.abilities[0] != personal.abilities[1] => 2,
+        _ => 1,
+    }
+}
+
+fn count_flags(pk5: &Pk5, offset: u64, bits: impl Iterator<Item = usize>) -> u8 {
+    bits.filter(|bit| pk5.read_flag(offset, *bit)).count() as u8
+}
+
+fn convert_ribbons(pk5: &Pk5, pk6: &mut Pk6) {
+    let ribbons = SINNOH_RIBBONS
+        .iter()
+        .map(|(bit, ribbon)| (SINNOH_RIBBON_OFFSET, *bit, *ribbon))
+        .chain(
+            HOENN_RIBBONS
+                .iter()
+                .map(|(bit, ribbon)| (HOENN_RIBBON_OFFSET, *bit, *ribbon)),
+        );
+    for (offset, bit, ribbon) in ribbons {
+        if pk5.read_flag(offset, bit) {
+            pk6.set_ribbon(ribbon, true);
+        }
+    }
+
+    let contest = count_flags(pk5, HOENN_RIBBON_OFFSET, 0..CONTEST_RIBBON_COUNT)
+        + count_flags(pk5, SINNOH_CONTEST_RIBBON_OFFSET, 0..CONTEST_RIBBON_COUNT);
+    let battle = count_flags(
+        pk5,
+        SINNOH_RIBBON_OFFSET,
+        SINNOH_BATTLE_RIBBON_BITS.into_iter(),
+    ) + count_flags(
+        pk5,
+        HOENN_RIBBON_OFFSET,
+        HOENN_BATTLE_RIBBON_BITS.into_iter(),
+    );
+
+    pk6.set_ribbon_count_memory_contest(contest);
+    pk6.set_ribbon(Ribbon::CountMemoryContest, contest != 0);
+    pk6.set_ribbon_count_memory_battle(battle);
+    pk6.set_ribbon(Ribbon::CountMemoryBattle, battle != 0);
+}
+
+impl Pk5 {
+    /// Moves the Pokemon to Gen6 the way Poke Bank's Poke Transfer does, with
+    /// `trainer` as the one transferring it.  Eggs can't be transferred.
+    pub fn poke_transfer(&self, trainer: &TrainerInfo) -> Result<Pk6, ConvertError> {
+        if self.is_egg() {
+            return Err(ConvertError::Egg);
+        }
+
+        let species = self.species_t();
+        let personal =
+            personal_info(species, self.form()).ok_or(ConvertError::UnknownSpecies(species))?;
+        let ability_number = ability_number(self, personal);
+
+        let mut pk6 = Pk6::default();
+        pk6.set_encryption_constant(self.pid());
+        pk6.set_pid(self.pid());
+        pk6.set_species(self.species());
+        pk6.set_form(self.form());
+        pk6.set_gender(self.gender());
+        pk6.set_held_item(self.held_item());
+        pk6.set_tid16(self.tid16());
+        pk6.set_sid16(self.sid16());
+        pk6.set_exp(self.exp());
+        pk6.set_ability(personal.ability(ability_number).map_or(0, |a| a as u16));
+        pk6.set_ability_number(ability_number);
+        pk6.set_nature(self.nature());
+        pk6.set_markings(self.markings());
+        pk6.set_pokerus(self.pokerus());
+        pk6.set_fateful_encounter(self.is_fateful_encounter());
+
+        pk6.set_ev_hp(self.ev_hp());
+        pk6.set_ev_atk(self.ev_atk());
+        pk6.set_ev_def(self.ev_def());
+        pk6.set_ev_spe(self.ev_spe());
+        pk6.set_ev_spa(self.ev_spa());
+        pk6.set_ev_spd(self.ev_spd());
+        pk6.set_iv32(self.iv32());
+        pk6.set_contest_cool(self.contest_cool());
+        pk6.set_contest_beauty(self.contest_beauty());
+        pk6.set_contest_cute(self.contest_cute());
+        pk6.set_contest_smart(self.contest_smart());
+        pk6.set_contest_tough(self.contest_tough());
+        pk6.set_contest_sheen(self.contest_sheen());
+
+        pk6.set_move1(self.move1());
+        pk6.set_move2(self.move2());
+        pk6.set_move3(self.move3());
+        pk6.set_move4(self.move4());
+        pk6.set_move1_pp(self.move1_pp());
+        pk6.set_move2_pp(self.move2_pp());
+        pk6.set_move3_pp(self.move3_pp());
+        pk6.set_move4_pp(self.move4_pp());
+        pk6.set_move1_pp_ups(self.move1_pp_ups());
+        pk6.set_move2_pp_ups(self.move2_pp_ups());
+        pk6.set_move3_pp_ups(self.move3_pp_ups());
+        pk6.set_move4_pp_ups(self.move4_pp_ups());
+
+        let nickname = match self.is_nicknamed() {
+            true => self.nickname(),
+            false => species_name(species, self.language(), &self.nickname()),
+        };
+        pk6.set_nickname(&nickname);
+        pk6.set_ot_name(&self.ot_name());
+        pk6.set_ot_gender(self.ot_gender());
+        pk6.set_ot_friendship(self.ot_friendship());
+        pk6.set_language(self.language());
+        pk6.set_version(self.version());
+        pk6.set_ball(self.ball());
+        pk6.set_egg_location(self.egg_location());
+        pk6.set_met_location(POKE_TRANSFER_LOCATION);
+
+        // Poke Transfer records the level the Pokemon arrived at.
+        pk6.set_met_level(personal.growth_rate.level_for_exp(self.exp()));
+
+        pk6.set_ht_name(&trainer.ot_name);
+        pk6.set_ht_gender(trainer.gender);
+        pk6.set_ht_friendship(self.ot_friendship());
+        pk6.set_current_handler(1);
+
+        convert_ribbons(self, &mut pk6);
+
+        pk6.refresh_checksum();
+        Ok(pk6)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkx::Pkx;
+    use crate::poke_crypto::{calculate_checksum, PokeCrypto};
+    use crate::types::{Ability, GameVersion, Language, Species};
+    use crate::writer::Writer;
+    use alloc::string::ToString;
+
+    fn trainer() -> TrainerInfo {
+        TrainerInfo {
+            ot_name: "Serena".to_string(),
+            tid16: 1,
+            sid16: 2,
+            gender: 1,
+            language: Language::English as u8,
+            version: GameVersion::Y,
+        }
+    }
+
+    fn pk5_data(species: Species, nickname: &str, language: Language) -> [u8; Pk5::STORED_SIZE] {
+        let mut pk5 = Pk5::default();
+        pk5.write(0x00, 0x0002_2345u32);
+        pk5.write(0x08, species as u16);
+        pk5.write(0x10, 135u32);
+        pk5.write(0x17, language as u8);
+        pk5.write(0x80, 8u16);
+        pk5.write(0x84, 3u8);
+        pk5.set_nickname(nickname);
+        pk5.set_ot_name("N");
+        pk5.as_slice().try_into().unwrap()
+    }
+
+    fn transfer(mut data: [u8; Pk5::STORED_SIZE]) -> Result<Pk6, ConvertError> {
+        let checksum = calculate_checksum(&data[8..]);
+        data.write(0x06, checksum);
+        Pk5::new(data).poke_transfer(&trainer())
+    }
+
+    #[test]
+    fn should_apply_transfer_rules() {
+        let pk6 = transfer(pk5_data(Species::Pikachu, "PIKACHU", Language::English)).unwrap();
+
+        assert!(pk6.is_valid());
+        assert_eq!(pk6.encryption_constant(), 0x0002_2345);
+        assert_eq!(pk6.pid(), 0x0002_2345);
+        assert_eq!(pk6.nickname(), "Pikachu");
+        assert_eq!(pk6.ot_name(), "N");
+        assert_eq!(pk6.met_location(), POKE_TRANSFER_LOCATION);
+        assert_eq!(pk6.met_level(), 5);
+        assert_eq!(pk6.ht_name(), "Serena");
+        assert_eq!(pk6.ht_gender(), 1);
+        assert_eq!(pk6.current_handler(), 1);
+        assert_eq!(pk6.ability_number(), 1);
+    }
+
+    #[test]
+    fn should_fix_names_in_other_languages() {
+        let pk6 = transfer(pk5_data(Species::MrMime, "M. MIME", Language::French)).unwrap();
+        assert_eq!(pk6.nickname(), "M. Mime");
+
+        let pk6 = transfer(pk5_data(Species::HoOh, "HO-OH", Language::English)).unwrap();
+        assert_eq!(pk6.nickname(), "Ho-Oh");
+    }
+
+    #[test]
+    fn should_keep_nicknames() {
+        let mut data = pk5_data(Species::Pikachu, "SPARKY", Language::English);
+        data.write(0x38, 1u32 << 31);
+        assert_eq!(transfer(data).unwrap().nickname(), "SPARKY");
+    }
+
+    #[test]
+    fn should_recompute_ability_numbers() {
+        let mut data = pk5_data(Species::Eevee, "EEVEE", Language::English);
+        data.write(0x15, Ability::Adaptability as u8);
+        assert_eq!(transfer(data).unwrap().ability_number(), 2);
+
+        data.write(0x42, 1u8);
+        let pk6 = transfer(data).unwrap();
+        assert_eq!(pk6.ability_number(), 4);
+        assert_eq!(pk6.ability_t(), Ability::Anticipation);
+    }
+
+    #[test]
+    fn should_take_abilities_from_personal_data() {
+        let mut data = pk5_data(Species::Eevee, "EEVEE", Language::English);
+        data.write(0x00, 0x0001_0000u32);
+        data.write(0x15, Ability::Levitate as u8);
+        let pk6 = transfer(data).unwrap();
+        assert_eq!(pk6.ability_number(), 1);
+        assert_eq!(pk6.ability_t(), Ability::RunAway);
+    }
+
+    #[test]
+    fn should_record_the_current_level() {
+        let mut data = pk5_data(Species::Eevee, "EEVEE", Language::English);
+        data.write(0x10, 27000u32);
+        assert_eq!(transfer(data).unwrap().met_level(), 30);
+    }
+
+    #[test]
+    fn should_convert_ribbons() {
+        let mut data = pk5_data(Species::Eevee, "EEVEE", Language::English);
+        data.write_flag(SINNOH_RIBBON_OFFSET, 0, true);
+        data.write_flag(SINNOH_RIBBON_OFFSET, 2, true);
+        data.write_flag(SINNOH_RIBBON_OFFSET, 27, true);
+        data.write_flag(HOENN_RIBBON_OFFSET, 0, true);
+        data.write_flag(HOENN_RIBBON_OFFSET, 1, true);
+        data.write_flag(HOENN_RIBBON_OFFSET, 21, true);
+        data.write_flag(SINNOH_CONTEST_RIBBON_OFFSET, 5, true);
+
+        let pk6 = transfer(data).unwrap();
+        assert!(pk6.has_ribbon(Ribbon::ChampionSinnoh));
+        assert!(pk6.has_ribbon(Ribbon::Premier));
+        assert!(pk6.has_ribbon(Ribbon::CountMemoryContest));
+        assert!(pk6.has_ribbon(Ribbon::CountMemoryBattle));
+        assert_eq!(pk6.ribbon_count_memory_contest(), 3);
+        assert_eq!(pk6.ribbon_count_memory_battle(), 2);
+    }
+
+    #[test]
+    fn should_reject_eggs() {
+        let mut data = pk5_data(Species::Eevee, "EEVEE", Language::English);
+        data.write(0x38, 1u32 << 30);
+        assert_eq!(transfer(data).err(), Some(ConvertError::Egg));
+    }
+}
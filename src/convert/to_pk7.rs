@@ -0,0 +1,375 @@
+use super::{species_name, ConvertError};
+use crate::personal::personal_info;
+use crate::pk6::Pk6;
+use crate::pk7::Pk7;
+use crate::pkx::{Pkx, PkxMut};
+use crate::reader::Reader;
+use crate::types::{Ball, GameVersion, Gender, GenderRatio, Species, TrainerInfo};
+use crate::writer::Writer;
+use alloc::string::String;
+
+/// The met locations Poke Transporter gives Pokemon from the Gen1 and Gen2
+/// Virtual Console games.
+pub const VC_TRANSFER_LOCATION_GEN1: u16 = 30013;
+pub const VC_TRANSFER_LOCATION_GEN2: u16 = 30017;
+
+/// Legendaries that keep their first ability instead of getting their hidden
+/// ability.
+const DEFAULT_ABILITY_SPECIES: [Species; 11] = [
+    Species::Articuno,
+    Species::Zapdos,
+    Species::Moltres,
+    Species::Mewtwo,
+    Species::Mew,
+    Species::Raikou,
+    Species::Entei,
+    Species::Suicune,
+    Species::Lugia,
+    Species::HoOh,
+    Species::Celebi,
+];
+
+/// Moves a Pokemon through Poke Bank.  Gen7 kept Gen6's layout, so everything
+/// carries over apart from the few bytes Gen7 reused.
+impl From<&Pk6> for Pk7 {
+    fn from(pk6: &Pk6) -> Self {
+        let mut pk7 = Pk7::default();
+        pk7.as_mut_slice().copy_from_slice(pk6.as_slice());
+
+        // Gen6 keeps one bit per marking next to its training bag, Gen7 two
+        // bits per marking so it can tell blue from pink.
+        let markings = pk6.markings();
+        let markings = (0..6)
+            .filter(|index| (markings >> index) & 1 == 1)
+            .fold(0u16, |value, index| value | (1 << (index * 2)));
+        pk7.write(0x16, markings);
+        pk7.write(0x2a, 0u8);
+
+        // Gen7 decodes Chinese characters differently.
+        pk7.set_nickname(&pk6.nickname());
+        pk7.set_ot_name(&pk6.ot_name());
+        pk7.set_ht_name(&pk6.ht_name());
+
+        pk7.refresh_checksum();
+        pk7
+    }
+}
+
+/// A Pokemon from the Gen1 and Gen2 Virtual Console games.
+///
+/// The crate doesn't read Game Boy saves, so callers fill this in from their
+/// own parser.  Gen1 numbers species in its own order, which callers need to
+/// convert to national dex numbers first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VcPokemon {
+    pub species: u16,
+    pub version: GameVersion,
+    pub language: u8,
+    pub level: u8,
+    pub exp: u32,
+    /// The Attack, Defense, Speed and Special DVs, from 0 to 15.
+    pub dvs: [u8; 4],
+    pub moves: [u16; 4],
+    pub move_pp: [u8; 4],
+    pub move_pp_ups: [u8; 4],
+    pub nickname: String,
+    pub is_nicknamed: bool,
+    pub ot_name: String,
+    /// Only Crystal records the OT's gender.
+    pub ot_gender: u8,
+    pub tid16: u16,
+}
+
+impl VcPokemon {
+    /// Gen2's shininess, which Gen1 Pokemon also get once traded forward.
+    pub fn is_shiny(&self) -> bool {
+        let [atk, def, spe, spc] = self.dvs;
+        def == 10 && spe == 10 && spc == 10 && atk & 2 == 2
+    }
+
+    /// Gen2's gender, decided by the Attack DV.
+    pub fn gender(&self) -> Gender {
+        let threshold = match self.species_t().get_gender_ratio() {
+            GenderRatio::Genderless => return Gender::Genderless,
+            GenderRatio::MaleOnly => return Gender::Male,
+            GenderRatio::FemaleOnly => return Gender::Female,
+            GenderRatio::OneToSeven => 2,
+            GenderRatio::OneToThree => 4,
+            GenderRatio::OneToOne => 8,
+            GenderRatio::ThreeToOne => 12,
+            GenderRatio::SevenToOne => 14,
+        };
+
+        if self.dvs[0] < threshold {
+            Gender::Female
+        } else {
+            Gender::Male
+        }
+    }
+
+    pub fn species_t(&self) -> Species {
+        self.species.into()
+    }
+
+    /// Moves the Pokemon into Sun and Moon the way Poke Transporter does, with
+    /// `trainer` as the one transferring it.
+    ///
+    /// The nature comes from the experience, which is then cut back to the
+    /// start of the Pokemon's level.  Three IVs are perfect, or five for Mew and
+    /// Celebi, and most species get their hidden ability.  Friendship starts
+    /// over at the species' base friendship.
+    pub fn transport(
+        &self,
+        trainer: &TrainerInfo,
+        rand: &mut dyn FnMut() -> u32,
+    ) -> Result<Pk7, ConvertError> {
+        let species = self.species_t();
+        let personal = personal_info(species, 0).ok_or(ConvertError::UnknownSpecies(species))?;
+        let is_mythical = matches!(species, Species::Mew | Species::Celebi);
+
+        let mut pk7 = Pk7::default();
+        pk7.set_encryption_constant(rand());
+        pk7.set_species(self.species);
+        pk7.set_tid16(self.tid16);
+        pk7.set_sid16(0);
+        pk7.set_exp(personal.growth_rate.exp_for_level(self.level));
+        pk7.set_nature((self.exp % 25) as u8);
+        pk7.set_gender(self.gender() as u8);
+        pk7.set_fateful_encounter(is_mythical);
+
+        let pid = rand();
+        let xor = (pid >> 16) as u16 ^ pid as u16 ^ self.tid16;
+        let pid = match (self.is_shiny(), xor < 16) {
+            (true, _) => (pid & 0xffff) | (u32::from(pid as u16 ^ self.tid16) << 16),
+            (false, true) => pid ^ 0x1000_0000,
+            (false, false) => pid,
+        };
+        pk7.set_pid(pid);
+
+        let ability_number = match DEFAULT_ABILITY_SPECIES.contains(&species) {
+            true => 1,
+            false => 4,
+        };
+        let ability = personal.ability(ability_number).map_or(0, |a| a as u16);
+        pk7.set_ability(ability);
+        pk7.set_ability_number(ability_number);
+
+        let flawless = if is_mythical { 5 } else { 3 };
+        let mut ivs: [u8; 6] = core::array::from_fn(|_| (rand() & 31) as u8);
+        ivs[..flawless].fill(31);
+        for index in (1..ivs.len()).rev() {
+            ivs.swap(index, rand() as usize % (index + 1));
+        }
+        pk7.set_iv_hp(ivs[0]);
+        pk7.set_iv_atk(ivs[1]);
+        pk7.set_iv_def(ivs[2]);
+        pk7.set_iv_spe(ivs[3]);
+        pk7.set_iv_spa(ivs[4]);
+        pk7.set_iv_spd(ivs[5]);
+
+        pk7.set_move1(self.moves[0]);
+        pk7.set_move2(self.moves[1]);
+        pk7.set_move3(self.moves[2]);
+        pk7.set_move4(self.moves[3]);
+        pk7.set_move1_pp(self.move_pp[0]);
+        pk7.set_move2_pp(self.move_pp[1]);
+        pk7.set_move3_pp(self.move_pp[2]);
+        pk7.set_move4_pp(self.move_pp[3]);
+        pk7.set_move1_pp_ups(self.move_pp_ups[0]);
+        pk7.set_move2_pp_ups(self.move_pp_ups[1]);
+        pk7.set_move3_pp_ups(self.move_pp_ups[2]);
+        pk7.set_move4_pp_ups(self.move_pp_ups[3]);
+
+        let nickname = match self.is_nicknamed {
+            true => self.nickname.clone(),
+            false => species_name(species, self.language, &self.nickname),
+        };
+        pk7.set_nickname(&nickname);
+        pk7.set_is_nicknamed(self.is_nicknamed);
+        pk7.set_ot_name(&self.ot_name);
+        pk7.set_ot_gender(self.ot_gender);
+        pk7.set_ot_friendship(personal.friendship(7));
+        pk7.set_language(self.language);
+        pk7.set_version(self.version as u8);
+        pk7.set_ball(Ball::Poke as u8);
+        pk7.set_met_level(self.level);
+        pk7.set_met_location(match self.version.generation() {
+            1 => VC_TRANSFER_LOCATION_GEN1,
+            _ => VC_TRANSFER_LOCATION_GEN2,
+        });
+
+        pk7.set_ht_name(&trainer.ot_name);
+        pk7.set_ht_gender(trainer.gender);
+        pk7.set_current_handler(1);
+
+        pk7.refresh_checksum();
+        Ok(pk7)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::{Ability, Language, Ribbon};
+    use alloc::string::ToString;
+
+    #[test]
+    fn should_keep_the_pokemon() {
+        let mut pk6 = Pk6::default();
+        pk6.set_encryption_constant(0x1234_5678);
+        pk6.set_species(Species::Froakie as u16);
+        pk6.set_nickname("Froakie");
+        pk6.set_ot_name("Calem");
+        pk6.set_ability(Ability::Protean as u16);
+        pk6.set_ability_number(4);
+        pk6.set_contest_cool(20);
+        pk6.set_ribbon(Ribbon::ChampionKalos, true);
+        pk6.write(0x16, 3u8);
+        pk6.set_markings(0b101);
+        pk6.refresh_checksum();
+
+        let pk7 = Pk7::from(&pk6);
+        assert!(pk7.is_valid());
+        assert_eq!(pk7.species_t(), Species::Froakie);
+        assert_eq!(pk7.nickname(), "Froakie");
+        assert_eq!(pk7.ot_name(), "Calem");
+        assert_eq!(pk7.ability_t(), Ability::Protean);
+        assert_eq!(pk7.ability_number(), 4);
+        assert_eq!(pk7.contest_cool(), 20);
+        assert!(pk7.has_ribbon(Ribbon::ChampionKalos));
+        assert_eq!(pk7.read::<u16>(0x16), 0b01_00_01);
+    }
+
+    fn vc_pokemon(species: Species, exp: u32, dvs: [u8; 4]) -> VcPokemon {
+        VcPokemon {
+            species: species as u16,
+            version: GameVersion::Yellow,
+            language: Language::English as u8,
+            level: 5,
+            exp,
+            dvs,
+            moves: [84, 0, 0, 0],
+            move_pp: [30, 0, 0, 0],
+            move_pp_ups: [0; 4],
+            nickname: species.to_string().to_uppercase(),
+            is_nicknamed: false,
+            ot_name: "RED".to_string(),
+            ot_gender: 0,
+            tid16: 12345,
+        }
+    }
+
+    fn trainer() -> TrainerInfo {
+        TrainerInfo {
+            ot_name: "Sun".to_string(),
+            tid16: 1,
+            sid16: 2,
+            gender: 1,
+            language: Language::English as u8,
+            version: GameVersion::Sun,
+        }
+    }
+
+    fn transport(vc: &VcPokemon) -> Pk7 {
+        let mut state = 0x1234_5678u32;
+        let mut rand = || {
+            state = state.wrapping_mul(0x41c6_4e6d).wrapping_add(0x6073);
+            state
+        };
+        vc.transport(&trainer(), &mut rand).unwrap()
+    }
+
+    fn ivs(pk7: &Pk7) -> [u8; 6] {
+        [
+            pk7.iv_hp(),
+            pk7.iv_atk(),
+            pk7.iv_def(),
+            pk7.iv_spe(),
+            pk7.iv_spa(),
+            pk7.iv_spd(),
+        ]
+    }
+
+    #[test]
+    fn should_transport_with_hidden_ability() {
+        let pk7 = transport(&vc_pokemon(Species::Pikachu, 140, [8, 0, 0, 0]));
+        assert!(pk7.is_valid());
+        assert_eq!(pk7.species_t(), Species::Pikachu);
+        assert_eq!(pk7.nickname(), "Pikachu");
+        assert!(!pk7.is_nicknamed());
+        assert_eq!(pk7.ability_t(), Ability::LightningRod);
+        assert_eq!(pk7.ability_number(), 4);
+        assert_eq!(pk7.nature(), 140 % 25);
+        assert_eq!(pk7.exp(), 125);
+        assert_eq!(pk7.gender(), Gender::Male as u8);
+        assert!(ivs(&pk7).iter().filter(|iv| **iv == 31).count() >= 3);
+        assert_eq!(pk7.met_location(), VC_TRANSFER_LOCATION_GEN1);
+        assert_eq!(pk7.met_level(), 5);
+        assert_eq!(pk7.ball(), Ball::Poke as u8);
+        assert_eq!(pk7.ot_name(), "RED");
+        assert_eq!(pk7.tid16(), 12345);
+        assert_eq!(pk7.sid16(), 0);
+        assert_eq!(pk7.ht_name(), "Sun");
+        assert_eq!(pk7.ht_gender(), 1);
+        assert_eq!(pk7.current_handler(), 1);
+        assert_eq!(pk7.move1(), 84);
+        assert_eq!(pk7.ot_friendship(), 70);
+        assert!(!pk7.is_shiny());
+    }
+
+    #[test]
+    fn should_use_localized_names() {
+        let pk7 = transport(&vc_pokemon(Species::HoOh, 135, [0; 4]));
+        assert_eq!(pk7.nickname(), "Ho-Oh");
+
+        let pk7 = transport(&vc_pokemon(Species::FarfetchD, 135, [0; 4]));
+        assert_eq!(pk7.nickname(), "Farfetch\u{2019}d");
+
+        let mut vc = vc_pokemon(Species::Bulbasaur, 135, [0; 4]);
+        vc.language = Language::German as u8;
+        vc.nickname = "BISASAM".to_string();
+        assert_eq!(transport(&vc).nickname(), "Bisasam");
+    }
+
+    #[test]
+    fn should_give_mythicals_five_perfect_ivs() {
+        let mut vc = vc_pokemon(Species::Mew, 135, [0; 4]);
+        vc.version = GameVersion::Crystal;
+        let pk7 = transport(&vc);
+        assert!(ivs(&pk7).iter().filter(|iv| **iv == 31).count() >= 5);
+        assert!(pk7.is_fateful_encounter());
+        assert_eq!(pk7.ability_t(), Ability::Synchronize);
+        assert_eq!(pk7.ability_number(), 1);
+        assert_eq!(pk7.gender(), Gender::Genderless as u8);
+        assert_eq!(pk7.met_location(), VC_TRANSFER_LOCATION_GEN2);
+    }
+
+    #[test]
+    fn should_keep_legendaries_on_their_first_ability() {
+        let pk7 = transport(&vc_pokemon(Species::Articuno, 200, [0; 4]));
+        assert_eq!(pk7.ability_t(), Ability::Pressure);
+        assert!(!pk7.is_fateful_encounter());
+    }
+
+    #[test]
+    fn should_start_at_base_friendship() {
+        let pk7 = transport(&vc_pokemon(Species::Clefairy, 135, [0; 4]));
+        assert_eq!(pk7.ot_friendship(), 140);
+    }
+
+    #[test]
+    fn should_keep_shininess() {
+        let pk7 = transport(&vc_pokemon(Species::Eevee, 135, [2, 10, 10, 10]));
+        assert!(pk7.is_shiny());
+        assert_eq!(pk7.gender(), Gender::Male as u8);
+    }
+
+    #[test]
+    fn should_reject_unknown_species() {
+        let vc = vc_pokemon(Species::None, 135, [0; 4]);
+        assert_eq!(
+            vc.transport(&trainer(), &mut || 0).err(),
+            Some(ConvertError::UnknownSpecies(Species::None))
+        );
+    }
+}
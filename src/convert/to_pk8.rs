@@ -0,0 +1,141 @@
+use super::availability::SWSH;
+use super::{check_species, clear_memories, copy_common, species_from_gen9, ConvertError};
+use crate::pa8::Pa8;
+use crate::pk7::Pk7;
+use crate::pk8::Pk8;
+use crate::pk9::Pk9;
+use crate::pkx::{Pkx, PkxMut};
+
+/// Moves a Pokemon from Poke Bank into Sword and Shield through HOME.
+impl TryFrom<&Pk7> for Pk8 {
+    type Error = ConvertError;
+
+    fn try_from(pk7: &Pk7) -> Result<Self, Self::Error> {
+        check_species(pk7.species(), SWSH)?;
+
+        let mut pk8 = Pk8::default();
+        copy_common(pk7, &mut pk8);
+        pk8.set_minted_nature(pk7.nature());
+        clear_memories(&mut pk8);
+
+        pk8.refresh_checksum();
+        Ok(pk8)
+    }
+}
+
+impl TryFrom<&Pk9> for Pk8 {
+    type Error = ConvertError;
+
+    fn try_from(pk9: &Pk9) -> Result<Self, Self::Error> {
+        let species = species_from_gen9(pk9.species())
+            .ok_or(ConvertError::UnavailableSpecies(pk9.species()))?;
+        check_species(species, SWSH)?;
+
+        let mut pk8 = Pk8::default();
+        copy_common(pk9, &mut pk8);
+        pk8.set_species(species);
+        pk8.set_minted_nature(pk9.minted_nature_t() as u8);
+        pk8.set_height_scalar(pk9.height_scalar());
+        pk8.set_weight_scalar(pk9.weight_scalar());
+
+        pk8.refresh_checksum();
+        Ok(pk8)
+    }
+}
+
+/// Legends: Arceus has no EVs, so its effort levels stay behind and the
+/// Pokemon arrives with whatever EVs it had before it was sent there.
+impl TryFrom<&Pa8> for Pk8 {
+    type Error = ConvertError;
+
+    fn try_from(pa8: &Pa8) -> Result<Self, Self::Error> {
+        check_species(pa8.species(), SWSH)?;
+
+        let mut pk8 = Pk8::default();
+        copy_common(pa8, &mut pk8);
+        pk8.set_minted_nature(pa8.minted_nature_t() as u8);
+        pk8.set_height_scalar(pa8.height_scalar());
+        pk8.set_weight_scalar(pa8.weight_scalar());
+
+        pk8.refresh_checksum();
+        Ok(pk8)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::{Ability, Ribbon, Species};
+
+    #[test]
+    fn should_convert_from_pk7() {
+        let mut pk7 = Pk7::default();
+        pk7.set_encryption_constant(0xcafe_f00d);
+        pk7.set_species(Species::Lucario as u16);
+        pk7.set_nickname("Lucario");
+        pk7.set_ability(Ability::Justified as u16);
+        pk7.set_ability_number(4);
+        pk7.set_nature(3);
+        pk7.set_move1(1);
+        pk7.set_move1_pp(35);
+        pk7.set_ht_name("Hau");
+        pk7.set_ht_memory(4);
+        pk7.set_ribbon(Ribbon::ChampionAlola, true);
+        pk7.refresh_checksum();
+
+        let pk8 = Pk8::try_from(&pk7).unwrap();
+        assert!(pk8.is_valid());
+        assert_eq!(pk8.encryption_constant(), 0xcafe_f00d);
+        assert_eq!(pk8.species_t(), Species::Lucario);
+        assert_eq!(pk8.nickname(), "Lucario");
+        assert_eq!(pk8.ability_t(), Ability::Justified);
+        assert_eq!(pk8.ability_number(), 4);
+        assert_eq!(pk8.minted_nature_t() as u8, 3);
+        assert_eq!(pk8.move1(), 1);
+        assert_eq!(pk8.move1_pp(), 35);
+        assert_eq!(pk8.ht_name(), "Hau");
+        assert_eq!(pk8.ht_memory(), 0);
+        assert!(pk8.has_ribbon(Ribbon::ChampionAlola));
+    }
+
+    #[test]
+    fn should_reject_species_cut_from_the_galar_dex() {
+        let mut pk7 = Pk7::default();
+        pk7.set_species(Species::Bidoof as u16);
+        assert_eq!(
+            Pk8::try_from(&pk7).err(),
+            Some(ConvertError::UnavailableSpecies(399))
+        );
+    }
+
+    #[test]
+    fn should_remap_pk9_species() {
+        let mut pk9 = Pk9::default();
+        pk9.set_species(Species::Eevee as u16);
+        pk9.set_height_scalar(200);
+        assert_eq!(Pk8::try_from(&pk9).unwrap().height_scalar(), 200);
+
+        pk9.set_species(Species::Dudunsparce as u16);
+        assert_eq!(
+            Pk8::try_from(&pk9).err(),
+            Some(ConvertError::UnavailableSpecies(982))
+        );
+    }
+
+    #[test]
+    fn should_reject_hisui_only_species() {
+        let mut pa8 = Pa8::default();
+        pa8.set_species(Species::Kleavor as u16);
+        assert_eq!(
+            Pk8::try_from(&pa8).err(),
+            Some(ConvertError::UnavailableSpecies(900))
+        );
+
+        pa8.set_species(Species::Rowlet as u16);
+        pa8.set_gv_atk(7);
+        pa8.set_ev_atk(12);
+        let pk8 = Pk8::try_from(&pa8).unwrap();
+        assert_eq!(pk8.species_t(), Species::Rowlet);
+        assert_eq!(pk8.ev_atk(), 12);
+    }
+}
@@ -0,0 +1,112 @@
+use super::availability::SV;
+use super::{check_species, copy_common, species_to_gen9, ConvertError};
+use crate::pa8::Pa8;
+use crate::personal::personal_info;
+use crate::pk8::Pk8;
+use crate::pk9::Pk9;
+use crate::pkx::{Pkx, PkxMut};
+use crate::types::TeraType;
+
+/// Creates a Pokemon in Scarlet and Violet's species numbering.  HOME gives it
+/// its species' primary type as its original Tera Type.
+fn new_pk9<T: Pkx>(src: &T) -> Result<Pk9, ConvertError> {
+    let species = check_species(src.species(), SV)?;
+    let info = personal_info(src.species_t(), src.form())
+        .ok_or(ConvertError::UnknownSpecies(src.species_t()))?;
+    let species = species_to_gen9(species).ok_or(ConvertError::UnavailableSpecies(species))?;
+
+    let mut pk9 = Pk9::default();
+    copy_common(src, &mut pk9);
+    pk9.set_species(species);
+    pk9.set_tera_type_original(info.types[0] as u8);
+    pk9.set_tera_type_override(TeraType::OVERRIDE_NONE);
+    Ok(pk9)
+}
+
+/// Moves a Pokemon from Sword and Shield into Scarlet and Violet through HOME.
+impl TryFrom<&Pk8> for Pk9 {
+    type Error = ConvertError;
+
+    fn try_from(pk8: &Pk8) -> Result<Self, Self::Error> {
+        let mut pk9 = new_pk9(pk8)?;
+        pk9.set_minted_nature(pk8.minted_nature_t() as u8);
+        pk9.set_height_scalar(pk8.height_scalar());
+        pk9.set_weight_scalar(pk8.weight_scalar());
+        pk9.set_scale(pk8.height_scalar());
+
+        pk9.refresh_checksum();
+        Ok(pk9)
+    }
+}
+
+/// Moves a Pokemon from Legends: Arceus into Scarlet and Violet through HOME.
+/// The effort levels stay behind like they do when going to Sword and Shield.
+impl TryFrom<&Pa8> for Pk9 {
+    type Error = ConvertError;
+
+    fn try_from(pa8: &Pa8) -> Result<Self, Self::Error> {
+        let mut pk9 = new_pk9(pa8)?;
+        pk9.set_minted_nature(pa8.minted_nature_t() as u8);
+        pk9.set_height_scalar(pa8.height_scalar());
+        pk9.set_weight_scalar(pa8.weight_scalar());
+        pk9.set_scale(pa8.height_scalar());
+
+        pk9.refresh_checksum();
+        Ok(pk9)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::{Ribbon, Species};
+
+    #[test]
+    fn should_convert_from_pk8() {
+        let mut pk8 = Pk8::default();
+        pk8.set_encryption_constant(0x0102_0304);
+        pk8.set_species(Species::Rookidee as u16);
+        pk8.set_nickname("Rookidee");
+        pk8.set_height_scalar(0x80);
+        pk8.set_ribbon(Ribbon::MarkRare, true);
+        pk8.refresh_checksum();
+
+        let pk9 = Pk9::try_from(&pk8).unwrap();
+        assert!(pk9.is_valid());
+        assert_eq!(pk9.species_t(), Species::Rookidee);
+        assert_eq!(pk9.nickname(), "Rookidee");
+        assert_eq!(pk9.scale(), 0x80);
+        assert_eq!(pk9.tera_type_original_t(), TeraType::Flying);
+        assert_eq!(pk9.tera_type_override_t(), None);
+        assert!(pk9.has_ribbon(Ribbon::MarkRare));
+    }
+
+    #[test]
+    fn should_convert_from_pa8() {
+        let mut pa8 = Pa8::default();
+        pa8.set_species(Species::Growlithe as u16);
+        pa8.set_form(1);
+        pa8.set_height_scalar(12);
+
+        let pk9 = Pk9::try_from(&pa8).unwrap();
+        assert!(pk9.is_valid());
+        assert_eq!(pk9.species_t(), Species::Growlithe);
+        assert_eq!(pk9.form(), 1);
+        assert_eq!(pk9.scale(), 12);
+        assert_eq!(pk9.tera_type_original_t(), TeraType::Fire);
+
+        pa8.set_species(Species::Bidoof as u16);
+        assert_eq!(
+            Pk9::try_from(&pa8).err(),
+            Some(ConvertError::UnavailableSpecies(399))
+        );
+    }
+
+    #[test]
+    fn should_reject_empty_slots() {
+        assert_eq!(
+            Pk9::try_from(&Pk8::default()).err(),
+            Some(ConvertError::UnavailableSpecies(0))
+        );
+    }
+}
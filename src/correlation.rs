@@ -0,0 +1,366 @@
+use crate::encounters::{self, Encounter, EncounterKind, ShinyRule};
+use crate::pkx::Pkx;
+use crate::rng::{pid_matches, pkx_ivs, raid8_seeds, raid9_seed, roll_ivs, Xoroshiro128Plus};
+use crate::types::GameVersion;
+use alloc::vec::Vec;
+
+/// A way Gen6 and later games generate a Pokemon's EC, PID and IVs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CorrelationMethod {
+    /// Fixed encounters, like Gen6 and Gen7 legendaries and Sword and Shield's
+    /// scripted ones.  Every value is drawn on its own, so only the perfect IVs
+    /// and shiny lock can be checked.
+    Fixed { flawless_ivs: u8, shiny: ShinyRule },
+    /// Sword and Shield overworld spawns, which draw the EC, PID and IVs from
+    /// one 32-bit seed.
+    Overworld8 { flawless_ivs: u8, shiny: ShinyRule },
+    /// Sword and Shield Max Raids, which draw from a 64-bit seed.
+    Raid8 { flawless_ivs: u8 },
+    /// Scarlet and Violet Tera raids and fixed encounters, which draw from a
+    /// 32-bit seed.
+    Encounter9 { flawless_ivs: u8, shiny: ShinyRule },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CorrelationMismatch {
+    /// Fewer IVs are perfect than the method guarantees.
+    FlawlessIvs { expected: u8, found: u8 },
+    /// The Pokemon's shininess isn't allowed.
+    Shiny,
+    /// No seed generates the Pokemon's EC, PID and IVs.
+    Seed,
+}
+
+/// The result of checking a Pokemon against the methods of its encounters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Correlation {
+    /// The Pokemon fits `method`, with the seed it came from if the method has
+    /// one.
+    Match {
+        method: CorrelationMethod,
+        seed: Option<u64>,
+    },
+    Mismatch {
+        method: CorrelationMethod,
+        reason: CorrelationMismatch,
+    },
+    /// None of the Pokemon's known encounters has a method to check.
+    Unknown,
+}
+
+impl CorrelationMethod {
+    /// The method an encounter in `version` uses, if it has one that can be
+    /// checked.  Gen7 SOS chains draw IVs after the fact, so wild Gen6 and Gen7
+    /// encounters are left out, as are Sword and Shield's hidden encounters.
+    pub fn for_encounter(encounter: &Encounter, version: GameVersion) -> Option<Self> {
+        let flawless_ivs = encounter.flawless_ivs;
+        let shiny = encounter.shiny;
+
+        match (version.generation(), encounter.kind) {
+            (8, EncounterKind::Wild | EncounterKind::Static) if encounter.overworld => {
+                Some(Self::Overworld8 {
+                    flawless_ivs,
+                    shiny,
+                })
+            }
+            (6..=8, EncounterKind::Static | EncounterKind::Gift)
+                if flawless_ivs > 0 || shiny != ShinyRule::Random =>
+            {
+                Some(Self::Fixed {
+                    flawless_ivs,
+                    shiny,
+                })
+            }
+            (8, EncounterKind::Raid) => Some(Self::Raid8 { flawless_ivs }),
+            (9, EncounterKind::Static | EncounterKind::TeraRaid) => Some(Self::Encounter9 {
+                flawless_ivs,
+                shiny,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn flawless_ivs(&self) -> u8 {
+        match self {
+            Self::Fixed { flawless_ivs, .. }
+            | Self::Overworld8 { flawless_ivs, .. }
+            | Self::Raid8 { flawless_ivs }
+            | Self::Encounter9 { flawless_ivs, .. } => *flawless_ivs,
+        }
+    }
+
+    pub fn shiny(&self) -> ShinyRule {
+        match self {
+            Self::Fixed { shiny, .. }
+            | Self::Overworld8 { shiny, .. }
+            | Self::Encounter9 { shiny, .. } => *shiny,
+            Self::Raid8 { .. } => ShinyRule::Random,
+        }
+    }
+
+    /// Checks `pkm` against the method, returning the seed it came from if
+    /// the method has one.
+    pub fn check<T: Pkx>(&self, pkm: &T) -> Result<Option<u64>, CorrelationMismatch> {
+        let expected = self.flawless_ivs();
+        let found = pkx_ivs(pkm).iter().filter(|iv| **iv == 31).count() as u8;
+        if found < expected {
+            return Err(CorrelationMismatch::FlawlessIvs { expected, found });
+        }
+
+        if !self.shiny().allows(pkm.shiny_xor() < 16) {
+            return Err(CorrelationMismatch::Shiny);
+        }
+
+        let seed = match self {
+            Self::Fixed { .. } => return Ok(None),
+            Self::Overworld8 { flawless_ivs, .. } => overworld8_seed(pkm, *flawless_ivs),
+            Self::Raid8 { flawless_ivs } => raid8_seeds(pkm, *flawless_ivs..=*flawless_ivs)
+                .first()
+                .copied(),
+            Self::Encounter9 { flawless_ivs, .. } => {
+                raid9_seed(pkm, *flawless_ivs..=*flawless_ivs).map(u64::from)
+            }
+        };
+
+        seed.map(Some).ok_or(CorrelationMismatch::Seed)
+    }
+}
+
+/// Overworld seeds are 32 bits and draw the EC first, so like Tera raids the
+/// seed comes straight from the EC.  There's no random trainer ID in between.
+fn overworld8_seed<T: Pkx>(pkm: &T, flawless_ivs: u8) -> Option<u64> {
+    let seed = pkm
+        .encryption_constant()
+        .wrapping_sub(Xoroshiro128Plus::SEED_CONSTANT as u32) as u64;
+
+    let mut rng = Xoroshiro128Plus::new(seed);
+    let encryption_constant = rng.next_int(0xffffffff) as u32;
+    let drawn_pid = rng.next_int(0xffffffff) as u32;
+
+    let is_match = encryption_constant == pkm.encryption_constant()
+        && pid_matches(drawn_pid, pkm.pid(), pkm.shiny_xor() < 16)
+        && roll_ivs(&mut rng, flawless_ivs) == pkx_ivs(pkm);
+    is_match.then_some(seed)
+}
+
+/// Checks `pkm` against the methods of the encounters it could come from,
+/// preferring a match over a mismatch.
+pub fn check_correlation<T: Pkx>(pkm: &T) -> Correlation {
+    let version = pkm.version_t();
+    let methods: Vec<CorrelationMethod> = encounters::encounters(version)
+        .filter(|encounter| {
+            encounter.species == pkm.species_t()
+                && encounter.form == pkm.form()
+                && (encounter.locations.is_empty()
+                    || encounter.locations.contains(&pkm.met_location()))
+        })
+        .filter_map(|encounter| CorrelationMethod::for_encounter(encounter, version))
+        .collect();
+
+    let mut result = Correlation::Unknown;
+    for method in methods {
+        match method.check(pkm) {
+            Ok(seed) => return Correlation::Match { method, seed },
+            Err(reason) if result == Correlation::Unknown => {
+                result = Correlation::Mismatch { method, reason };
+            }
+            Err(_) => {}
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pk6::Pk6;
+    use crate::pk8::Pk8;
+    use crate::pk9::Pk9;
+    use crate::pkx::PkxMut;
+    use crate::rng::{Raid9Encounter, RaidTeraType};
+    use crate::types::{Species, TrainerInfo};
+    use alloc::string::ToString;
+
+    fn set_ivs<T: PkxMut>(pkm: &mut T, ivs: [u8; 6]) {
+        let [hp, atk, def, spe, spa, spd] = ivs;
+        pkm.set_iv_hp(hp);
+        pkm.set_iv_atk(atk);
+        pkm.set_iv_def(def);
+        pkm.set_iv_spe(spe);
+        pkm.set_iv_spa(spa);
+        pkm.set_iv_spd(spd);
+    }
+
+    /// Generates an overworld Pokemon from `seed` the way Sword and Shield do.
+    fn overworld8(species: Species, seed: u32, flawless_ivs: u8) -> Pk8 {
+        let mut pk8 = Pk8::default();
+        pk8.set_species(species as u16);
+        pk8.set_version(GameVersion::Sword as u8);
+        pk8.set_tid16(1);
+        pk8.set_sid16(2);
+
+        let mut rng = Xoroshiro128Plus::new(seed as u64);
+        pk8.set_encryption_constant(rng.next_int(0xffffffff) as u32);
+        let pid = rng.next_int(0xffffffff) as u32;
+        pk8.set_pid(pid);
+        if pk8.shiny_xor() < 16 {
+            pk8.set_pid(pid ^ 0x1000_0000);
+        }
+        set_ivs(&mut pk8, roll_ivs(&mut rng, flawless_ivs));
+        pk8.refresh_checksum();
+        pk8
+    }
+
+    #[test]
+    fn should_match_overworld8_seeds() {
+        let mut pk8 = overworld8(Species::Wooloo, 0xdead_beef, 0);
+        pk8.set_met_location(12);
+        assert_eq!(
+            check_correlation(&pk8),
+            Correlation::Match {
+                method: CorrelationMethod::Overworld8 {
+                    flawless_ivs: 0,
+                    shiny: ShinyRule::Random,
+                },
+                seed: Some(0xdead_beef),
+            }
+        );
+
+        let method = CorrelationMethod::Overworld8 {
+            flawless_ivs: 3,
+            shiny: ShinyRule::Never,
+        };
+        let pk8 = overworld8(Species::Zacian, 0x1234_5678, 3);
+        assert_eq!(method.check(&pk8), Ok(Some(0x1234_5678)));
+    }
+
+    #[test]
+    fn should_reject_unrelated_ivs() {
+        let mut pk8 = overworld8(Species::Wooloo, 0x1234_5678, 0);
+        pk8.set_met_location(12);
+        let ivs = pkx_ivs(&pk8).map(|iv| iv ^ 1);
+        set_ivs(&mut pk8, ivs);
+        assert_eq!(
+            check_correlation(&pk8),
+            Correlation::Mismatch {
+                method: CorrelationMethod::Overworld8 {
+                    flawless_ivs: 0,
+                    shiny: ShinyRule::Random,
+                },
+                reason: CorrelationMismatch::Seed,
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_correlate_scripted_encounters() {
+        let mut pk8 = overworld8(Species::Zacian, 0x1234_5678, 3);
+        pk8.set_met_location(66);
+        let ivs = pkx_ivs(&pk8).map(|iv| if iv == 31 { 31 } else { iv ^ 1 });
+        set_ivs(&mut pk8, ivs);
+        assert_eq!(
+            check_correlation(&pk8),
+            Correlation::Match {
+                method: CorrelationMethod::Fixed {
+                    flawless_ivs: 3,
+                    shiny: ShinyRule::Never,
+                },
+                seed: None,
+            }
+        );
+
+        let gift = encounters::encounters(GameVersion::Sword)
+            .find(|encounter| encounter.species == Species::Grookey)
+            .unwrap();
+        assert_eq!(
+            CorrelationMethod::for_encounter(gift, GameVersion::Sword),
+            None
+        );
+    }
+
+    #[test]
+    fn should_check_fixed_legendaries() {
+        let mut pk6 = Pk6::default();
+        pk6.set_species(Species::Xerneas as u16);
+        pk6.set_version(GameVersion::X as u8);
+        pk6.set_met_location(138);
+        pk6.set_pid(0x1234_5678);
+        set_ivs(&mut pk6, [31, 31, 31, 0, 0, 0]);
+
+        let method = CorrelationMethod::Fixed {
+            flawless_ivs: 3,
+            shiny: ShinyRule::Never,
+        };
+        assert_eq!(
+            check_correlation(&pk6),
+            Correlation::Match { method, seed: None }
+        );
+
+        set_ivs(&mut pk6, [31, 31, 0, 0, 0, 0]);
+        assert_eq!(
+            check_correlation(&pk6),
+            Correlation::Mismatch {
+                method,
+                reason: CorrelationMismatch::FlawlessIvs {
+                    expected: 3,
+                    found: 2
+                },
+            }
+        );
+
+        set_ivs(&mut pk6, [31; 6]);
+        pk6.set_pid(0x5678_5678);
+        assert_eq!(
+            check_correlation(&pk6),
+            Correlation::Mismatch {
+                method,
+                reason: CorrelationMismatch::Shiny,
+            }
+        );
+    }
+
+    #[test]
+    fn should_match_encounter9_seeds() {
+        let trainer = TrainerInfo {
+            ot_name: "Arven".to_string(),
+            tid16: 3,
+            sid16: 4,
+            gender: 0,
+            language: 2,
+            version: GameVersion::Violet,
+        };
+        let encounter = Raid9Encounter {
+            species: Species::TingLu,
+            form: 0,
+            stars: 5,
+            rate: 1,
+            tera_type: RaidTeraType::Default,
+            flawless_ivs: 3,
+            ability_type: 0,
+            shiny: ShinyRule::Never,
+            nature: None,
+            scale: None,
+        };
+
+        let mut pk9 = Pk9::default();
+        pk9.set_version(GameVersion::Violet as u8);
+        pk9.set_tid16(trainer.tid16);
+        pk9.set_sid16(trainer.sid16);
+        encounter.generate(0xabcd_ef01, &trainer).apply_to(&mut pk9);
+        assert!(matches!(
+            check_correlation(&pk9),
+            Correlation::Match {
+                seed: Some(0xabcd_ef01),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn should_skip_encounters_without_methods() {
+        let mut pk6 = Pk6::default();
+        pk6.set_species(Species::Froakie as u16);
+        pk6.set_version(GameVersion::X as u8);
+        assert_eq!(check_correlation(&pk6), Correlation::Unknown);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::types::Species;
+
+/// Species that never hatch from an egg: Ditto, and the legendary, mythical,
+/// Ultra Beast and Paradox species of the Undiscovered egg group.  Babies are
+/// also Undiscovered but hatch from their parents' eggs, and Manaphy hatches
+/// from its gift egg, so they aren't listed.
+#[rustfmt::skip]
+const UNHATCHABLE: [Species; 109] = [
+    Species::Ditto, Species::Articuno, Species::Zapdos, Species::Moltres, Species::Mewtwo,
+    Species::Mew, Species::Unown, Species::Raikou, Species::Entei, Species::Suicune,
+    Species::Lugia, Species::HoOh, Species::Celebi, Species::Regirock, Species::Regice,
+    Species::Registeel, Species::Latias, Species::Latios, Species::Kyogre, Species::Groudon,
+    Species::Rayquaza, Species::Jirachi, Species::Deoxys, Species::Uxie, Species::Mesprit,
+    Species::Azelf, Species::Dialga, Species::Palkia, Species::Heatran, Species::Regigigas,
+    Species::Giratina, Species::Cresselia, Species::Darkrai, Species::Shaymin, Species::Arceus,
+    Species::Victini, Species::Cobalion, Species::Terrakion, Species::Virizion, Species::Tornadus,
+    Species::Thundurus, Species::Reshiram, Species::Zekrom, Species::Landorus, Species::Kyurem,
+    Species::Keldeo, Species::Meloetta, Species::Genesect, Species::Xerneas, Species::Yveltal,
+    Species::Zygarde, Species::Diancie, Species::Hoopa, Species::Volcanion, Species::TypeNull,
+    Species::TapuKoko, Species::TapuLele, Species::TapuBulu, Species::TapuFini, Species::Cosmog,
+    Species::Nihilego, Species::Buzzwole, Species::Pheromosa, Species::Xurkitree, Species::Celesteela,
+    Species::Kartana, Species::Guzzlord, Species::Necrozma, Species::Magearna, Species::Marshadow,
+    Species::Poipole, Species::Stakataka, Species::Blacephalon, Species::Zeraora, Species::Meltan,
+    Species::Zacian, Species::Zamazenta, Species::Eternatus, Species::Kubfu, Species::Zarude,
+    Species::Regieleki, Species::Regidrago, Species::Glastrier, Species::Spectrier, Species::Calyrex,
+    Species::Enamorus, Species::Gimmighoul, Species::GreatTusk, Species::BruteBonnet, Species::Aketuban,
+    Species::SandyShocks, Species::ScreamTail, Species::FlutterMane, Species::SlitherWing, Species::RoaringMoon,
+    Species::IronTreads, Species::Bketuban, Species::IronMoth, Species::IronHands, Species::IronJugulis,
+    Species::IronThorns, Species::IronBundle, Species::IronValiant, Species::TingLu, Species::ChienPao,
+    Species::WoChien, Species::ChiYu, Species::Koraidon, Species::Miraidon,
+];
+
+/// Whether a base species can hatch from an egg.
+pub(super) fn can_hatch(species: Species) -> bool {
+    !UNHATCHABLE.contains(&species)
+}
@@ -0,0 +1,30 @@
+use super::{Encounter, EncounterKind, ShinyRule};
+use crate::types::{Ball, GameVersion, Species};
+
+const XY: &[GameVersion] = &[GameVersion::X, GameVersion::Y];
+const ORAS: &[GameVersion] = &[GameVersion::OmegaRuby, GameVersion::AlphaSapphire];
+
+/// Encounters available in X/Y and Omega Ruby/Alpha Sapphire.
+#[rustfmt::skip]
+pub(super) const ENCOUNTERS: [Encounter; 20] = [
+    Encounter { kind: EncounterKind::Gift, species: Species::Chespin, form: 0, versions: XY, locations: &[10], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Fennekin, form: 0, versions: XY, locations: &[10], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Froakie, form: 0, versions: XY, locations: &[10], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Bulbasaur, form: 0, versions: XY, locations: &[], level_min: 10, level_max: 10, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Charmander, form: 0, versions: XY, locations: &[], level_min: 10, level_max: 10, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Squirtle, form: 0, versions: XY, locations: &[], level_min: 10, level_max: 10, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Lucario, form: 0, versions: XY, locations: &[], level_min: 32, level_max: 32, ball: Some(Ball::Poke), shiny: ShinyRule::Never, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Xerneas, form: 0, versions: &[GameVersion::X], locations: &[138], level_min: 50, level_max: 50, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Yveltal, form: 0, versions: &[GameVersion::Y], locations: &[138], level_min: 50, level_max: 50, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Zygarde, form: 0, versions: XY, locations: &[], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Random, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Snorlax, form: 0, versions: XY, locations: &[], level_min: 15, level_max: 15, ball: None, shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Treecko, form: 0, versions: ORAS, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Torchic, form: 0, versions: ORAS, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Mudkip, form: 0, versions: ORAS, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Beldum, form: 0, versions: ORAS, locations: &[], level_min: 1, level_max: 1, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Groudon, form: 0, versions: &[GameVersion::OmegaRuby], locations: &[], level_min: 45, level_max: 45, ball: None, shiny: ShinyRule::Random, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Kyogre, form: 0, versions: &[GameVersion::AlphaSapphire], locations: &[], level_min: 45, level_max: 45, ball: None, shiny: ShinyRule::Random, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Rayquaza, form: 0, versions: ORAS, locations: &[], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Random, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Deoxys, form: 0, versions: ORAS, locations: &[], level_min: 80, level_max: 80, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Regigigas, form: 0, versions: ORAS, locations: &[], level_min: 50, level_max: 50, ball: None, shiny: ShinyRule::Random, flawless_ivs: 3, overworld: false },
+];
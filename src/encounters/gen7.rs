@@ -0,0 +1,29 @@
+use super::{Encounter, EncounterKind, ShinyRule};
+use crate::types::{Ball, GameVersion, Species};
+
+const SM: &[GameVersion] = &[GameVersion::Sun, GameVersion::Moon];
+const ALOLA: &[GameVersion] = &[
+    GameVersion::Sun,
+    GameVersion::Moon,
+    GameVersion::UltraSun,
+    GameVersion::UltraMoon,
+];
+
+/// Encounters available in Sun/Moon and Ultra Sun/Ultra Moon.
+#[rustfmt::skip]
+pub(super) const ENCOUNTERS: [Encounter; 14] = [
+    Encounter { kind: EncounterKind::Gift, species: Species::Rowlet, form: 0, versions: ALOLA, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Litten, form: 0, versions: ALOLA, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Popplio, form: 0, versions: ALOLA, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Cosmog, form: 0, versions: ALOLA, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::TypeNull, form: 0, versions: ALOLA, locations: &[], level_min: 40, level_max: 40, ball: Some(Ball::Poke), shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Solgaleo, form: 0, versions: &[GameVersion::Sun], locations: &[], level_min: 55, level_max: 55, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Lunala, form: 0, versions: &[GameVersion::Moon], locations: &[], level_min: 55, level_max: 55, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Solgaleo, form: 0, versions: &[GameVersion::UltraSun], locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Lunala, form: 0, versions: &[GameVersion::UltraMoon], locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Necrozma, form: 0, versions: SM, locations: &[], level_min: 75, level_max: 75, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::TapuKoko, form: 0, versions: ALOLA, locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::TapuLele, form: 0, versions: ALOLA, locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::TapuBulu, form: 0, versions: ALOLA, locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::TapuFini, form: 0, versions: ALOLA, locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+];
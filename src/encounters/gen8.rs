@@ -0,0 +1,28 @@
+use super::{Encounter, EncounterKind, ShinyRule};
+use crate::types::{Ball, GameVersion, Species};
+
+const SWSH: &[GameVersion] = &[GameVersion::Sword, GameVersion::Shield];
+
+/// Encounters available in Sword/Shield.
+#[rustfmt::skip]
+pub(super) const ENCOUNTERS: [Encounter; 19] = [
+    Encounter { kind: EncounterKind::Gift, species: Species::Grookey, form: 0, versions: SWSH, locations: &[6], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Scorbunny, form: 0, versions: SWSH, locations: &[6], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Sobble, form: 0, versions: SWSH, locations: &[6], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Random, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Kubfu, form: 0, versions: SWSH, locations: &[], level_min: 10, level_max: 10, ball: Some(Ball::Poke), shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Wild, species: Species::Skwovet, form: 0, versions: SWSH, locations: &[12], level_min: 2, level_max: 5, ball: None, shiny: ShinyRule::Random, flawless_ivs: 0, overworld: true },
+    Encounter { kind: EncounterKind::Wild, species: Species::Rookidee, form: 0, versions: SWSH, locations: &[12], level_min: 2, level_max: 5, ball: None, shiny: ShinyRule::Random, flawless_ivs: 0, overworld: true },
+    Encounter { kind: EncounterKind::Wild, species: Species::Wooloo, form: 0, versions: SWSH, locations: &[12], level_min: 2, level_max: 5, ball: None, shiny: ShinyRule::Random, flawless_ivs: 0, overworld: true },
+    Encounter { kind: EncounterKind::Static, species: Species::Zacian, form: 0, versions: SWSH, locations: &[66], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Zamazenta, form: 0, versions: SWSH, locations: &[66], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Eternatus, form: 0, versions: SWSH, locations: &[66], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Regieleki, form: 0, versions: SWSH, locations: &[], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Regidrago, form: 0, versions: SWSH, locations: &[], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Glastrier, form: 0, versions: SWSH, locations: &[], level_min: 75, level_max: 75, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Spectrier, form: 0, versions: SWSH, locations: &[], level_min: 75, level_max: 75, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Calyrex, form: 0, versions: SWSH, locations: &[], level_min: 80, level_max: 80, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Raid, species: Species::Articuno, form: 0, versions: SWSH, locations: &[244], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Random, flawless_ivs: 4, overworld: false },
+    Encounter { kind: EncounterKind::Raid, species: Species::Mewtwo, form: 0, versions: SWSH, locations: &[244], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Random, flawless_ivs: 4, overworld: false },
+    Encounter { kind: EncounterKind::Raid, species: Species::Zapdos, form: 0, versions: SWSH, locations: &[244], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Random, flawless_ivs: 4, overworld: false },
+    Encounter { kind: EncounterKind::Raid, species: Species::Moltres, form: 0, versions: SWSH, locations: &[244], level_min: 70, level_max: 70, ball: None, shiny: ShinyRule::Random, flawless_ivs: 4, overworld: false },
+];
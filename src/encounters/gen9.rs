@@ -0,0 +1,26 @@
+use super::{Encounter, EncounterKind, ShinyRule};
+use crate::locations::TERA_RAID;
+use crate::types::{Ball, GameVersion, Species};
+
+const SV: &[GameVersion] = &[GameVersion::Scarlet, GameVersion::Violet];
+
+/// Encounters available in Scarlet/Violet.  Six-star Tera raids are level 90
+/// with five perfect IVs.
+#[rustfmt::skip]
+pub(super) const ENCOUNTERS: [Encounter; 15] = [
+    Encounter { kind: EncounterKind::Gift, species: Species::Sprigatito, form: 0, versions: SV, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Never, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Fuecoco, form: 0, versions: SV, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Never, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Gift, species: Species::Quaxly, form: 0, versions: SV, locations: &[], level_min: 5, level_max: 5, ball: Some(Ball::Poke), shiny: ShinyRule::Never, flawless_ivs: 0, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::TingLu, form: 0, versions: SV, locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::ChienPao, form: 0, versions: SV, locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::WoChien, form: 0, versions: SV, locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::ChiYu, form: 0, versions: SV, locations: &[], level_min: 60, level_max: 60, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Koraidon, form: 0, versions: &[GameVersion::Scarlet], locations: &[], level_min: 72, level_max: 72, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::Static, species: Species::Miraidon, form: 0, versions: &[GameVersion::Violet], locations: &[], level_min: 72, level_max: 72, ball: None, shiny: ShinyRule::Never, flawless_ivs: 3, overworld: false },
+    Encounter { kind: EncounterKind::TeraRaid, species: Species::Dragonite, form: 0, versions: SV, locations: &[TERA_RAID], level_min: 90, level_max: 90, ball: None, shiny: ShinyRule::Random, flawless_ivs: 5, overworld: false },
+    Encounter { kind: EncounterKind::TeraRaid, species: Species::Tyranitar, form: 0, versions: SV, locations: &[TERA_RAID], level_min: 90, level_max: 90, ball: None, shiny: ShinyRule::Random, flawless_ivs: 5, overworld: false },
+    Encounter { kind: EncounterKind::TeraRaid, species: Species::Salamence, form: 0, versions: SV, locations: &[TERA_RAID], level_min: 90, level_max: 90, ball: None, shiny: ShinyRule::Random, flawless_ivs: 5, overworld: false },
+    Encounter { kind: EncounterKind::TeraRaid, species: Species::Garchomp, form: 0, versions: SV, locations: &[TERA_RAID], level_min: 90, level_max: 90, ball: None, shiny: ShinyRule::Random, flawless_ivs: 5, overworld: false },
+    Encounter { kind: EncounterKind::TeraRaid, species: Species::Hydreigon, form: 0, versions: SV, locations: &[TERA_RAID], level_min: 90, level_max: 90, ball: None, shiny: ShinyRule::Random, flawless_ivs: 5, overworld: false },
+    Encounter { kind: EncounterKind::TeraRaid, species: Species::Baxcalibur, form: 0, versions: SV, locations: &[TERA_RAID], level_min: 90, level_max: 90, ball: None, shiny: ShinyRule::Random, flawless_ivs: 5, overworld: false },
+];
@@ -0,0 +1,367 @@
+mod breeding;
+mod gen6;
+mod gen7;
+mod gen8;
+mod gen9;
+
+use crate::evolutions;
+use crate::locations;
+use crate::pkx::Pkx;
+use crate::types::{Ball, GameVersion, Species};
+use crate::wondercards::{GiftShiny, Wondercard};
+use alloc::vec::Vec;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EncounterKind {
+    Wild,
+    Static,
+    Gift,
+    /// Gen8 Max Raid Battles and Dynamax Adventures.
+    Raid,
+    TeraRaid,
+    /// Wondercard gifts, which [EncounterMatcher::gift_candidates] matches.
+    MysteryGift,
+    /// In-game trades, which the tables don't list yet.
+    Trade,
+    Egg,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShinyRule {
+    Random,
+    Never,
+    Always,
+}
+
+impl ShinyRule {
+    pub fn allows(&self, is_shiny: bool) -> bool {
+        match self {
+            Self::Random => true,
+            Self::Never => !is_shiny,
+            Self::Always => is_shiny,
+        }
+    }
+}
+
+/// A way to obtain a Pokemon.
+///
+/// Empty `versions` or `locations` mean the encounter isn't restricted by them,
+/// either because it can happen anywhere (eggs) or because the location isn't
+/// tracked yet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Encounter {
+    pub kind: EncounterKind,
+    pub species: Species,
+    pub form: u8,
+    pub versions: &'static [GameVersion],
+    pub locations: &'static [u16],
+    pub level_min: u8,
+    pub level_max: u8,
+    /// The ball the Pokemon is always in, if the player doesn't choose it.
+    pub ball: Option<Ball>,
+    pub shiny: ShinyRule,
+    pub flawless_ivs: u8,
+    /// Whether the Pokemon spawns in the Sword and Shield overworld, which draws
+    /// the EC, PID and IVs from one seed.  Gifts, scripted encounters and other
+    /// games' encounters don't.
+    pub overworld: bool,
+}
+
+fn game_encounters(version: GameVersion) -> &'static [Encounter] {
+    match version {
+        GameVersion::X | GameVersion::Y | GameVersion::AlphaSapphire | GameVersion::OmegaRuby => {
+            &gen6::ENCOUNTERS
+        }
+        GameVersion::Sun | GameVersion::Moon | GameVersion::UltraSun | GameVersion::UltraMoon => {
+            &gen7::ENCOUNTERS
+        }
+        GameVersion::Sword | GameVersion::Shield => &gen8::ENCOUNTERS,
+        GameVersion::Scarlet | GameVersion::Violet => &gen9::ENCOUNTERS,
+        _ => &[],
+    }
+}
+
+/// Lists the known encounters for a game.  The tables are partial.
+pub fn encounters(version: GameVersion) -> impl Iterator<Item = &'static Encounter> {
+    game_encounters(version).iter().filter(move |encounter| {
+        encounter.versions.is_empty() || encounter.versions.contains(&version)
+    })
+}
+
+/// Finds the encounters a Pokemon could have come from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EncounterMatcher {
+    pub species: Species,
+    pub form: u8,
+    pub version: GameVersion,
+    pub met_location: u16,
+    pub egg_location: u16,
+    pub met_level: u8,
+    pub ball: Ball,
+    pub is_shiny: bool,
+    pub flawless_ivs: u8,
+}
+
+impl EncounterMatcher {
+    pub fn new<T: Pkx>(pkm: &T) -> Self {
+        let ivs = [
+            pkm.iv_hp(),
+            pkm.iv_atk(),
+            pkm.iv_def(),
+            pkm.iv_spa(),
+            pkm.iv_spd(),
+            pkm.iv_spe(),
+        ];
+
+        Self {
+            species: pkm.species_t(),
+            form: pkm.form(),
+            version: pkm.version_t(),
+            met_location: pkm.met_location(),
+            egg_location: pkm.egg_location(),
+            met_level: pkm.met_level(),
+            ball: pkm.ball_t(),
+            is_shiny: pkm.is_shiny(),
+            flawless_ivs: ivs.iter().filter(|iv| **iv == 31).count() as u8,
+        }
+    }
+
+    fn is_hatched(&self) -> bool {
+        self.egg_location != locations::NO_LOCATION && self.egg_location != u16::MAX
+    }
+
+    fn matches_species(&self, encounter: &Encounter) -> bool {
+        if encounter.species == self.species {
+            return encounter.form == self.form;
+        }
+
+        evolutions::can_evolve_into(encounter.species, self.species)
+    }
+
+    pub fn is_match(&self, encounter: &Encounter) -> bool {
+        self.matches_species(encounter)
+            && (encounter.versions.is_empty() || encounter.versions.contains(&self.version))
+            && (encounter.locations.is_empty() || encounter.locations.contains(&self.met_location))
+            && (encounter.level_min..=encounter.level_max).contains(&self.met_level)
+            && encounter.ball.is_none_or(|ball| ball == self.ball)
+            && encounter.shiny.allows(self.is_shiny)
+            && self.flawless_ivs >= encounter.flawless_ivs
+    }
+
+    fn egg_encounter(&self) -> Encounter {
+        let (species, form) = evolutions::base_species(self.species, self.form);
+
+        Encounter {
+            kind: EncounterKind::Egg,
+            species,
+            form,
+            versions: &[],
+            locations: &[],
+            level_min: 1,
+            level_max: 1,
+            ball: None,
+            shiny: ShinyRule::Random,
+            flawless_ivs: 0,
+            overworld: false,
+        }
+    }
+
+    fn gift_encounter<W: Wondercard>(card: &W) -> Encounter {
+        let ivs = card.ivs();
+        let flawless_ivs = match ivs[0] {
+            code @ 0xfc..=0xfe => code - 0xfb,
+            _ => ivs.iter().filter(|iv| **iv == 31).count() as u8,
+        };
+
+        Encounter {
+            kind: EncounterKind::MysteryGift,
+            species: card.species_t(),
+            form: card.form(),
+            versions: &[],
+            locations: &[],
+            level_min: card.met_level(),
+            level_max: card.met_level(),
+            ball: Some(match card.ball() {
+                0 => Ball::Poke,
+                ball => Ball::from(ball),
+            }),
+            shiny: match card.gift_shiny() {
+                GiftShiny::Never => ShinyRule::Never,
+                GiftShiny::Always | GiftShiny::AlwaysStar | GiftShiny::AlwaysSquare => {
+                    ShinyRule::Always
+                }
+                GiftShiny::Fixed | GiftShiny::Random => ShinyRule::Random,
+            },
+            flawless_ivs,
+            overworld: false,
+        }
+    }
+
+    /// Lists the Pokemon gifts in `cards` the Pokemon could have come from,
+    /// as mystery gift encounters.
+    pub fn gift_candidates<W: Wondercard>(&self, cards: &[W]) -> Vec<Encounter> {
+        if self.is_hatched() {
+            return Vec::new();
+        }
+
+        cards
+            .iter()
+            .filter(|card| {
+                card.is_pokemon()
+                    && !card.is_egg()
+                    && card.met_location() == self.met_location
+                    && (card.origin_game() == 0 || card.origin_game() == self.version as u8)
+            })
+            .map(Self::gift_encounter)
+            .filter(|encounter| self.is_match(encounter))
+            .collect()
+    }
+
+    /// Lists the encounters consistent with the Pokemon.
+    ///
+    /// Hatched Pokemon only match an egg of their base species, since the met
+    /// data of a hatched Pokemon describes where it hatched.  Species that
+    /// can't breed, like most legendaries, never match an egg.
+    pub fn candidates(&self) -> Vec<Encounter> {
+        if self.is_hatched() {
+            let egg = self.egg_encounter();
+            return if breeding::can_hatch(egg.species) && self.is_match(&egg) {
+                alloc::vec![egg]
+            } else {
+                Vec::new()
+            };
+        }
+
+        encounters(self.version)
+            .filter(|encounter| self.is_match(encounter))
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wondercards::Wc8;
+    use crate::writer::Writer;
+
+    fn matcher(
+        species: Species,
+        version: GameVersion,
+        met_location: u16,
+        met_level: u8,
+    ) -> EncounterMatcher {
+        EncounterMatcher {
+            species,
+            form: 0,
+            version,
+            met_location,
+            egg_location: locations::NO_LOCATION,
+            met_level,
+            ball: Ball::Poke,
+            is_shiny: false,
+            flawless_ivs: 3,
+        }
+    }
+
+    #[test]
+    fn should_match_gifts() {
+        let candidates = matcher(Species::Froakie, GameVersion::X, 10, 5).candidates();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].kind, EncounterKind::Gift);
+    }
+
+    #[test]
+    fn should_match_evolved_pokemon() {
+        let candidates = matcher(Species::Corviknight, GameVersion::Sword, 12, 3).candidates();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].species, Species::Rookidee);
+    }
+
+    #[test]
+    fn should_reject_wrong_levels_and_locations() {
+        assert!(matcher(Species::Rookidee, GameVersion::Sword, 12, 9)
+            .candidates()
+            .is_empty());
+        assert!(matcher(Species::Rookidee, GameVersion::Sword, 14, 3)
+            .candidates()
+            .is_empty());
+        assert!(matcher(Species::Rookidee, GameVersion::X, 12, 3)
+            .candidates()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_respect_shiny_locks_and_ivs() {
+        let mut zacian = matcher(Species::Zacian, GameVersion::Shield, 66, 70);
+        assert_eq!(zacian.candidates().len(), 1);
+
+        zacian.is_shiny = true;
+        assert!(zacian.candidates().is_empty());
+
+        zacian.is_shiny = false;
+        zacian.flawless_ivs = 2;
+        assert!(zacian.candidates().is_empty());
+    }
+
+    #[test]
+    fn should_match_mystery_gifts() {
+        let mut data = [0u8; Wc8::SIZE];
+        Writer::write(&mut data, 0x11, 1u8);
+        Writer::write(&mut data, 0x22a, 40u16);
+        Writer::write(&mut data, 0x240, Species::Eevee as u16);
+        Writer::write(&mut data, 0x249, 10u8);
+        Writer::write_array(&mut data, 0x26c, &[0xfd, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        let cards = [Wc8::new(data)];
+
+        let mut espeon = matcher(Species::Espeon, GameVersion::Shield, 40, 10);
+        let candidates = espeon.gift_candidates(&cards);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].kind, EncounterKind::MysteryGift);
+        assert_eq!(candidates[0].flawless_ivs, 2);
+
+        espeon.is_shiny = true;
+        assert!(espeon.gift_candidates(&cards).is_empty());
+
+        espeon.is_shiny = false;
+        espeon.met_location = 41;
+        assert!(espeon.gift_candidates(&cards).is_empty());
+    }
+
+    #[test]
+    fn should_match_tera_raids() {
+        let mut dragonite = matcher(
+            Species::Dragonite,
+            GameVersion::Violet,
+            locations::TERA_RAID,
+            90,
+        );
+        assert!(dragonite.candidates().is_empty());
+
+        dragonite.flawless_ivs = 5;
+        assert_eq!(dragonite.candidates()[0].kind, EncounterKind::TeraRaid);
+    }
+
+    #[test]
+    fn should_match_hatched_pokemon_to_eggs() {
+        let mut raichu = matcher(Species::Raichu, GameVersion::Sword, 12, 1);
+        raichu.egg_location = locations::DAY_CARE;
+        let candidates = raichu.candidates();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].kind, EncounterKind::Egg);
+        assert_eq!(candidates[0].species, Species::Pichu);
+    }
+
+    #[test]
+    fn should_not_hatch_species_that_cant_breed() {
+        for species in [Species::Zacian, Species::Mewtwo, Species::Ditto] {
+            let mut hatched = matcher(species, GameVersion::Sword, 12, 1);
+            hatched.egg_location = locations::DAY_CARE;
+            assert!(hatched.candidates().is_empty(), "{species:?}");
+        }
+
+        let mut manaphy = matcher(Species::Manaphy, GameVersion::Sword, 12, 1);
+        manaphy.egg_location = locations::DAY_CARE;
+        assert_eq!(manaphy.candidates().len(), 1);
+    }
+}
@@ -0,0 +1,890 @@
+use crate::types::{Move, Species};
+use alloc::vec::Vec;
+
+const SUN_STONE: u16 = 80;
+const MOON_STONE: u16 = 81;
+const FIRE_STONE: u16 = 82;
+const THUNDER_STONE: u16 = 83;
+const WATER_STONE: u16 = 84;
+const LEAF_STONE: u16 = 85;
+const SHINY_STONE: u16 = 107;
+const DUSK_STONE: u16 = 108;
+const DAWN_STONE: u16 = 109;
+const OVAL_STONE: u16 = 110;
+const KINGS_ROCK: u16 = 221;
+const DEEP_SEA_TOOTH: u16 = 226;
+const DEEP_SEA_SCALE: u16 = 227;
+const METAL_COAT: u16 = 233;
+const DRAGON_SCALE: u16 = 235;
+const UP_GRADE: u16 = 252;
+const PROTECTOR: u16 = 321;
+const ELECTIRIZER: u16 = 322;
+const MAGMARIZER: u16 = 323;
+const DUBIOUS_DISC: u16 = 324;
+const REAPER_CLOTH: u16 = 325;
+const RAZOR_CLAW: u16 = 326;
+const RAZOR_FANG: u16 = 327;
+const PRISM_SCALE: u16 = 537;
+const WHIPPED_DREAM: u16 = 646;
+const SACHET: u16 = 647;
+const ICE_STONE: u16 = 849;
+const SWEET_APPLE: u16 = 1116;
+const TART_APPLE: u16 = 1117;
+const CRACKED_POT: u16 = 1253;
+const CHIPPED_POT: u16 = 1254;
+const GALARICA_CUFF: u16 = 1582;
+const GALARICA_WREATH: u16 = 1592;
+const BLACK_AUGURITE: u16 = 1610;
+const PEAT_BLOCK: u16 = 1611;
+const AUSPICIOUS_ARMOR: u16 = 2344;
+const MALICIOUS_ARMOR: u16 = 2345;
+
+/// Gholdengo needs a full bag of 999 coins; the bag can't hold 1000.
+const GIMMIGHOUL_COINS: u16 = 999;
+
+/// What has to happen for a Pokemon to evolve.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EvolutionMethod {
+    LevelUp(u8),
+    /// Level up during the day.
+    LevelUpMorning(u8),
+    LevelUpNight(u8),
+    /// Level up in the evening.
+    LevelUpDusk(u8),
+    LevelUpMale(u8),
+    LevelUpFemale(u8),
+    LevelUpFriendship,
+    LevelUpFriendshipMorning,
+    LevelUpFriendshipNight,
+    /// Level up with high affection while knowing a Fairy-type move.
+    LevelUpFairyMove,
+    LevelUpKnowsMove(Move),
+    /// Level up near a special landmark, such as a Moss Rock or Ice Rock.
+    LevelUpAtLocation,
+    /// Level up during the day while holding an item, by item id.
+    LevelUpHeldItemMorning(u16),
+    /// Level up at night while holding an item, by item id.
+    LevelUpHeldItemNight(u16),
+    /// Level up with the given species in the party.
+    LevelUpWithSpecies(Species),
+    /// Level up with a Dark-type Pokemon in the party.
+    LevelUpWithDarkType(u8),
+    /// Level up while it's raining in the overworld.
+    LevelUpRain(u8),
+    /// Level up with the console held upside down.
+    LevelUpUpsideDown(u8),
+    /// Level up with Attack higher than Defense.
+    LevelUpAttackHigher(u8),
+    /// Level up with Defense higher than Attack.
+    LevelUpDefenseHigher(u8),
+    LevelUpAttackDefenseEqual(u8),
+    /// Level up with high beauty.
+    LevelUpBeauty,
+    /// Level up with an empty party slot and a Poke Ball in the bag, which
+    /// leaves a Shedinja behind.
+    LevelUpSpareSlot(u8),
+    /// Level up while in a Union Circle with other players.
+    LevelUpUnionCircle(u8),
+    /// Level up after walking the given number of steps in Let's Go mode.
+    LevelUpSteps(u16),
+    /// Level up after using a move the given number of times.
+    LevelUpMoveUses(Move, u8),
+    /// Level up after defeating the given number of Bisharp leading Pawniard.
+    LevelUpDefeatLeaders(u8),
+    /// Level up while holding the given number of Gimmighoul Coins.
+    Coins(u16),
+    /// Use an item, by item id.
+    UseItem(u16),
+    UseItemMale(u16),
+    UseItemFemale(u16),
+    Trade,
+    /// Trade while holding an item, by item id.
+    TradeWithItem(u16),
+    /// Trade for the given species.
+    TradeWithSpecies(Species),
+    /// Spin around while holding a Sweet.
+    Spin,
+    /// Land the given number of critical hits in one battle.
+    CriticalHits(u8),
+    /// Lose at least the given amount of HP without fainting, then walk
+    /// through a specific place or level up.
+    HitPointsLost(u16),
+    /// Read the scroll in the Tower of Darkness.
+    TowerOfDarkness,
+    /// Read the scroll in the Tower of Waters.
+    TowerOfWaters,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Evolution {
+    pub species: Species,
+    pub form: u8,
+    pub into: Species,
+    pub into_form: u8,
+    pub method: EvolutionMethod,
+}
+
+const fn evo(species: Species, into: Species, method: EvolutionMethod) -> Evolution {
+    form_evo(species, 0, into, 0, method)
+}
+
+const fn form_evo(
+    species: Species,
+    form: u8,
+    into: Species,
+    into_form: u8,
+    method: EvolutionMethod,
+) -> Evolution {
+    Evolution {
+        species,
+        form,
+        into,
+        into_form,
+        method,
+    }
+}
+
+use EvolutionMethod::*;
+use Species::*;
+
+// Methods that changed between games (e.g. Leafeon) are listed once per
+// method.  Regional forms that only appear in their own region (e.g. Alolan
+// Raichu) are listed alongside the usual evolution.  Where a random value,
+// the gender, the nature or the game version picks the result, each result is
+// listed with the same method.
+#[rustfmt::skip]
+const EVOLUTIONS: &[Evolution] = &[
+    // Generation 1
+    evo(Bulbasaur, Ivysaur, LevelUp(16)),
+    evo(Ivysaur, Venusaur, LevelUp(32)),
+    evo(Charmander, Charmeleon, LevelUp(16)),
+    evo(Charmeleon, Charizard, LevelUp(36)),
+    evo(Squirtle, Wartortle, LevelUp(16)),
+    evo(Wartortle, Blastoise, LevelUp(36)),
+    evo(Caterpie, Metapod, LevelUp(7)),
+    evo(Metapod, Butterfree, LevelUp(10)),
+    evo(Weedle, Kakuna, LevelUp(7)),
+    evo(Kakuna, Beedrill, LevelUp(10)),
+    evo(Pidgey, Pidgeotto, LevelUp(18)),
+    evo(Pidgeotto, Pidgeot, LevelUp(36)),
+    evo(Rattata, Raticate, LevelUp(20)),
+    form_evo(Rattata, 1, Raticate, 1, LevelUpNight(20)),
+    evo(Spearow, Fearow, LevelUp(20)),
+    evo(Ekans, Arbok, LevelUp(22)),
+    evo(Pichu, Pikachu, LevelUpFriendship),
+    evo(Pikachu, Raichu, UseItem(THUNDER_STONE)),
+    form_evo(Pikachu, 0, Raichu, 1, UseItem(THUNDER_STONE)),
+    evo(Sandshrew, Sandslash, LevelUp(22)),
+    form_evo(Sandshrew, 1, Sandslash, 1, UseItem(ICE_STONE)),
+    evo(NidoranF, Nidorina, LevelUp(16)),
+    evo(Nidorina, Nidoqueen, UseItem(MOON_STONE)),
+    evo(NidoranM, Nidorino, LevelUp(16)),
+    evo(Nidorino, Nidoking, UseItem(MOON_STONE)),
+    evo(Cleffa, Clefairy, LevelUpFriendship),
+    evo(Clefairy, Clefable, UseItem(MOON_STONE)),
+    evo(Vulpix, Ninetales, UseItem(FIRE_STONE)),
+    form_evo(Vulpix, 1, Ninetales, 1, UseItem(ICE_STONE)),
+    evo(Igglybuff, Jigglypuff, LevelUpFriendship),
+    evo(Jigglypuff, Wigglytuff, UseItem(MOON_STONE)),
+    evo(Zubat, Golbat, LevelUp(22)),
+    evo(Golbat, Crobat, LevelUpFriendship),
+    evo(Oddish, Gloom, LevelUp(21)),
+    evo(Gloom, Vileplume, UseItem(LEAF_STONE)),
+    evo(Gloom, Bellossom, UseItem(SUN_STONE)),
+    evo(Paras, Parasect, LevelUp(24)),
+    evo(Venonat, Venomoth, LevelUp(31)),
+    evo(Diglett, Dugtrio, LevelUp(26)),
+    form_evo(Diglett, 1, Dugtrio, 1, LevelUp(26)),
+    evo(Meowth, Persian, LevelUp(28)),
+    form_evo(Meowth, 1, Persian, 1, LevelUpFriendship),
+    form_evo(Meowth, 2, Perrserker, 0, LevelUp(28)),
+    evo(Psyduck, Golduck, LevelUp(33)),
+    evo(Mankey, Primeape, LevelUp(28)),
+    evo(Primeape, Annihilape, LevelUpMoveUses(Move::RageFist, 20)),
+    evo(Growlithe, Arcanine, UseItem(FIRE_STONE)),
+    form_evo(Growlithe, 1, Arcanine, 1, UseItem(FIRE_STONE)),
+    evo(Poliwag, Poliwhirl, LevelUp(25)),
+    evo(Poliwhirl, Poliwrath, UseItem(WATER_STONE)),
+    evo(Poliwhirl, Politoed, TradeWithItem(KINGS_ROCK)),
+    evo(Abra, Kadabra, LevelUp(16)),
+    evo(Kadabra, Alakazam, Trade),
+    evo(Machop, Machoke, LevelUp(28)),
+    evo(Machoke, Machamp, Trade),
+    evo(Bellsprout, Weepinbell, LevelUp(21)),
+    evo(Weepinbell, Victreebel, UseItem(LEAF_STONE)),
+    evo(Tentacool, Tentacruel, LevelUp(30)),
+    evo(Geodude, Graveler, LevelUp(25)),
+    form_evo(Geodude, 1, Graveler, 1, LevelUp(25)),
+    evo(Graveler, Golem, Trade),
+    form_evo(Graveler, 1, Golem, 1, Trade),
+    evo(Ponyta, Rapidash, LevelUp(40)),
+    form_evo(Ponyta, 1, Rapidash, 1, LevelUp(40)),
+    evo(Slowpoke, Slowbro, LevelUp(37)),
+    evo(Slowpoke, Slowking, TradeWithItem(KINGS_ROCK)),
+    form_evo(Slowpoke, 1, Slowbro, 2, UseItem(GALARICA_CUFF)),
+    form_evo(Slowpoke, 1, Slowking, 1, UseItem(GALARICA_WREATH)),
+    evo(Magnemite, Magneton, LevelUp(30)),
+    evo(Magneton, Magnezone, LevelUpAtLocation),
+    evo(Magneton, Magnezone, UseItem(THUNDER_STONE)),
+    form_evo(FarfetchD, 1, SirfetchD, 0, CriticalHits(3)),
+    evo(Doduo, Dodrio, LevelUp(31)),
+    evo(Seel, Dewgong, LevelUp(34)),
+    evo(Grimer, Muk, LevelUp(38)),
+    form_evo(Grimer, 1, Muk, 1, LevelUp(38)),
+    evo(Shellder, Cloyster, UseItem(WATER_STONE)),
+    evo(Gastly, Haunter, LevelUp(25)),
+    evo(Haunter, Gengar, Trade),
+    evo(Onix, Steelix, TradeWithItem(METAL_COAT)),
+    evo(Drowzee, Hypno, LevelUp(26)),
+    evo(Krabby, Kingler, LevelUp(28)),
+    evo(Voltorb, Electrode, LevelUp(30)),
+    form_evo(Voltorb, 1, Electrode, 1, UseItem(LEAF_STONE)),
+    evo(Exeggcute, Exeggutor, UseItem(LEAF_STONE)),
+    form_evo(Exeggcute, 0, Exeggutor, 1, UseItem(LEAF_STONE)),
+    evo(Cubone, Marowak, LevelUp(28)),
+    form_evo(Cubone, 0, Marowak, 1, LevelUpNight(28)),
+    evo(Tyrogue, Hitmonlee, LevelUpAttackHigher(20)),
+    evo(Tyrogue, Hitmonchan, LevelUpDefenseHigher(20)),
+    evo(Tyrogue, Hitmontop, LevelUpAttackDefenseEqual(20)),
+    evo(Lickitung, Lickilicky, LevelUpKnowsMove(Move::Rollout)),
+    evo(Koffing, Weezing, LevelUp(35)),
+    form_evo(Koffing, 0, Weezing, 1, LevelUp(35)),
+    evo(Rhyhorn, Rhydon, LevelUp(42)),
+    evo(Rhydon, Rhyperior, TradeWithItem(PROTECTOR)),
+    evo(Happiny, Chansey, LevelUpHeldItemMorning(OVAL_STONE)),
+    evo(Chansey, Blissey, LevelUpFriendship),
+    evo(Tangela, Tangrowth, LevelUpKnowsMove(Move::AncientPower)),
+    evo(Horsea, Seadra, LevelUp(32)),
+    evo(Seadra, Kingdra, TradeWithItem(DRAGON_SCALE)),
+    evo(Goldeen, Seaking, LevelUp(33)),
+    evo(Staryu, Starmie, UseItem(WATER_STONE)),
+    evo(MimeJr, MrMime, LevelUpKnowsMove(Move::Mimic)),
+    form_evo(MimeJr, 0, MrMime, 1, LevelUpKnowsMove(Move::Mimic)),
+    form_evo(MrMime, 1, MrRime, 0, LevelUp(42)),
+    evo(Scyther, Scizor, TradeWithItem(METAL_COAT)),
+    evo(Scyther, Kleavor, UseItem(BLACK_AUGURITE)),
+    evo(Smoochum, Jynx, LevelUp(30)),
+    evo(Elekid, Electabuzz, LevelUp(30)),
+    evo(Electabuzz, Electivire, TradeWithItem(ELECTIRIZER)),
+    evo(Magby, Magmar, LevelUp(30)),
+    evo(Magmar, Magmortar, TradeWithItem(MAGMARIZER)),
+    evo(Magikarp, Gyarados, LevelUp(20)),
+    evo(Eevee, Vaporeon, UseItem(WATER_STONE)),
+    evo(Eevee, Jolteon, UseItem(THUNDER_STONE)),
+    evo(Eevee, Flareon, UseItem(FIRE_STONE)),
+    evo(Eevee, Espeon, LevelUpFriendshipMorning),
+    evo(Eevee, Umbreon, LevelUpFriendshipNight),
+    evo(Eevee, Leafeon, LevelUpAtLocation),
+    evo(Eevee, Leafeon, UseItem(LEAF_STONE)),
+    evo(Eevee, Glaceon, LevelUpAtLocation),
+    evo(Eevee, Glaceon, UseItem(ICE_STONE)),
+    evo(Eevee, Sylveon, LevelUpFairyMove),
+    evo(Porygon, Porygon2, TradeWithItem(UP_GRADE)),
+    evo(Porygon2, PorygonZ, TradeWithItem(DUBIOUS_DISC)),
+    evo(Omanyte, Omastar, LevelUp(40)),
+    evo(Kabuto, Kabutops, LevelUp(40)),
+    evo(Munchlax, Snorlax, LevelUpFriendship),
+    evo(Dratini, Dragonair, LevelUp(30)),
+    evo(Dragonair, Dragonite, LevelUp(55)),
+
+    // Generation 2
+    evo(Chikorita, Bayleef, LevelUp(16)),
+    evo(Bayleef, Meganium, LevelUp(32)),
+    evo(Cyndaquil, Quilava, LevelUp(14)),
+    evo(Quilava, Typhlosion, LevelUp(36)),
+    form_evo(Quilava, 0, Typhlosion, 1, LevelUp(36)),
+    evo(Totodile, Croconaw, LevelUp(18)),
+    evo(Croconaw, Feraligatr, LevelUp(30)),
+    evo(Sentret, Furret, LevelUp(15)),
+    evo(Hoothoot, Noctowl, LevelUp(20)),
+    evo(Ledyba, Ledian, LevelUp(18)),
+    evo(Spinarak, Ariados, LevelUp(22)),
+    evo(Chinchou, Lanturn, LevelUp(27)),
+    evo(Togepi, Togetic, LevelUpFriendship),
+    evo(Togetic, Togekiss, UseItem(SHINY_STONE)),
+    evo(Natu, Xatu, LevelUp(25)),
+    evo(Mareep, Flaaffy, LevelUp(15)),
+    evo(Flaaffy, Ampharos, LevelUp(30)),
+    evo(Azurill, Marill, LevelUpFriendship),
+    evo(Marill, Azumarill, LevelUp(18)),
+    evo(Bonsly, Sudowoodo, LevelUpKnowsMove(Move::Mimic)),
+    evo(Hoppip, Skiploom, LevelUp(18)),
+    evo(Skiploom, Jumpluff, LevelUp(27)),
+    evo(Aipom, Ambipom, LevelUpKnowsMove(Move::DoubleHit)),
+    evo(Sunkern, Sunflora, UseItem(SUN_STONE)),
+    evo(Yanma, Yanmega, LevelUpKnowsMove(Move::AncientPower)),
+    evo(Wooper, Quagsire, LevelUp(20)),
+    form_evo(Wooper, 1, Clodsire, 0, LevelUp(20)),
+    evo(Murkrow, Honchkrow, UseItem(DUSK_STONE)),
+    evo(Misdreavus, Mismagius, UseItem(DUSK_STONE)),
+    evo(Wynaut, Wobbuffet, LevelUp(15)),
+    evo(Girafarig, Farigiraf, LevelUpKnowsMove(Move::TwinBeam)),
+    evo(Pineco, Forretress, LevelUp(31)),
+    evo(Dunsparce, Dudunsparce, LevelUpKnowsMove(Move::HyperDrill)),
+    form_evo(Dunsparce, 0, Dudunsparce, 1, LevelUpKnowsMove(Move::HyperDrill)),
+    evo(Gligar, Gliscor, LevelUpHeldItemNight(RAZOR_FANG)),
+    evo(Snubbull, Granbull, LevelUp(23)),
+    form_evo(Qwilfish, 1, Overqwil, 0, LevelUpMoveUses(Move::BarbBarrage, 20)),
+    evo(Sneasel, Weavile, LevelUpHeldItemNight(RAZOR_CLAW)),
+    form_evo(Sneasel, 1, Sneasler, 0, LevelUpHeldItemMorning(RAZOR_CLAW)),
+    evo(Teddiursa, Ursaring, LevelUp(30)),
+    evo(Ursaring, Ursaluna, UseItem(PEAT_BLOCK)),
+    evo(Slugma, Magcargo, LevelUp(38)),
+    evo(Swinub, Piloswine, LevelUp(33)),
+    evo(Piloswine, Mamoswine, LevelUpKnowsMove(Move::AncientPower)),
+    form_evo(Corsola, 1, Cursola, 0, LevelUp(38)),
+    evo(Remoraid, Octillery, LevelUp(25)),
+    evo(Mantyke, Mantine, LevelUpWithSpecies(Remoraid)),
+    evo(Houndour, Houndoom, LevelUp(24)),
+    evo(Phanpy, Donphan, LevelUp(25)),
+    evo(Stantler, Wyrdeer, LevelUpMoveUses(Move::PsyshieldBash, 20)),
+    evo(Larvitar, Pupitar, LevelUp(30)),
+    evo(Pupitar, Tyranitar, LevelUp(55)),
+
+    // Generation 3
+    evo(Treecko, Grovyle, LevelUp(16)),
+    evo(Grovyle, Sceptile, LevelUp(36)),
+    evo(Torchic, Combusken, LevelUp(16)),
+    evo(Combusken, Blaziken, LevelUp(36)),
+    evo(Mudkip, Marshtomp, LevelUp(16)),
+    evo(Marshtomp, Swampert, LevelUp(36)),
+    evo(Poochyena, Mightyena, LevelUp(18)),
+    evo(Zigzagoon, Linoone, LevelUp(20)),
+    form_evo(Zigzagoon, 1, Linoone, 1, LevelUp(20)),
+    form_evo(Linoone, 1, Obstagoon, 0, LevelUpNight(35)),
+    evo(Wurmple, Silcoon, LevelUp(7)),
+    evo(Wurmple, Cascoon, LevelUp(7)),
+    evo(Silcoon, Beautifly, LevelUp(10)),
+    evo(Cascoon, Dustox, LevelUp(10)),
+    evo(Lotad, Lombre, LevelUp(14)),
+    evo(Lombre, Ludicolo, UseItem(WATER_STONE)),
+    evo(Seedot, Nuzleaf, LevelUp(14)),
+    evo(Nuzleaf, Shiftry, UseItem(LEAF_STONE)),
+    evo(Taillow, Swellow, LevelUp(22)),
+    evo(Wingull, Pelipper, LevelUp(25)),
+    evo(Ralts, Kirlia, LevelUp(20)),
+    evo(Kirlia, Gardevoir, LevelUp(30)),
+    evo(Kirlia, Gallade, UseItemMale(DAWN_STONE)),
+    evo(Surskit, Masquerain, LevelUp(22)),
+    evo(Shroomish, Breloom, LevelUp(23)),
+    evo(Slakoth, Vigoroth, LevelUp(18)),
+    evo(Vigoroth, Slaking, LevelUp(36)),
+    evo(Nincada, Ninjask, LevelUp(20)),
+    evo(Nincada, Shedinja, LevelUpSpareSlot(20)),
+    evo(Whismur, Loudred, LevelUp(20)),
+    evo(Loudred, Exploud, LevelUp(40)),
+    evo(Makuhita, Hariyama, LevelUp(24)),
+    evo(Nosepass, Probopass, LevelUpAtLocation),
+    evo(Nosepass, Probopass, UseItem(THUNDER_STONE)),
+    evo(Skitty, Delcatty, UseItem(MOON_STONE)),
+    evo(Aron, Lairon, LevelUp(32)),
+    evo(Lairon, Aggron, LevelUp(42)),
+    evo(Meditite, Medicham, LevelUp(37)),
+    evo(Electrike, Manectric, LevelUp(26)),
+    evo(Budew, Roselia, LevelUpFriendshipMorning),
+    evo(Roselia, Roserade, UseItem(SHINY_STONE)),
+    evo(Gulpin, Swalot, LevelUp(26)),
+    evo(Carvanha, Sharpedo, LevelUp(30)),
+    evo(Wailmer, Wailord, LevelUp(40)),
+    evo(Numel, Camerupt, LevelUp(33)),
+    evo(Spoink, Grumpig, LevelUp(32)),
+    evo(Trapinch, Vibrava, LevelUp(35)),
+    evo(Vibrava, Flygon, LevelUp(45)),
+    evo(Cacnea, Cacturne, LevelUp(32)),
+    evo(Swablu, Altaria, LevelUp(35)),
+    evo(Barboach, Whiscash, LevelUp(30)),
+    evo(Corphish, Crawdaunt, LevelUp(30)),
+    evo(Baltoy, Claydol, LevelUp(36)),
+    evo(Lileep, Cradily, LevelUp(40)),
+    evo(Anorith, Armaldo, LevelUp(40)),
+    evo(Feebas, Milotic, LevelUpBeauty),
+    evo(Feebas, Milotic, TradeWithItem(PRISM_SCALE)),
+    evo(Shuppet, Banette, LevelUp(37)),
+    evo(Duskull, Dusclops, LevelUp(37)),
+    evo(Dusclops, Dusknoir, TradeWithItem(REAPER_CLOTH)),
+    evo(Chingling, Chimecho, LevelUpFriendshipNight),
+    evo(Snorunt, Glalie, LevelUp(42)),
+    evo(Snorunt, Froslass, UseItemFemale(DAWN_STONE)),
+    evo(Spheal, Sealeo, LevelUp(32)),
+    evo(Sealeo, Walrein, LevelUp(44)),
+    evo(Clamperl, Huntail, TradeWithItem(DEEP_SEA_TOOTH)),
+    evo(Clamperl, Gorebyss, TradeWithItem(DEEP_SEA_SCALE)),
+    evo(Bagon, Shelgon, LevelUp(30)),
+    evo(Shelgon, Salamence, LevelUp(50)),
+    evo(Beldum, Metang, LevelUp(20)),
+    evo(Metang, Metagross, LevelUp(45)),
+
+    // Generation 4
+    evo(Turtwig, Grotle, LevelUp(18)),
+    evo(Grotle, Torterra, LevelUp(32)),
+    evo(Chimchar, Monferno, LevelUp(14)),
+    evo(Monferno, Infernape, LevelUp(36)),
+    evo(Piplup, Prinplup, LevelUp(16)),
+    evo(Prinplup, Empoleon, LevelUp(36)),
+    evo(Starly, Staravia, LevelUp(14)),
+    evo(Staravia, Staraptor, LevelUp(34)),
+    evo(Bidoof, Bibarel, LevelUp(15)),
+    evo(Kricketot, Kricketune, LevelUp(10)),
+    evo(Shinx, Luxio, LevelUp(15)),
+    evo(Luxio, Luxray, LevelUp(30)),
+    evo(Cranidos, Rampardos, LevelUp(30)),
+    evo(Shieldon, Bastiodon, LevelUp(30)),
+    form_evo(Burmy, 0, Wormadam, 0, LevelUpFemale(20)),
+    form_evo(Burmy, 1, Wormadam, 1, LevelUpFemale(20)),
+    form_evo(Burmy, 2, Wormadam, 2, LevelUpFemale(20)),
+    form_evo(Burmy, 0, Mothim, 0, LevelUpMale(20)),
+    form_evo(Burmy, 1, Mothim, 0, LevelUpMale(20)),
+    form_evo(Burmy, 2, Mothim, 0, LevelUpMale(20)),
+    evo(Combee, Vespiquen, LevelUpFemale(21)),
+    evo(Buizel, Floatzel, LevelUp(26)),
+    evo(Cherubi, Cherrim, LevelUp(25)),
+    form_evo(Shellos, 0, Gastrodon, 0, LevelUp(30)),
+    form_evo(Shellos, 1, Gastrodon, 1, LevelUp(30)),
+    evo(Drifloon, Drifblim, LevelUp(28)),
+    evo(Buneary, Lopunny, LevelUpFriendship),
+    evo(Glameow, Purugly, LevelUp(38)),
+    evo(Stunky, Skuntank, LevelUp(34)),
+    evo(Bronzor, Bronzong, LevelUp(33)),
+    evo(Gible, Gabite, LevelUp(24)),
+    evo(Gabite, Garchomp, LevelUp(48)),
+    evo(Riolu, Lucario, LevelUpFriendshipMorning),
+    evo(Hippopotas, Hippowdon, LevelUp(34)),
+    evo(Skorupi, Drapion, LevelUp(40)),
+    evo(Croagunk, Toxicroak, LevelUp(37)),
+    evo(Finneon, Lumineon, LevelUp(31)),
+    evo(Snover, Abomasnow, LevelUp(40)),
+
+    // Generation 5
+    evo(Snivy, Servine, LevelUp(17)),
+    evo(Servine, Serperior, LevelUp(36)),
+    evo(Tepig, Pignite, LevelUp(17)),
+    evo(Pignite, Emboar, LevelUp(36)),
+    evo(Oshawott, Dewott, LevelUp(17)),
+    evo(Dewott, Samurott, LevelUp(36)),
+    form_evo(Dewott, 0, Samurott, 1, LevelUp(36)),
+    evo(Patrat, Watchog, LevelUp(20)),
+    evo(Lillipup, Herdier, LevelUp(16)),
+    evo(Herdier, Stoutland, LevelUp(32)),
+    evo(Purrloin, Liepard, LevelUp(20)),
+    evo(Pansage, Simisage, UseItem(LEAF_STONE)),
+    evo(Pansear, Simisear, UseItem(FIRE_STONE)),
+    evo(Panpour, Simipour, UseItem(WATER_STONE)),
+    evo(Munna, Musharna, UseItem(MOON_STONE)),
+    evo(Pidove, Tranquill, LevelUp(21)),
+    evo(Tranquill, Unfezant, LevelUp(32)),
+    evo(Blitzle, Zebstrika, LevelUp(27)),
+    evo(Roggenrola, Boldore, LevelUp(25)),
+    evo(Boldore, Gigalith, Trade),
+    evo(Woobat, Swoobat, LevelUpFriendship),
+    evo(Drilbur, Excadrill, LevelUp(31)),
+    evo(Timburr, Gurdurr, LevelUp(25)),
+    evo(Gurdurr, Conkeldurr, Trade),
+    evo(Tympole, Palpitoad, LevelUp(25)),
+    evo(Palpitoad, Seismitoad, LevelUp(36)),
+    evo(Sewaddle, Swadloon, LevelUp(20)),
+    evo(Swadloon, Leavanny, LevelUpFriendship),
+    evo(Venipede, Whirlipede, LevelUp(22)),
+    evo(Whirlipede, Scolipede, LevelUp(30)),
+    evo(Cottonee, Whimsicott, UseItem(SUN_STONE)),
+    evo(Petilil, Lilligant, UseItem(SUN_STONE)),
+    form_evo(Petilil, 0, Lilligant, 1, UseItem(SUN_STONE)),
+    form_evo(Basculin, 2, Basculegion, 0, HitPointsLost(294)),
+    form_evo(Basculin, 2, Basculegion, 1, HitPointsLost(294)),
+    evo(Sandile, Krokorok, LevelUp(29)),
+    evo(Krokorok, Krookodile, LevelUp(40)),
+    evo(Darumaka, Darmanitan, LevelUp(35)),
+    form_evo(Darumaka, 1, Darmanitan, 2, UseItem(ICE_STONE)),
+    evo(Dwebble, Crustle, LevelUp(34)),
+    evo(Scraggy, Scrafty, LevelUp(39)),
+    evo(Yamask, Cofagrigus, LevelUp(34)),
+    form_evo(Yamask, 1, Runerigus, 0, HitPointsLost(49)),
+    evo(Tirtouga, Carracosta, LevelUp(37)),
+    evo(Archen, Archeops, LevelUp(37)),
+    evo(Trubbish, Garbodor, LevelUp(36)),
+    evo(Zorua, Zoroark, LevelUp(30)),
+    form_evo(Zorua, 1, Zoroark, 1, LevelUp(30)),
+    evo(Minccino, Cinccino, UseItem(SHINY_STONE)),
+    evo(Gothita, Gothorita, LevelUp(32)),
+    evo(Gothorita, Gothitelle, LevelUp(41)),
+    evo(Solosis, Duosion, LevelUp(32)),
+    evo(Duosion, Reuniclus, LevelUp(41)),
+    evo(Ducklett, Swanna, LevelUp(35)),
+    evo(Vanillite, Vanillish, LevelUp(35)),
+    evo(Vanillish, Vanilluxe, LevelUp(47)),
+    form_evo(Deerling, 0, Sawsbuck, 0, LevelUp(34)),
+    form_evo(Deerling, 1, Sawsbuck, 1, LevelUp(34)),
+    form_evo(Deerling, 2, Sawsbuck, 2, LevelUp(34)),
+    form_evo(Deerling, 3, Sawsbuck, 3, LevelUp(34)),
+    evo(Karrablast, Escavalier, TradeWithSpecies(Shelmet)),
+    evo(Shelmet, Accelgor, TradeWithSpecies(Karrablast)),
+    evo(Foongus, Amoonguss, LevelUp(39)),
+    evo(Frillish, Jellicent, LevelUp(40)),
+    evo(Joltik, Galvantula, LevelUp(36)),
+    evo(Ferroseed, Ferrothorn, LevelUp(40)),
+    evo(Klink, Klang, LevelUp(38)),
+    evo(Klang, Klinklang, LevelUp(49)),
+    evo(Tynamo, Eelektrik, LevelUp(39)),
+    evo(Eelektrik, Eelektross, UseItem(THUNDER_STONE)),
+    evo(Elgyem, Beheeyem, LevelUp(42)),
+    evo(Litwick, Lampent, LevelUp(41)),
+    evo(Lampent, Chandelure, UseItem(DUSK_STONE)),
+    evo(Axew, Fraxure, LevelUp(38)),
+    evo(Fraxure, Haxorus, LevelUp(48)),
+    evo(Cubchoo, Beartic, LevelUp(37)),
+    evo(Mienfoo, Mienshao, LevelUp(50)),
+    evo(Golett, Golurk, LevelUp(43)),
+    evo(Pawniard, Bisharp, LevelUp(52)),
+    evo(Bisharp, Kingambit, LevelUpDefeatLeaders(3)),
+    evo(Rufflet, Braviary, LevelUp(54)),
+    form_evo(Rufflet, 0, Braviary, 1, LevelUp(54)),
+    evo(Vullaby, Mandibuzz, LevelUp(54)),
+    evo(Deino, Zweilous, LevelUp(50)),
+    evo(Zweilous, Hydreigon, LevelUp(64)),
+    evo(Larvesta, Volcarona, LevelUp(59)),
+
+    // Generation 6
+    evo(Chespin, Quilladin, LevelUp(16)),
+    evo(Quilladin, Chesnaught, LevelUp(36)),
+    evo(Fennekin, Braixen, LevelUp(16)),
+    evo(Braixen, Delphox, LevelUp(36)),
+    evo(Froakie, Frogadier, LevelUp(16)),
+    evo(Frogadier, Greninja, LevelUp(36)),
+    evo(Bunnelby, Diggersby, LevelUp(20)),
+    evo(Fletchling, Fletchinder, LevelUp(17)),
+    evo(Fletchinder, Talonflame, LevelUp(35)),
+    form_evo(Scatterbug, 0, Spewpa, 0, LevelUp(9)),
+    form_evo(Scatterbug, 1, Spewpa, 1, LevelUp(9)),
+    form_evo(Scatterbug, 2, Spewpa, 2, LevelUp(9)),
+    form_evo(Scatterbug, 3, Spewpa, 3, LevelUp(9)),
+    form_evo(Scatterbug, 4, Spewpa, 4, LevelUp(9)),
+    form_evo(Scatterbug, 5, Spewpa, 5, LevelUp(9)),
+    form_evo(Scatterbug, 6, Spewpa, 6, LevelUp(9)),
+    form_evo(Scatterbug, 7, Spewpa, 7, LevelUp(9)),
+    form_evo(Scatterbug, 8, Spewpa, 8, LevelUp(9)),
+    form_evo(Scatterbug, 9, Spewpa, 9, LevelUp(9)),
+    form_evo(Scatterbug, 10, Spewpa, 10, LevelUp(9)),
+    form_evo(Scatterbug, 11, Spewpa, 11, LevelUp(9)),
+    form_evo(Scatterbug, 12, Spewpa, 12, LevelUp(9)),
+    form_evo(Scatterbug, 13, Spewpa, 13, LevelUp(9)),
+    form_evo(Scatterbug, 14, Spewpa, 14, LevelUp(9)),
+    form_evo(Scatterbug, 15, Spewpa, 15, LevelUp(9)),
+    form_evo(Scatterbug, 16, Spewpa, 16, LevelUp(9)),
+    form_evo(Scatterbug, 17, Spewpa, 17, LevelUp(9)),
+    form_evo(Scatterbug, 18, Spewpa, 18, LevelUp(9)),
+    form_evo(Scatterbug, 19, Spewpa, 19, LevelUp(9)),
+    form_evo(Spewpa, 0, Vivillon, 0, LevelUp(12)),
+    form_evo(Spewpa, 1, Vivillon, 1, LevelUp(12)),
+    form_evo(Spewpa, 2, Vivillon, 2, LevelUp(12)),
+    form_evo(Spewpa, 3, Vivillon, 3, LevelUp(12)),
+    form_evo(Spewpa, 4, Vivillon, 4, LevelUp(12)),
+    form_evo(Spewpa, 5, Vivillon, 5, LevelUp(12)),
+    form_evo(Spewpa, 6, Vivillon, 6, LevelUp(12)),
+    form_evo(Spewpa, 7, Vivillon, 7, LevelUp(12)),
+    form_evo(Spewpa, 8, Vivillon, 8, LevelUp(12)),
+    form_evo(Spewpa, 9, Vivillon, 9, LevelUp(12)),
+    form_evo(Spewpa, 10, Vivillon, 10, LevelUp(12)),
+    form_evo(Spewpa, 11, Vivillon, 11, LevelUp(12)),
+    form_evo(Spewpa, 12, Vivillon, 12, LevelUp(12)),
+    form_evo(Spewpa, 13, Vivillon, 13, LevelUp(12)),
+    form_evo(Spewpa, 14, Vivillon, 14, LevelUp(12)),
+    form_evo(Spewpa, 15, Vivillon, 15, LevelUp(12)),
+    form_evo(Spewpa, 16, Vivillon, 16, LevelUp(12)),
+    form_evo(Spewpa, 17, Vivillon, 17, LevelUp(12)),
+    form_evo(Spewpa, 18, Vivillon, 18, LevelUp(12)),
+    form_evo(Spewpa, 19, Vivillon, 19, LevelUp(12)),
+    evo(Litleo, Pyroar, LevelUp(35)),
+    form_evo(Flabebe, 0, Floette, 0, LevelUp(19)),
+    form_evo(Flabebe, 1, Floette, 1, LevelUp(19)),
+    form_evo(Flabebe, 2, Floette, 2, LevelUp(19)),
+    form_evo(Flabebe, 3, Floette, 3, LevelUp(19)),
+    form_evo(Flabebe, 4, Floette, 4, LevelUp(19)),
+    form_evo(Floette, 0, Florges, 0, UseItem(SHINY_STONE)),
+    form_evo(Floette, 1, Florges, 1, UseItem(SHINY_STONE)),
+    form_evo(Floette, 2, Florges, 2, UseItem(SHINY_STONE)),
+    form_evo(Floette, 3, Florges, 3, UseItem(SHINY_STONE)),
+    form_evo(Floette, 4, Florges, 4, UseItem(SHINY_STONE)),
+    evo(Skiddo, Gogoat, LevelUp(32)),
+    evo(Pancham, Pangoro, LevelUpWithDarkType(32)),
+    form_evo(Espurr, 0, Meowstic, 0, LevelUpMale(25)),
+    form_evo(Espurr, 0, Meowstic, 1, LevelUpFemale(25)),
+    evo(Honedge, Doublade, LevelUp(35)),
+    evo(Doublade, Aegislash, UseItem(DUSK_STONE)),
+    evo(Spritzee, Aromatisse, TradeWithItem(SACHET)),
+    evo(Swirlix, Slurpuff, TradeWithItem(WHIPPED_DREAM)),
+    evo(Inkay, Malamar, LevelUpUpsideDown(30)),
+    evo(Binacle, Barbaracle, LevelUp(39)),
+    evo(Skrelp, Dragalge, LevelUp(48)),
+    evo(Clauncher, Clawitzer, LevelUp(37)),
+    evo(Helioptile, Heliolisk, UseItem(SUN_STONE)),
+    evo(Tyrunt, Tyrantrum, LevelUpMorning(39)),
+    evo(Amaura, Aurorus, LevelUpNight(39)),
+    evo(Goomy, Sliggoo, LevelUp(40)),
+    form_evo(Goomy, 0, Sliggoo, 1, LevelUp(40)),
+    evo(Sliggoo, Goodra, LevelUpRain(50)),
+    form_evo(Sliggoo, 1, Goodra, 1, LevelUpRain(50)),
+    evo(Sliggoo, Goodra, LevelUp(50)),
+    form_evo(Sliggoo, 1, Goodra, 1, LevelUp(50)),
+    evo(Phantump, Trevenant, Trade),
+    form_evo(Pumpkaboo, 0, Gourgeist, 0, Trade),
+    form_evo(Pumpkaboo, 1, Gourgeist, 1, Trade),
+    form_evo(Pumpkaboo, 2, Gourgeist, 2, Trade),
+    form_evo(Pumpkaboo, 3, Gourgeist, 3, Trade),
+    evo(Bergmite, Avalugg, LevelUp(37)),
+    form_evo(Bergmite, 0, Avalugg, 1, LevelUp(37)),
+    evo(Noibat, Noivern, LevelUp(48)),
+
+    // Generation 7
+    evo(Rowlet, Dartrix, LevelUp(17)),
+    evo(Dartrix, Decidueye, LevelUp(34)),
+    form_evo(Dartrix, 0, Decidueye, 1, LevelUp(36)),
+    evo(Litten, Torracat, LevelUp(17)),
+    evo(Torracat, Incineroar, LevelUp(34)),
+    evo(Popplio, Brionne, LevelUp(17)),
+    evo(Brionne, Primarina, LevelUp(34)),
+    evo(Pikipek, Trumbeak, LevelUp(14)),
+    evo(Trumbeak, Toucannon, LevelUp(28)),
+    evo(Yungoos, Gumshoos, LevelUpMorning(20)),
+    evo(Grubbin, Charjabug, LevelUp(20)),
+    evo(Charjabug, Vikavolt, LevelUpAtLocation),
+    evo(Charjabug, Vikavolt, UseItem(THUNDER_STONE)),
+    evo(Crabrawler, Crabominable, LevelUpAtLocation),
+    evo(Crabrawler, Crabominable, UseItem(ICE_STONE)),
+    evo(Cutiefly, Ribombee, LevelUp(25)),
+    form_evo(Rockruff, 0, Lycanroc, 0, LevelUpMorning(25)),
+    form_evo(Rockruff, 0, Lycanroc, 1, LevelUpNight(25)),
+    form_evo(Rockruff, 1, Lycanroc, 2, LevelUpDusk(25)),
+    evo(Mareanie, Toxapex, LevelUp(38)),
+    evo(Mudbray, Mudsdale, LevelUp(30)),
+    evo(Dewpider, Araquanid, LevelUp(22)),
+    evo(Fomantis, Lurantis, LevelUpMorning(34)),
+    evo(Morelull, Shiinotic, LevelUp(24)),
+    evo(Salandit, Salazzle, LevelUpFemale(33)),
+    evo(Stufful, Bewear, LevelUp(27)),
+    evo(Bounsweet, Steenee, LevelUp(18)),
+    evo(Steenee, Tsareena, LevelUpKnowsMove(Move::Stomp)),
+    evo(Wimpod, Golisopod, LevelUp(30)),
+    evo(Sandygast, Palossand, LevelUp(42)),
+    evo(TypeNull, Silvally, LevelUpFriendship),
+    evo(JangmoO, HakamoO, LevelUp(35)),
+    evo(HakamoO, KommoO, LevelUp(45)),
+    evo(Cosmog, Cosmoem, LevelUp(43)),
+    evo(Cosmoem, Solgaleo, LevelUp(53)),
+    evo(Cosmoem, Lunala, LevelUp(53)),
+    evo(Poipole, Naganadel, LevelUpKnowsMove(Move::DragonPulse)),
+
+    // Generation 8
+    evo(Grookey, Thwackey, LevelUp(16)),
+    evo(Thwackey, Rillaboom, LevelUp(35)),
+    evo(Scorbunny, Raboot, LevelUp(16)),
+    evo(Raboot, Cinderace, LevelUp(35)),
+    evo(Sobble, Drizzile, LevelUp(16)),
+    evo(Drizzile, Inteleon, LevelUp(35)),
+    evo(Skwovet, Greedent, LevelUp(24)),
+    evo(Rookidee, Corvisquire, LevelUp(18)),
+    evo(Corvisquire, Corviknight, LevelUp(38)),
+    evo(Blipbug, Dottler, LevelUp(10)),
+    evo(Dottler, Orbeetle, LevelUp(30)),
+    evo(Nickit, Thievul, LevelUp(18)),
+    evo(Gossifleur, Eldegoss, LevelUp(20)),
+    evo(Wooloo, Dubwool, LevelUp(24)),
+    evo(Chewtle, Drednaw, LevelUp(22)),
+    evo(Yamper, Boltund, LevelUp(25)),
+    evo(Rolycoly, Carkol, LevelUp(18)),
+    evo(Carkol, Coalossal, LevelUp(34)),
+    evo(Applin, Flapple, UseItem(TART_APPLE)),
+    evo(Applin, Appletun, UseItem(SWEET_APPLE)),
+    evo(Silicobra, Sandaconda, LevelUp(36)),
+    evo(Arrokuda, Barraskewda, LevelUp(26)),
+    form_evo(Toxel, 0, Toxtricity, 0, LevelUp(30)),
+    form_evo(Toxel, 0, Toxtricity, 1, LevelUp(30)),
+    evo(Sizzlipede, Centiskorch, LevelUp(28)),
+    evo(Clobbopus, Grapploct, LevelUpKnowsMove(Move::Taunt)),
+    form_evo(Sinistea, 0, Polteageist, 0, UseItem(CRACKED_POT)),
+    form_evo(Sinistea, 1, Polteageist, 1, UseItem(CHIPPED_POT)),
+    evo(Hatenna, Hattrem, LevelUp(32)),
+    evo(Hattrem, Hatterene, LevelUp(42)),
+    evo(Impidimp, Morgrem, LevelUp(32)),
+    evo(Morgrem, Grimmsnarl, LevelUp(42)),
+    form_evo(Milcery, 0, Alcremie, 0, Spin),
+    form_evo(Milcery, 0, Alcremie, 1, Spin),
+    form_evo(Milcery, 0, Alcremie, 2, Spin),
+    form_evo(Milcery, 0, Alcremie, 3, Spin),
+    form_evo(Milcery, 0, Alcremie, 4, Spin),
+    form_evo(Milcery, 0, Alcremie, 5, Spin),
+    form_evo(Milcery, 0, Alcremie, 6, Spin),
+    form_evo(Milcery, 0, Alcremie, 7, Spin),
+    form_evo(Milcery, 0, Alcremie, 8, Spin),
+    evo(Snom, Frosmoth, LevelUpFriendshipNight),
+    evo(Cufant, Copperajah, LevelUp(34)),
+    evo(Dreepy, Drakloak, LevelUp(50)),
+    evo(Drakloak, Dragapult, LevelUp(60)),
+    form_evo(Kubfu, 0, Urshifu, 0, TowerOfDarkness),
+    form_evo(Kubfu, 0, Urshifu, 1, TowerOfWaters),
+
+    // Generation 9
+    evo(Sprigatito, Floragato, LevelUp(16)),
+    evo(Floragato, Meowscarada, LevelUp(36)),
+    evo(Fuecoco, Crocalor, LevelUp(16)),
+    evo(Crocalor, Skeledirge, LevelUp(36)),
+    evo(Quaxly, Quaxwell, LevelUp(16)),
+    evo(Quaxwell, Quaquaval, LevelUp(36)),
+    form_evo(Lechonk, 0, Oinkologne, 0, LevelUpMale(18)),
+    form_evo(Lechonk, 0, Oinkologne, 1, LevelUpFemale(18)),
+    evo(Tarountula, Spidops, LevelUp(15)),
+    evo(Nymble, Lokix, LevelUp(24)),
+    evo(Rellor, Rabsca, LevelUpSteps(1000)),
+    evo(Greavard, Houndstone, LevelUpNight(30)),
+    evo(Flittle, Espathra, LevelUp(35)),
+    evo(Wiglett, Wugtrio, LevelUp(26)),
+    evo(Finizen, Palafin, LevelUpUnionCircle(38)),
+    evo(Smoliv, Dolliv, LevelUp(25)),
+    evo(Dolliv, Arboliva, LevelUp(35)),
+    evo(Capsakid, Scovillain, UseItem(FIRE_STONE)),
+    evo(Tadbulb, Bellibolt, UseItem(THUNDER_STONE)),
+    evo(Varoom, Revavroom, LevelUp(40)),
+    form_evo(Tandemaus, 0, Maushold, 0, LevelUp(25)),
+    form_evo(Tandemaus, 0, Maushold, 1, LevelUp(25)),
+    evo(Cetoddle, Cetitan, UseItem(ICE_STONE)),
+    evo(Frigibax, Arctibax, LevelUp(35)),
+    evo(Arctibax, Baxcalibur, LevelUp(54)),
+    evo(Pawmi, Pawmo, LevelUp(18)),
+    evo(Pawmo, Pawmot, LevelUpSteps(1000)),
+    evo(Wattrel, Kilowattrel, LevelUp(25)),
+    evo(Nacli, Naclstack, LevelUp(24)),
+    evo(Naclstack, Garganacl, LevelUp(38)),
+    evo(Glimmet, Glimmora, LevelUp(35)),
+    evo(Shroodle, Grafaiai, LevelUp(28)),
+    evo(Fidough, Dachsbun, LevelUp(26)),
+    evo(Maschiff, Mabosstiff, LevelUp(30)),
+    evo(Bramblin, Brambleghast, LevelUpSteps(1000)),
+    evo(Gimmighoul, Gholdengo, Coins(GIMMIGHOUL_COINS)),
+    form_evo(Gimmighoul, 1, Gholdengo, 0, Coins(GIMMIGHOUL_COINS)),
+    evo(Tinkatink, Tinkatuff, LevelUp(24)),
+    evo(Tinkatuff, Tinkaton, LevelUp(38)),
+    evo(Charcadet, Armarouge, UseItem(AUSPICIOUS_ARMOR)),
+    evo(Charcadet, Ceruledge, UseItem(MALICIOUS_ARMOR)),
+    evo(Toedscool, Toedscruel, LevelUp(30)),
+];
+
+/// Lists the ways a species and form can evolve.
+pub fn evolutions(species: Species, form: u8) -> impl Iterator<Item = &'static Evolution> {
+    EVOLUTIONS
+        .iter()
+        .filter(move |evolution| evolution.species == species && evolution.form == form)
+}
+
+fn pre_evolution(species: Species, form: u8) -> Option<&'static Evolution> {
+    EVOLUTIONS
+        .iter()
+        .find(|evolution| evolution.into == species && evolution.into_form == form)
+}
+
+/// Lists the species a species evolves from, nearest first.
+pub fn pre_evolutions(species: Species) -> Vec<Species> {
+    let mut result = Vec::new();
+    let mut current = EVOLUTIONS
+        .iter()
+        .find(|evolution| evolution.into == species);
+
+    while let Some(evolution) = current {
+        result.push(evolution.species);
+        current = pre_evolution(evolution.species, evolution.form);
+    }
+
+    result
+}
+
+/// Finds the unevolved species and form at the start of an evolution chain.
+pub fn base_species(species: Species, form: u8) -> (Species, u8) {
+    let mut base = (species, form);
+
+    while let Some(evolution) = pre_evolution(base.0, base.1) {
+        base = (evolution.species, evolution.form);
+    }
+
+    base
+}
+
+/// Whether `species` can eventually evolve into `into`, through any number of
+/// evolutions and ignoring forms.
+pub fn can_evolve_into(species: Species, into: Species) -> bool {
+    pre_evolutions(into).contains(&species)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_list_evolutions() {
+        let into: Vec<Species> = evolutions(Species::Eevee, 0)
+            .map(|evolution| evolution.into)
+            .collect();
+        assert!(into.contains(&Species::Sylveon));
+        assert_eq!(evolutions(Species::Gengar, 0).count(), 0);
+    }
+
+    #[test]
+    fn should_list_pre_evolutions() {
+        assert_eq!(
+            pre_evolutions(Species::Charizard),
+            [Species::Charmeleon, Species::Charmander]
+        );
+        assert_eq!(pre_evolutions(Species::Bulbasaur), []);
+    }
+
+    #[test]
+    fn should_find_base_species() {
+        assert_eq!(base_species(Species::Raichu, 1), (Species::Pichu, 0));
+        assert_eq!(
+            base_species(Species::Gholdengo, 0),
+            (Species::Gimmighoul, 0)
+        );
+        assert_eq!(base_species(Species::Eevee, 0), (Species::Eevee, 0));
+    }
+
+    #[test]
+    fn should_check_evolution_chains() {
+        assert!(can_evolve_into(Species::Pichu, Species::Raichu));
+        assert!(can_evolve_into(Species::Pawniard, Species::Kingambit));
+        assert!(!can_evolve_into(Species::Raichu, Species::Pichu));
+        assert!(!can_evolve_into(Species::Eevee, Species::Pikachu));
+    }
+
+    #[test]
+    fn should_read_gen9_methods() {
+        let evolution = evolutions(Species::Gimmighoul, 1).next().unwrap();
+        assert_eq!(evolution.method, EvolutionMethod::Coins(999));
+    }
+
+    #[test]
+    fn should_list_branched_evolutions() {
+        let methods: Vec<_> = evolutions(Species::Tyrogue, 0)
+            .map(|evolution| (evolution.into, evolution.method))
+            .collect();
+        assert_eq!(
+            methods,
+            [
+                (Species::Hitmonlee, EvolutionMethod::LevelUpAttackHigher(20)),
+                (
+                    Species::Hitmonchan,
+                    EvolutionMethod::LevelUpDefenseHigher(20)
+                ),
+                (
+                    Species::Hitmontop,
+                    EvolutionMethod::LevelUpAttackDefenseEqual(20)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_keep_forms_through_evolutions() {
+        assert_eq!(base_species(Species::Vivillon, 7), (Species::Scatterbug, 7));
+        assert_eq!(base_species(Species::SirfetchD, 0), (Species::FarfetchD, 1));
+        assert_eq!(
+            evolutions(Species::Sinistea, 1).next().unwrap().method,
+            EvolutionMethod::UseItem(CHIPPED_POT)
+        );
+    }
+}
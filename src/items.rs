@@ -0,0 +1,364 @@
+/// English item names by item id, from the held items every Gen4 and later
+/// game shares up to the Razor Fang.  Ids the games leave unused are empty.
+#[rustfmt::skip]
+const NAMES: [&str; 328] = [
+    "",
+    "Master Ball",
+    "Ultra Ball",
+    "Great Ball",
+    "Poké Ball",
+    "Safari Ball",
+    "Net Ball",
+    "Dive Ball",
+    "Nest Ball",
+    "Repeat Ball",
+    "Timer Ball",
+    "Luxury Ball",
+    "Premier Ball",
+    "Dusk Ball",
+    "Heal Ball",
+    "Quick Ball",
+    "Cherish Ball",
+    "Potion",
+    "Antidote",
+    "Burn Heal",
+    "Ice Heal",
+    "Awakening",
+    "Paralyze Heal",
+    "Full Restore",
+    "Max Potion",
+    "Hyper Potion",
+    "Super Potion",
+    "Full Heal",
+    "Revive",
+    "Max Revive",
+    "Fresh Water",
+    "Soda Pop",
+    "Lemonade",
+    "Moomoo Milk",
+    "Energy Powder",
+    "Energy Root",
+    "Heal Powder",
+    "Revival Herb",
+    "Ether",
+    "Max Ether",
+    "Elixir",
+    "Max Elixir",
+    "Lava Cookie",
+    "Berry Juice",
+    "Sacred Ash",
+    "HP Up",
+    "Protein",
+    "Iron",
+    "Carbos",
+    "Calcium",
+    "Rare Candy",
+    "PP Up",
+    "Zinc",
+    "PP Max",
+    "Old Gateau",
+    "Guard Spec.",
+    "Dire Hit",
+    "X Attack",
+    "X Defense",
+    "X Speed",
+    "X Accuracy",
+    "X Sp. Atk",
+    "X Sp. Def",
+    "Poké Doll",
+    "Fluffy Tail",
+    "Blue Flute",
+    "Yellow Flute",
+    "Red Flute",
+    "Black Flute",
+    "White Flute",
+    "Shoal Salt",
+    "Shoal Shell",
+    "Red Shard",
+    "Blue Shard",
+    "Yellow Shard",
+    "Green Shard",
+    "Super Repel",
+    "Max Repel",
+    "Escape Rope",
+    "Repel",
+    "Sun Stone",
+    "Moon Stone",
+    "Fire Stone",
+    "Thunder Stone",
+    "Water Stone",
+    "Leaf Stone",
+    "Tiny Mushroom",
+    "Big Mushroom",
+    "Pearl",
+    "Big Pearl",
+    "Stardust",
+    "Star Piece",
+    "Nugget",
+    "Heart Scale",
+    "Honey",
+    "Growth Mulch",
+    "Damp Mulch",
+    "Stable Mulch",
+    "Gooey Mulch",
+    "Root Fossil",
+    "Claw Fossil",
+    "Helix Fossil",
+    "Dome Fossil",
+    "Old Amber",
+    "Armor Fossil",
+    "Skull Fossil",
+    "Rare Bone",
+    "Shiny Stone",
+    "Dusk Stone",
+    "Dawn Stone",
+    "Oval Stone",
+    "Odd Keystone",
+    "Griseous Orb",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "Adamant Orb",
+    "Lustrous Orb",
+    "Grass Mail",
+    "Flame Mail",
+    "Bubble Mail",
+    "Bloom Mail",
+    "Tunnel Mail",
+    "Steel Mail",
+    "Heart Mail",
+    "Snow Mail",
+    "Space Mail",
+    "Air Mail",
+    "Mosaic Mail",
+    "Brick Mail",
+    "Cheri Berry",
+    "Chesto Berry",
+    "Pecha Berry",
+    "Rawst Berry",
+    "Aspear Berry",
+    "Leppa Berry",
+    "Oran Berry",
+    "Persim Berry",
+    "Lum Berry",
+    "Sitrus Berry",
+    "Figy Berry",
+    "Wiki Berry",
+    "Mago Berry",
+    "Aguav Berry",
+    "Iapapa Berry",
+    "Razz Berry",
+    "Bluk Berry",
+    "Nanab Berry",
+    "Wepear Berry",
+    "Pinap Berry",
+    "Pomeg Berry",
+    "Kelpsy Berry",
+    "Qualot Berry",
+    "Hondew Berry",
+    "Grepa Berry",
+    "Tamato Berry",
+    "Cornn Berry",
+    "Magost Berry",
+    "Rabuta Berry",
+    "Nomel Berry",
+    "Spelon Berry",
+    "Pamtre Berry",
+    "Watmel Berry",
+    "Durin Berry",
+    "Belue Berry",
+    "Occa Berry",
+    "Passho Berry",
+    "Wacan Berry",
+    "Rindo Berry",
+    "Yache Berry",
+    "Chople Berry",
+    "Kebia Berry",
+    "Shuca Berry",
+    "Coba Berry",
+    "Payapa Berry",
+    "Tanga Berry",
+    "Charti Berry",
+    "Kasib Berry",
+    "Haban Berry",
+    "Colbur Berry",
+    "Babiri Berry",
+    "Chilan Berry",
+    "Liechi Berry",
+    "Ganlon Berry",
+    "Salac Berry",
+    "Petaya Berry",
+    "Apicot Berry",
+    "Lansat Berry",
+    "Starf Berry",
+    "Enigma Berry",
+    "Micle Berry",
+    "Custap Berry",
+    "Jaboca Berry",
+    "Rowap Berry",
+    "Bright Powder",
+    "White Herb",
+    "Macho Brace",
+    "Exp. Share",
+    "Quick Claw",
+    "Soothe Bell",
+    "Mental Herb",
+    "Choice Band",
+    "King’s Rock",
+    "Silver Powder",
+    "Amulet Coin",
+    "Cleanse Tag",
+    "Soul Dew",
+    "Deep Sea Tooth",
+    "Deep Sea Scale",
+    "Smoke Ball",
+    "Everstone",
+    "Focus Band",
+    "Lucky Egg",
+    "Scope Lens",
+    "Metal Coat",
+    "Leftovers",
+    "Dragon Scale",
+    "Light Ball",
+    "Soft Sand",
+    "Hard Stone",
+    "Miracle Seed",
+    "Black Glasses",
+    "Black Belt",
+    "Magnet",
+    "Mystic Water",
+    "Sharp Beak",
+    "Poison Barb",
+    "Never-Melt Ice",
+    "Spell Tag",
+    "Twisted Spoon",
+    "Charcoal",
+    "Dragon Fang",
+    "Silk Scarf",
+    "Up-Grade",
+    "Shell Bell",
+    "Sea Incense",
+    "Lax Incense",
+    "Lucky Punch",
+    "Metal Powder",
+    "Thick Club",
+    "Leek",
+    "Red Scarf",
+    "Blue Scarf",
+    "Pink Scarf",
+    "Green Scarf",
+    "Yellow Scarf",
+    "Wide Lens",
+    "Muscle Band",
+    "Wise Glasses",
+    "Expert Belt",
+    "Light Clay",
+    "Life Orb",
+    "Power Herb",
+    "Toxic Orb",
+    "Flame Orb",
+    "Quick Powder",
+    "Focus Sash",
+    "Zoom Lens",
+    "Metronome",
+    "Iron Ball",
+    "Lagging Tail",
+    "Destiny Knot",
+    "Black Sludge",
+    "Icy Rock",
+    "Smooth Rock",
+    "Heat Rock",
+    "Damp Rock",
+    "Grip Claw",
+    "Choice Scarf",
+    "Sticky Barb",
+    "Power Bracer",
+    "Power Belt",
+    "Power Lens",
+    "Power Band",
+    "Power Anklet",
+    "Power Weight",
+    "Shed Shell",
+    "Big Root",
+    "Choice Specs",
+    "Flame Plate",
+    "Splash Plate",
+    "Zap Plate",
+    "Meadow Plate",
+    "Icicle Plate",
+    "Fist Plate",
+    "Toxic Plate",
+    "Earth Plate",
+    "Sky Plate",
+    "Mind Plate",
+    "Insect Plate",
+    "Stone Plate",
+    "Spooky Plate",
+    "Draco Plate",
+    "Dread Plate",
+    "Iron Plate",
+    "Odd Incense",
+    "Rock Incense",
+    "Full Incense",
+    "Wave Incense",
+    "Rose Incense",
+    "Luck Incense",
+    "Pure Incense",
+    "Protector",
+    "Electirizer",
+    "Magmarizer",
+    "Dubious Disc",
+    "Reaper Cloth",
+    "Razor Claw",
+    "Razor Fang",
+];
+
+/// The English name of an item, if it's known.
+///
+/// Only the items up to the Razor Fang are listed, so TMs and later items give
+/// `None`.
+pub fn item_name(id: u16) -> Option<&'static str> {
+    NAMES
+        .get(id as usize)
+        .copied()
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_name_items() {
+        assert_eq!(item_name(4), Some("Poké Ball"));
+        assert_eq!(item_name(158), Some("Sitrus Berry"));
+        assert_eq!(item_name(234), Some("Leftovers"));
+        assert_eq!(item_name(327), Some("Razor Fang"));
+    }
+
+    #[test]
+    fn should_return_none_for_unknown_items() {
+        assert_eq!(item_name(0), None);
+        assert_eq!(item_name(120), None);
+        assert_eq!(item_name(328), None);
+    }
+}
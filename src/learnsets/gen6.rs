@@ -0,0 +1,127 @@
+use super::Learnset;
+use crate::types::{Move, Species};
+
+// X/Y and Omega Ruby/Alpha Sapphire share level-up moves, TMs and egg moves.
+// Omega Ruby/Alpha Sapphire add the move tutors.
+const PIKACHU_LEVEL_UP: &[(u8, Move)] = &[
+    (1, Move::ThunderShock),
+    (1, Move::TailWhip),
+    (5, Move::Growl),
+    (7, Move::PlayNice),
+    (10, Move::QuickAttack),
+    (13, Move::ElectroBall),
+    (18, Move::ThunderWave),
+    (21, Move::Feint),
+    (23, Move::DoubleTeam),
+    (26, Move::Spark),
+    (29, Move::Nuzzle),
+    (34, Move::Discharge),
+    (37, Move::Slam),
+    (42, Move::Thunderbolt),
+    (45, Move::Agility),
+    (50, Move::WildCharge),
+    (53, Move::LightScreen),
+    (58, Move::Thunder),
+];
+
+const PIKACHU_MACHINE: &[Move] = &[
+    Move::Thunderbolt,
+    Move::Thunder,
+    Move::Protect,
+    Move::Rest,
+    Move::VoltSwitch,
+];
+
+const EEVEE_LEVEL_UP: &[(u8, Move)] = &[
+    (1, Move::HelpingHand),
+    (1, Move::Growl),
+    (1, Move::Tackle),
+    (5, Move::TailWhip),
+    (9, Move::SandAttack),
+    (10, Move::BabyDollEyes),
+    (13, Move::QuickAttack),
+    (17, Move::Bite),
+    (20, Move::Refresh),
+    (23, Move::Covet),
+    (25, Move::Swift),
+    (29, Move::Charm),
+    (33, Move::TakeDown),
+    (37, Move::BatonPass),
+    (41, Move::DoubleEdge),
+    (45, Move::LastResort),
+    (49, Move::TrumpCard),
+];
+
+const EEVEE_EGG: &[Move] = &[
+    Move::Curse,
+    Move::Detect,
+    Move::Endure,
+    Move::FakeTears,
+    Move::Flail,
+    Move::StoredPower,
+    Move::Tickle,
+    Move::Wish,
+    Move::Yawn,
+];
+
+/// Learnsets used by X/Y.
+pub(super) const XY: [Learnset; 2] = [
+    Learnset {
+        species: Species::Pikachu,
+        form: 0,
+        level_up: PIKACHU_LEVEL_UP,
+        machine: PIKACHU_MACHINE,
+        tutor: &[],
+        egg: &[],
+        move_shop: &[],
+    },
+    Learnset {
+        species: Species::Eevee,
+        form: 0,
+        level_up: EEVEE_LEVEL_UP,
+        machine: &[Move::Protect, Move::Rest],
+        tutor: &[],
+        egg: EEVEE_EGG,
+        move_shop: &[],
+    },
+];
+
+/// Learnsets used by Omega Ruby/Alpha Sapphire.
+pub(super) const ORAS: [Learnset; 2] = [
+    Learnset {
+        species: Species::Pikachu,
+        form: 0,
+        level_up: PIKACHU_LEVEL_UP,
+        machine: PIKACHU_MACHINE,
+        tutor: &[
+            Move::Covet,
+            Move::Electroweb,
+            Move::HelpingHand,
+            Move::IronTail,
+            Move::KnockOff,
+            Move::MagnetRise,
+            Move::ShockWave,
+            Move::SignalBeam,
+            Move::Snore,
+            Move::ThunderPunch,
+        ],
+        egg: &[],
+        move_shop: &[],
+    },
+    Learnset {
+        species: Species::Eevee,
+        form: 0,
+        level_up: EEVEE_LEVEL_UP,
+        machine: &[Move::Protect, Move::Rest],
+        tutor: &[
+            Move::Covet,
+            Move::HealBell,
+            Move::HelpingHand,
+            Move::IronTail,
+            Move::LastResort,
+            Move::Snore,
+        ],
+        egg: EEVEE_EGG,
+        move_shop: &[],
+    },
+];
@@ -0,0 +1,77 @@
+use super::Learnset;
+use crate::types::{Move, Species};
+
+/// Learnsets shared by Sun/Moon and Ultra Sun/Ultra Moon.
+pub(super) const LEARNSETS: [Learnset; 2] = [
+    Learnset {
+        species: Species::Pikachu,
+        form: 0,
+        level_up: &[
+            (1, Move::ThunderShock),
+            (1, Move::TailWhip),
+            (5, Move::Growl),
+            (7, Move::PlayNice),
+            (10, Move::QuickAttack),
+            (13, Move::ElectroBall),
+            (18, Move::ThunderWave),
+            (21, Move::Feint),
+            (23, Move::DoubleTeam),
+            (26, Move::Spark),
+            (29, Move::Nuzzle),
+            (34, Move::Discharge),
+            (37, Move::Slam),
+            (42, Move::Thunderbolt),
+            (45, Move::Agility),
+            (50, Move::WildCharge),
+            (53, Move::LightScreen),
+            (58, Move::Thunder),
+        ],
+        machine: &[
+            Move::Thunderbolt,
+            Move::Thunder,
+            Move::Protect,
+            Move::Rest,
+            Move::VoltSwitch,
+        ],
+        tutor: &[],
+        egg: &[],
+        move_shop: &[],
+    },
+    Learnset {
+        species: Species::Eevee,
+        form: 0,
+        level_up: &[
+            (1, Move::HelpingHand),
+            (1, Move::Growl),
+            (1, Move::Tackle),
+            (5, Move::TailWhip),
+            (9, Move::SandAttack),
+            (10, Move::BabyDollEyes),
+            (13, Move::QuickAttack),
+            (17, Move::Bite),
+            (20, Move::Refresh),
+            (23, Move::Covet),
+            (25, Move::Swift),
+            (29, Move::Charm),
+            (33, Move::TakeDown),
+            (37, Move::BatonPass),
+            (41, Move::DoubleEdge),
+            (45, Move::LastResort),
+            (49, Move::TrumpCard),
+        ],
+        machine: &[Move::Protect, Move::Rest],
+        tutor: &[],
+        egg: &[
+            Move::Curse,
+            Move::Detect,
+            Move::Endure,
+            Move::FakeTears,
+            Move::Flail,
+            Move::StoredPower,
+            Move::Tickle,
+            Move::Wish,
+            Move::Yawn,
+        ],
+        move_shop: &[],
+    },
+];
@@ -0,0 +1,76 @@
+use super::Learnset;
+use crate::types::{Move, Species};
+
+/// Learnsets used by Sword/Shield.
+pub(super) const LEARNSETS: [Learnset; 2] = [
+    Learnset {
+        species: Species::Pikachu,
+        form: 0,
+        level_up: &[
+            (1, Move::TailWhip),
+            (1, Move::Growl),
+            (1, Move::ThunderShock),
+            (1, Move::QuickAttack),
+            (1, Move::SweetKiss),
+            (1, Move::Charm),
+            (1, Move::NastyPlot),
+            (1, Move::PlayNice),
+            (1, Move::Nuzzle),
+            (4, Move::ThunderWave),
+            (8, Move::DoubleTeam),
+            (12, Move::ElectroBall),
+            (16, Move::Feint),
+            (20, Move::Spark),
+            (24, Move::Agility),
+            (28, Move::Slam),
+            (32, Move::Discharge),
+            (36, Move::Thunderbolt),
+            (40, Move::LightScreen),
+            (44, Move::Thunder),
+        ],
+        machine: &[
+            Move::Thunderbolt,
+            Move::Thunder,
+            Move::Protect,
+            Move::Rest,
+            Move::Substitute,
+            Move::IronTail,
+            Move::VoltSwitch,
+        ],
+        tutor: &[],
+        egg: &[],
+        move_shop: &[],
+    },
+    Learnset {
+        species: Species::Eevee,
+        form: 0,
+        level_up: &[
+            (1, Move::Covet),
+            (1, Move::HelpingHand),
+            (1, Move::Growl),
+            (1, Move::Tackle),
+            (1, Move::TailWhip),
+            (5, Move::SandAttack),
+            (10, Move::QuickAttack),
+            (15, Move::BabyDollEyes),
+            (20, Move::Swift),
+            (25, Move::Bite),
+            (30, Move::Copycat),
+            (35, Move::BatonPass),
+            (40, Move::TakeDown),
+            (45, Move::Charm),
+            (50, Move::DoubleEdge),
+            (55, Move::LastResort),
+        ],
+        machine: &[Move::Protect, Move::Rest, Move::Substitute],
+        tutor: &[],
+        egg: &[
+            Move::Curse,
+            Move::Detect,
+            Move::Tickle,
+            Move::Wish,
+            Move::Yawn,
+        ],
+        move_shop: &[],
+    },
+];
@@ -0,0 +1,31 @@
+use super::Learnset;
+use crate::types::{Move, Species};
+
+/// Learnsets used by Legends: Arceus, which has no TMs, tutors or eggs.  Every
+/// move a species can learn is also sold by the move shop.
+pub(super) const LEARNSETS: [Learnset; 2] = [
+    Learnset {
+        species: Species::Pikachu,
+        form: 0,
+        level_up: &[(1, Move::QuickAttack), (1, Move::ThunderShock)],
+        machine: &[],
+        tutor: &[],
+        egg: &[],
+        move_shop: &[
+            Move::QuickAttack,
+            Move::ThunderShock,
+            Move::Thunderbolt,
+            Move::Thunder,
+            Move::Rest,
+        ],
+    },
+    Learnset {
+        species: Species::Eevee,
+        form: 0,
+        level_up: &[(1, Move::Tackle)],
+        machine: &[],
+        tutor: &[],
+        egg: &[],
+        move_shop: &[Move::Tackle, Move::Swift, Move::Rest],
+    },
+];
@@ -0,0 +1,144 @@
+mod gen6;
+mod gen7;
+mod gen8;
+mod gen8a;
+mod gen9;
+
+use crate::types::{GameVersion, Move, Species};
+use alloc::vec::Vec;
+
+/// How a move can be learned in a game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LearnMethod {
+    /// Learned by leveling up at the given level.  Level 1 includes moves
+    /// learned on evolution and through the move reminder.
+    LevelUp(u8),
+    /// Taught by a TM, TR or TM record.
+    Machine,
+    Tutor,
+    Egg,
+    /// Bought from the Legends Arceus move shop.
+    MoveShop,
+}
+
+/// Every move a species and form can learn in a single game group.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Learnset {
+    pub species: Species,
+    pub form: u8,
+    pub level_up: &'static [(u8, Move)],
+    pub machine: &'static [Move],
+    pub tutor: &'static [Move],
+    pub egg: &'static [Move],
+    pub move_shop: &'static [Move],
+}
+
+impl Learnset {
+    pub fn methods(&self, move_t: Move) -> Vec<LearnMethod> {
+        let mut methods: Vec<LearnMethod> = self
+            .level_up
+            .iter()
+            .filter(|(_, learned)| *learned == move_t)
+            .map(|(level, _)| LearnMethod::LevelUp(*level))
+            .collect();
+
+        let others = [
+            (self.machine, LearnMethod::Machine),
+            (self.tutor, LearnMethod::Tutor),
+            (self.egg, LearnMethod::Egg),
+            (self.move_shop, LearnMethod::MoveShop),
+        ];
+
+        for (moves, method) in others {
+            if moves.contains(&move_t) {
+                methods.push(method);
+            }
+        }
+
+        methods
+    }
+}
+
+fn game_learnsets(game: GameVersion) -> Option<&'static [Learnset]> {
+    match game {
+        GameVersion::X | GameVersion::Y => Some(&gen6::XY),
+        GameVersion::AlphaSapphire | GameVersion::OmegaRuby => Some(&gen6::ORAS),
+        GameVersion::Sun | GameVersion::Moon | GameVersion::UltraSun | GameVersion::UltraMoon => {
+            Some(&gen7::LEARNSETS)
+        }
+        GameVersion::Sword | GameVersion::Shield => Some(&gen8::LEARNSETS),
+        GameVersion::LegendsArceus => Some(&gen8a::LEARNSETS),
+        GameVersion::Scarlet | GameVersion::Violet => Some(&gen9::LEARNSETS),
+        _ => None,
+    }
+}
+
+/// Looks up the learnset of a species and form in a game.
+///
+/// The tables are still being filled in, so `None` means the learnset is not
+/// known rather than that the species can't learn anything.
+pub fn learnset(species: Species, form: u8, game: GameVersion) -> Option<&'static Learnset> {
+    game_learnsets(game)?
+        .iter()
+        .find(|learnset| learnset.species == species && learnset.form == form)
+}
+
+/// Lists every way a species and form can learn a move in a game.
+///
+/// An empty list means the move can't be learned, or that the learnset is
+/// not known yet; use [learnset] to tell the two apart.
+pub fn can_learn(species: Species, form: u8, game: GameVersion, move_t: Move) -> Vec<LearnMethod> {
+    learnset(species, form, game).map_or_else(Vec::new, |learnset| learnset.methods(move_t))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_find_level_up_moves() {
+        assert_eq!(
+            can_learn(Species::Pikachu, 0, GameVersion::Sword, Move::Thunderbolt),
+            [LearnMethod::LevelUp(36), LearnMethod::Machine]
+        );
+        assert_eq!(
+            can_learn(Species::Pikachu, 0, GameVersion::X, Move::Thunderbolt),
+            [LearnMethod::LevelUp(42), LearnMethod::Machine]
+        );
+    }
+
+    #[test]
+    fn should_find_egg_moves() {
+        assert_eq!(
+            can_learn(Species::Eevee, 0, GameVersion::Violet, Move::Wish),
+            [LearnMethod::Egg]
+        );
+    }
+
+    #[test]
+    fn should_return_nothing_for_unlearnable_moves() {
+        assert!(can_learn(Species::Eevee, 0, GameVersion::Moon, Move::Thunder).is_empty());
+    }
+
+    #[test]
+    fn should_find_tutor_moves_only_in_oras() {
+        assert_eq!(
+            can_learn(Species::Pikachu, 0, GameVersion::OmegaRuby, Move::KnockOff),
+            [LearnMethod::Tutor]
+        );
+        assert!(can_learn(Species::Pikachu, 0, GameVersion::Y, Move::KnockOff).is_empty());
+    }
+
+    #[test]
+    fn should_find_move_shop_moves() {
+        assert_eq!(
+            can_learn(
+                Species::Pikachu,
+                0,
+                GameVersion::LegendsArceus,
+                Move::Thunderbolt
+            ),
+            [LearnMethod::MoveShop]
+        );
+    }
+}
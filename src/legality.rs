@@ -0,0 +1,644 @@
+use crate::encounters::{self, Encounter, EncounterKind};
+use crate::locations;
+use crate::personal::personal_info;
+use crate::pkx::Pkx;
+use crate::species_names::{egg_name, species_name};
+use crate::types::{Ball, Language, Move, Species};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+const MAX_EV: u8 = 252;
+const MAX_EV_TOTAL: u16 = 510;
+const MAX_PP_UPS: u8 = 3;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LegalitySeverity {
+    /// The Pokemon can't exist in an unmodified game.
+    Invalid,
+    /// The Pokemon is unlikely, but could be legitimate.
+    Fishy,
+}
+
+impl fmt::Display for LegalitySeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LegalityCategory {
+    Checksum,
+    PidEc,
+    Shiny,
+    Ability,
+    Evs,
+    Ivs,
+    Moves,
+    Ball,
+    Level,
+    Nickname,
+    Language,
+}
+
+impl fmt::Display for LegalityCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegalityFinding {
+    pub severity: LegalitySeverity,
+    pub category: LegalityCategory,
+    pub message: String,
+}
+
+impl LegalityFinding {
+    fn invalid(category: LegalityCategory, message: impl Into<String>) -> Self {
+        Self {
+            severity: LegalitySeverity::Invalid,
+            category,
+            message: message.into(),
+        }
+    }
+
+    fn fishy(category: LegalityCategory, message: impl Into<String>) -> Self {
+        Self {
+            severity: LegalitySeverity::Fishy,
+            category,
+            message: message.into(),
+        }
+    }
+}
+
+/// The fixed encounter a Pokemon was met in, if one in its game has the same
+/// species and form and lists its met location.  Hatched Pokemon never come
+/// from one.
+fn static_encounter<T: Pkx>(pkm: &T) -> Option<&'static Encounter> {
+    if has_egg_location(pkm.egg_location()) {
+        return None;
+    }
+
+    encounters::encounters(pkm.version_t()).find(|encounter| {
+        encounter.kind == EncounterKind::Static
+            && encounter.species == pkm.species_t()
+            && encounter.form == pkm.form()
+            && encounter.locations.contains(&pkm.met_location())
+    })
+}
+
+fn check_pid_ec<T: Pkx>(pkm: &T, findings: &mut Vec<LegalityFinding>) {
+    let generation = pkm.version_t().generation();
+    let matches = pkm.encryption_constant() == pkm.pid();
+
+    // Poke Transfer copies the PID of Gen3-5 Pokemon into the encryption constant.
+    if (3..=5).contains(&generation) && !matches {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::PidEc,
+            "Encryption constant should match the PID of Gen3-5 Pokemon.",
+        ));
+    } else if generation >= 6 && matches {
+        findings.push(LegalityFinding::fishy(
+            LegalityCategory::PidEc,
+            "Encryption constant matches the PID.",
+        ));
+    }
+}
+
+fn check_fixed_encounter<T: Pkx>(pkm: &T, findings: &mut Vec<LegalityFinding>) {
+    let Some(encounter) = static_encounter(pkm) else {
+        return;
+    };
+
+    if !encounter.shiny.allows(pkm.is_shiny()) {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Shiny,
+            format!("{} can't be shiny.", encounter.species),
+        ));
+    }
+
+    let ivs = [
+        pkm.iv_hp(),
+        pkm.iv_atk(),
+        pkm.iv_def(),
+        pkm.iv_spa(),
+        pkm.iv_spd(),
+        pkm.iv_spe(),
+    ];
+    let flawless = ivs.iter().filter(|iv| **iv == 31).count() as u8;
+    if flawless < encounter.flawless_ivs {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Ivs,
+            format!(
+                "{} should have at least {} perfect IVs.",
+                encounter.species, encounter.flawless_ivs
+            ),
+        ));
+    }
+}
+
+fn check_ability<T: Pkx>(pkm: &T, findings: &mut Vec<LegalityFinding>) {
+    let ability_number = pkm.ability_number();
+    if !matches!(ability_number, 1 | 2 | 4) {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Ability,
+            format!("Ability number {} is not valid.", ability_number),
+        ));
+        return;
+    }
+
+    let Some(personal) = personal_info(pkm.species_t(), pkm.form()) else {
+        return;
+    };
+
+    let ability = pkm.ability_t();
+    if personal.ability(ability_number) == Some(ability) {
+        return;
+    }
+
+    let message = if personal.abilities.contains(&ability) {
+        format!(
+            "{} doesn't match ability number {}.",
+            ability, ability_number
+        )
+    } else {
+        format!("{} can't have {}.", pkm.species_t(), ability)
+    };
+    findings.push(LegalityFinding::invalid(LegalityCategory::Ability, message));
+}
+
+fn check_evs<T: Pkx>(pkm: &T, findings: &mut Vec<LegalityFinding>) {
+    let evs = [
+        pkm.ev_hp(),
+        pkm.ev_atk(),
+        pkm.ev_def(),
+        pkm.ev_spa(),
+        pkm.ev_spd(),
+        pkm.ev_spe(),
+    ];
+
+    let total: u16 = evs.iter().map(|ev| u16::from(*ev)).sum();
+    if total > MAX_EV_TOTAL {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Evs,
+            format!("EV total {} is above {}.", total, MAX_EV_TOTAL),
+        ));
+    }
+
+    if evs.iter().any(|ev| *ev > MAX_EV) {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Evs,
+            format!("EVs can't be above {}.", MAX_EV),
+        ));
+    }
+
+    if pkm.is_egg() && total != 0 {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Evs,
+            "Eggs can't have EVs.",
+        ));
+    }
+}
+
+fn check_moves<T: Pkx>(pkm: &T, findings: &mut Vec<LegalityFinding>) {
+    let moves = pkm.iter_moves();
+    let pp = [
+        pkm.move1_pp(),
+        pkm.move2_pp(),
+        pkm.move3_pp(),
+        pkm.move4_pp(),
+    ];
+    let pp_ups = [
+        pkm.move1_pp_ups(),
+        pkm.move2_pp_ups(),
+        pkm.move3_pp_ups(),
+        pkm.move4_pp_ups(),
+    ];
+
+    if moves[0] == Move::None {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Moves,
+            "The first move slot is empty.",
+        ));
+    }
+
+    for (index, move_t) in moves.iter().enumerate() {
+        let slot = index + 1;
+
+        if pp_ups[index] > MAX_PP_UPS {
+            findings.push(LegalityFinding::invalid(
+                LegalityCategory::Moves,
+                format!("Move {} has more than {} PP Ups.", slot, MAX_PP_UPS),
+            ));
+        }
+
+        if *move_t == Move::None {
+            if pp[index] != 0 || pp_ups[index] != 0 {
+                findings.push(LegalityFinding::invalid(
+                    LegalityCategory::Moves,
+                    format!("Empty move {} has PP.", slot),
+                ));
+            }
+            continue;
+        }
+
+        let max_pp = move_t.max_pp(pp_ups[index]);
+        if pp[index] > max_pp {
+            findings.push(LegalityFinding::invalid(
+                LegalityCategory::Moves,
+                format!("{} has more than {} PP.", move_t, max_pp),
+            ));
+        }
+
+        if moves[..index].contains(move_t) {
+            findings.push(LegalityFinding::invalid(
+                LegalityCategory::Moves,
+                format!("{} is known more than once.", move_t),
+            ));
+        }
+    }
+
+    // Moves aren't checked against learnsets until every species and form is
+    // listed, since a missing entry would flag legal moves.
+}
+
+fn check_ball<T: Pkx>(pkm: &T, findings: &mut Vec<LegalityFinding>) {
+    let ball = pkm.ball_t();
+    let valid = if ball == Ball::None {
+        pkm.is_egg()
+    } else {
+        ball.is_valid_for(pkm.version_t())
+    };
+
+    if !valid {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Ball,
+            format!("{} can't be used in {}.", ball, pkm.version_t()),
+        ));
+    }
+}
+
+fn has_egg_location(location: u16) -> bool {
+    location != locations::NO_LOCATION && location != u16::MAX
+}
+
+fn check_level<T: Pkx>(pkm: &T, findings: &mut Vec<LegalityFinding>) {
+    let met_level = pkm.met_level();
+
+    if met_level > 100 {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Level,
+            format!("Met level {} is above 100.", met_level),
+        ));
+    }
+
+    let level = personal_info(pkm.species_t(), pkm.form())
+        .map(|personal| personal.growth_rate.level_for_exp(pkm.exp()));
+    if let Some(level) = level.filter(|level| met_level > *level && !pkm.is_egg()) {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Level,
+            format!(
+                "Met level {} is above the current level {}.",
+                met_level, level
+            ),
+        ));
+    }
+
+    let hatched = !pkm.is_egg() && has_egg_location(pkm.egg_location());
+    if hatched && pkm.version_t().generation() >= 6 && met_level != 1 {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Level,
+            "Hatched Pokemon should have a met level of 1.",
+        ));
+    }
+
+    if hatched {
+        return;
+    }
+
+    let Some(encounter) = static_encounter(pkm) else {
+        return;
+    };
+
+    if !(encounter.level_min..=encounter.level_max).contains(&met_level) {
+        let levels = if encounter.level_min == encounter.level_max {
+            format!("{}", encounter.level_min)
+        } else {
+            format!("{}-{}", encounter.level_min, encounter.level_max)
+        };
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Level,
+            format!("{} should be met at level {}.", encounter.species, levels),
+        ));
+    }
+}
+
+fn check_language<T: Pkx>(pkm: &T, findings: &mut Vec<LegalityFinding>) {
+    let language = pkm.language_t();
+
+    if language == Language::Invalid {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Language,
+            format!("Language {} is not valid.", pkm.language()),
+        ));
+        return;
+    }
+
+    // Korean and Chinese names aren't known, so those nicknames aren't checked.
+    let expected = if pkm.is_egg() {
+        egg_name(language)
+    } else if pkm.is_nicknamed() {
+        None
+    } else {
+        species_name(pkm.species_t(), language)
+    };
+
+    if let Some(expected) = expected.filter(|name| pkm.nickname() != *name) {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Nickname,
+            format!("Nickname should be {}.", expected),
+        ));
+    }
+}
+
+/// Checks a Pokemon for data that an unmodified game can't produce.
+///
+/// An empty list means no problems were found.  The checks are limited to the
+/// data this crate knows about, so a clean result isn't proof of legitimacy.
+/// The encounter tables are partial, so a Pokemon that matches no known
+/// encounter isn't flagged for it.
+pub fn check_legality<T: Pkx>(pkm: &T) -> Vec<LegalityFinding> {
+    let mut findings = Vec::new();
+
+    if pkm.sanity() != 0 || !pkm.valid_checksum() {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Checksum,
+            "Checksum or sanity value is invalid.",
+        ));
+        return findings;
+    }
+
+    if pkm.species_t() == Species::None {
+        findings.push(LegalityFinding::invalid(
+            LegalityCategory::Checksum,
+            "Species is empty.",
+        ));
+        return findings;
+    }
+
+    check_pid_ec(pkm, &mut findings);
+    check_fixed_encounter(pkm, &mut findings);
+    check_ability(pkm, &mut findings);
+    check_evs(pkm, &mut findings);
+    check_moves(pkm, &mut findings);
+    check_ball(pkm, &mut findings);
+    check_level(pkm, &mut findings);
+    check_language(pkm, &mut findings);
+
+    findings
+}
+
+/// Whether [check_legality] found nothing invalid.
+pub fn is_legal<T: Pkx>(pkm: &T) -> bool {
+    check_legality(pkm)
+        .iter()
+        .all(|finding| finding.severity != LegalitySeverity::Invalid)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::encounters::EncounterMatcher;
+    use crate::pk8::Pk8;
+    use crate::pkx::PkxMut;
+    use crate::types::{Ability, GameVersion, GrowthRate};
+
+    /// A wild Level 5 Pikachu caught in a Poke Ball in Sword.
+    fn pikachu() -> Pk8 {
+        let mut pkm = Pk8::default();
+        pkm.set_encryption_constant(0x12345678);
+        pkm.set_species(Species::Pikachu as u16);
+        pkm.set_exp(GrowthRate::MediumFast.exp_for_level(5));
+        pkm.set_ability(Ability::Static as u16);
+        pkm.set_ability_number(1);
+        pkm.set_pid(0x9abcdef0);
+        pkm.set_nickname("Pikachu");
+        pkm.set_moves([Move::ThunderShock, Move::None, Move::None, Move::None]);
+        pkm.heal_pp();
+        pkm.set_version(GameVersion::Sword as u8);
+        pkm.set_language(Language::English as u8);
+        pkm.set_met_location(12);
+        pkm.set_ball(Ball::Poke as u8);
+        pkm.set_met_level(5);
+        pkm.refresh_checksum();
+        pkm
+    }
+
+    fn edited(edit: impl FnOnce(&mut Pk8)) -> Pk8 {
+        let mut pkm = pikachu();
+        edit(&mut pkm);
+        pkm.refresh_checksum();
+        pkm
+    }
+
+    fn categories(pkm: &Pk8) -> Vec<LegalityCategory> {
+        check_legality(pkm)
+            .iter()
+            .map(|finding| finding.category)
+            .collect()
+    }
+
+    #[test]
+    fn should_pass_a_clean_pokemon() {
+        let pkm = pikachu();
+        assert_eq!(check_legality(&pkm), []);
+        assert!(is_legal(&pkm));
+    }
+
+    #[test]
+    fn should_not_flag_pokemon_without_known_encounters() {
+        let pkm = pikachu();
+        assert!(EncounterMatcher::new(&pkm).candidates().is_empty());
+        assert_eq!(check_legality(&pkm), []);
+    }
+
+    #[test]
+    fn should_stop_at_bad_checksums() {
+        let mut pkm = pikachu();
+        pkm.set_species(Species::Raichu as u16);
+        assert_eq!(categories(&pkm), [LegalityCategory::Checksum]);
+    }
+
+    #[test]
+    fn should_flag_ev_totals() {
+        let pkm = edited(|pkm| {
+            pkm.set_ev_hp(252);
+            pkm.set_ev_atk(252);
+            pkm.set_ev_def(10);
+        });
+        assert_eq!(categories(&pkm), [LegalityCategory::Evs]);
+        assert!(!is_legal(&pkm));
+    }
+
+    #[test]
+    fn should_flag_pp_ups_and_duplicate_moves() {
+        let pkm = edited(|pkm| {
+            pkm.set_move2(Move::ThunderShock as u16);
+            pkm.set_move2_pp(30);
+            pkm.set_move1_pp_ups(4);
+        });
+        assert_eq!(
+            categories(&pkm),
+            [LegalityCategory::Moves, LegalityCategory::Moves]
+        );
+    }
+
+    #[test]
+    fn should_flag_pp_above_the_maximum() {
+        let pkm = edited(|pkm| pkm.set_move1_pp(31));
+        assert_eq!(categories(&pkm), [LegalityCategory::Moves]);
+
+        let pkm = edited(|pkm| {
+            pkm.set_move1_pp_ups(3);
+            pkm.set_move1_pp(48);
+        });
+        assert_eq!(check_legality(&pkm), []);
+    }
+
+    #[test]
+    fn should_not_flag_moves_against_partial_learnsets() {
+        let pkm = edited(|pkm| {
+            pkm.set_move2(Move::Facade as u16);
+            pkm.heal_pp();
+        });
+        assert_eq!(check_legality(&pkm), []);
+    }
+
+    #[test]
+    fn should_flag_hisui_balls_outside_legends_arceus() {
+        let pkm = edited(|pkm| pkm.set_ball(Ball::HisuiPoke as u8));
+        assert_eq!(categories(&pkm), [LegalityCategory::Ball]);
+    }
+
+    #[test]
+    fn should_flag_strange_balls_outside_legends_arceus() {
+        let pkm = edited(|pkm| pkm.set_ball(Ball::Strange as u8));
+        assert_eq!(categories(&pkm), [LegalityCategory::Ball]);
+    }
+
+    #[test]
+    fn should_flag_ability_numbers() {
+        let pkm = edited(|pkm| pkm.set_ability_number(3));
+        assert_eq!(categories(&pkm), [LegalityCategory::Ability]);
+    }
+
+    #[test]
+    fn should_flag_abilities_the_species_cant_have() {
+        let pkm = edited(|pkm| pkm.set_ability(Ability::Intimidate as u16));
+        assert_eq!(categories(&pkm), [LegalityCategory::Ability]);
+
+        let pkm = edited(|pkm| pkm.set_ability(Ability::LightningRod as u16));
+        assert_eq!(categories(&pkm), [LegalityCategory::Ability]);
+
+        let pkm = edited(|pkm| {
+            pkm.set_ability(Ability::LightningRod as u16);
+            pkm.set_ability_number(4);
+        });
+        assert_eq!(check_legality(&pkm), []);
+    }
+
+    #[test]
+    fn should_flag_pid_ec_matches() {
+        let pkm = edited(|pkm| pkm.set_encryption_constant(0x9abcdef0));
+        assert_eq!(categories(&pkm), [LegalityCategory::PidEc]);
+    }
+
+    #[test]
+    fn should_flag_hatched_met_levels() {
+        let pkm = edited(|pkm| pkm.set_egg_location(locations::DAY_CARE));
+        assert_eq!(categories(&pkm), [LegalityCategory::Level]);
+    }
+
+    #[test]
+    fn should_flag_met_levels_above_the_current_level() {
+        let pkm = edited(|pkm| pkm.set_met_level(6));
+        assert_eq!(categories(&pkm), [LegalityCategory::Level]);
+    }
+
+    #[test]
+    fn should_flag_species_names() {
+        let pkm = edited(|pkm| pkm.set_nickname("Qikachu"));
+        assert_eq!(categories(&pkm), [LegalityCategory::Nickname]);
+
+        let pkm = edited(|pkm| pkm.set_is_nicknamed(true));
+        assert_eq!(check_legality(&pkm), []);
+    }
+
+    #[test]
+    fn should_check_localized_species_names() {
+        let pkm = edited(|pkm| {
+            pkm.set_species(Species::FarfetchD as u16);
+            pkm.set_ability(Ability::KeenEye as u16);
+            pkm.set_language(Language::French as u8);
+            pkm.set_nickname("Canarticho");
+        });
+        assert_eq!(check_legality(&pkm), []);
+
+        let pkm = edited(|pkm| {
+            pkm.set_species(Species::FarfetchD as u16);
+            pkm.set_ability(Ability::KeenEye as u16);
+            pkm.set_nickname("Farfetch\u{2019}d");
+        });
+        assert_eq!(check_legality(&pkm), []);
+
+        let pkm = edited(|pkm| pkm.set_language(Language::Korean as u8));
+        assert_eq!(check_legality(&pkm), []);
+    }
+
+    #[test]
+    fn should_flag_fixed_encounters() {
+        let pkm = edited(|pkm| {
+            pkm.set_species(Species::Zacian as u16);
+            pkm.set_ability(Ability::IntrepidSword as u16);
+            pkm.set_exp(GrowthRate::Slow.exp_for_level(70));
+            pkm.set_met_level(70);
+            pkm.set_nickname("Zacian");
+            pkm.set_met_location(66);
+            // Shiny for TID/SID 0
+            pkm.set_pid(0x00010001);
+        });
+        assert_eq!(
+            categories(&pkm),
+            [LegalityCategory::Shiny, LegalityCategory::Ivs]
+        );
+    }
+
+    #[test]
+    fn should_only_apply_fixed_encounters_at_their_location() {
+        let pkm = edited(|pkm| {
+            pkm.set_species(Species::Zacian as u16);
+            pkm.set_ability(Ability::IntrepidSword as u16);
+            pkm.set_exp(GrowthRate::Slow.exp_for_level(70));
+            pkm.set_met_level(60);
+            pkm.set_nickname("Zacian");
+            pkm.set_pid(0x00010001);
+        });
+        assert_eq!(check_legality(&pkm), []);
+    }
+
+    #[test]
+    fn should_flag_fixed_encounter_levels() {
+        let pkm = edited(|pkm| {
+            pkm.set_species(Species::Zacian as u16);
+            pkm.set_ability(Ability::IntrepidSword as u16);
+            pkm.set_exp(GrowthRate::Slow.exp_for_level(70));
+            pkm.set_met_level(60);
+            pkm.set_nickname("Zacian");
+            pkm.set_met_location(66);
+            pkm.set_iv_hp(31);
+            pkm.set_iv_atk(31);
+            pkm.set_iv_def(31);
+        });
+        assert_eq!(categories(&pkm), [LegalityCategory::Level]);
+    }
+}
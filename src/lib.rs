@@ -5,22 +5,60 @@ extern crate alloc;
 #[cfg(test)]
 mod test_utils;
 
+mod convert;
+mod correlation;
+mod encounters;
+mod evolutions;
+mod items;
+// Only a few species are listed so far, so the learnsets stay private until
+// every species and form is filled in.
+#[allow(dead_code)]
+mod learnsets;
+mod legality;
+mod locations;
+mod memories;
 mod pa8;
+mod party;
+mod personal;
+mod pk5;
 mod pk6;
 mod pk7;
 mod pk8;
 mod pk9;
+mod pkm_storage;
 mod pkx;
 mod poke_crypto;
 mod reader;
+pub mod rng;
+mod saves;
+mod scblock;
+mod species_names;
 mod strings;
 mod types;
+mod wondercards;
+mod writer;
 
+pub use convert::*;
+pub use correlation::*;
+pub use encounters::*;
+pub use evolutions::*;
+pub use items::*;
+pub use legality::*;
+pub use locations::*;
+pub use memories::*;
 pub use pa8::*;
+pub use party::*;
+pub use personal::*;
+pub use pk5::*;
 pub use pk6::*;
 pub use pk7::*;
 pub use pk8::*;
 pub use pk9::*;
+pub use pkm_storage::*;
 pub use pkx::*;
 pub use poke_crypto::*;
+pub use saves::*;
+pub use scblock::*;
+pub use species_names::*;
 pub use types::*;
+pub use wondercards::*;
@@ -0,0 +1,81 @@
+/// Met locations used by X/Y and Omega Ruby/Alpha Sapphire.
+pub(super) const LOCATIONS: [(u16, &str); 78] = [
+    (2, "Mystery Zone"),
+    (4, "Faraway place"),
+    (6, "Vaniville Town"),
+    (8, "Route 1"),
+    (10, "Aquacorde Town"),
+    (12, "Route 2"),
+    (14, "Santalune Forest"),
+    (16, "Route 3"),
+    (18, "Santalune City"),
+    (20, "Route 4"),
+    (22, "Lumiose City"),
+    (24, "Prism Tower"),
+    (26, "Lysandre Labs"),
+    (28, "Route 5"),
+    (30, "Camphrier Town"),
+    (32, "Shabboneau Castle"),
+    (34, "Route 6"),
+    (36, "Parfum Palace"),
+    (38, "Route 7"),
+    (40, "Connecting Cave"),
+    (42, "Route 8"),
+    (44, "Ambrette Town"),
+    (56, "Glittering Cave"),
+    (60, "Shalour City"),
+    (138, "Team Flare Secret HQ"),
+    (140, "Terminus Cave"),
+    (142, "Lost Hotel"),
+    (146, "Unknown Dungeon"),
+    (170, "Littleroot Town"),
+    (172, "Oldale Town"),
+    (174, "Dewford Town"),
+    (176, "Lavaridge Town"),
+    (178, "Fallarbor Town"),
+    (180, "Verdanturf Town"),
+    (182, "Pacifidlog Town"),
+    (184, "Petalburg City"),
+    (186, "Slateport City"),
+    (188, "Mauville City"),
+    (190, "Rustboro City"),
+    (192, "Fortree City"),
+    (194, "Lilycove City"),
+    (196, "Mossdeep City"),
+    (198, "Sootopolis City"),
+    (200, "Ever Grande City"),
+    (202, "Route 101"),
+    (204, "Route 102"),
+    (206, "Route 103"),
+    (208, "Route 104"),
+    (210, "Route 105"),
+    (212, "Route 106"),
+    (214, "Route 107"),
+    (216, "Route 108"),
+    (218, "Route 109"),
+    (220, "Route 110"),
+    (222, "Route 111"),
+    (224, "Route 112"),
+    (226, "Route 113"),
+    (228, "Route 114"),
+    (230, "Route 115"),
+    (232, "Route 116"),
+    (234, "Route 117"),
+    (236, "Route 118"),
+    (238, "Route 119"),
+    (240, "Route 120"),
+    (242, "Route 121"),
+    (244, "Route 122"),
+    (246, "Route 123"),
+    (248, "Route 124"),
+    (250, "Route 125"),
+    (252, "Route 126"),
+    (254, "Route 127"),
+    (256, "Route 128"),
+    (258, "Route 129"),
+    (260, "Route 130"),
+    (262, "Route 131"),
+    (264, "Route 132"),
+    (266, "Route 133"),
+    (268, "Route 134"),
+];
@@ -0,0 +1,6 @@
+/// Met locations used by Sun/Moon and Ultra Sun/Ultra Moon.
+pub(super) const LOCATIONS: [(u16, &str); 3] = [
+    (2, "Mystery Zone"),
+    (4, "Faraway place"),
+    (140, "Ruins of Conflict"),
+];
@@ -0,0 +1,45 @@
+/// Met locations used by Sword/Shield.
+pub(super) const LOCATIONS: [(u16, &str); 42] = [
+    (2, "Mystery Zone"),
+    (4, "Faraway place"),
+    (6, "Postwick"),
+    (8, "Slumbering Weald"),
+    (12, "Route 1"),
+    (14, "Wedgehurst"),
+    (18, "Route 2"),
+    (66, "Tower Summit"),
+    (122, "Rolling Fields"),
+    (124, "Dappled Grove"),
+    (126, "Watchtower Ruins"),
+    (128, "East Lake Axewell"),
+    (130, "West Lake Axewell"),
+    (132, "Axew's Eye"),
+    (134, "South Lake Miloch"),
+    (136, "Giant's Seat"),
+    (138, "North Lake Miloch"),
+    (140, "Motostoke Riverbank"),
+    (142, "Bridge Field"),
+    (144, "Stony Wilderness"),
+    (146, "Dusty Bowl"),
+    (148, "Giant's Mirror"),
+    (150, "Hammerlocke Hills"),
+    (152, "Giant's Cap"),
+    (154, "Lake of Outrage"),
+    (164, "Fields of Honor"),
+    (166, "Soothing Wetlands"),
+    (168, "Forest of Focus"),
+    (170, "Challenge Beach"),
+    (172, "Brawlers' Cave"),
+    (174, "Challenge Road"),
+    (176, "Courageous Cavern"),
+    (178, "Loop Lagoon"),
+    (180, "Training Lowlands"),
+    (182, "Warm-Up Tunnel"),
+    (184, "Potbottom Desert"),
+    (186, "Workout Sea"),
+    (188, "Stepping-Stone Sea"),
+    (190, "Insular Sea"),
+    (192, "Honeycalm Sea"),
+    (194, "Honeycalm Island"),
+    (244, "Max Lair"),
+];
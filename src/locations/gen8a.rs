@@ -0,0 +1,6 @@
+/// Met locations used by Legends: Arceus.
+pub(super) const LOCATIONS: [(u16, &str); 3] = [
+    (2, "Mystery Zone"),
+    (4, "Faraway place"),
+    (6, "Jubilife Village"),
+];
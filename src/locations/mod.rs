@@ -0,0 +1,131 @@
+mod gen6;
+mod gen7;
+mod gen8;
+mod gen8a;
+mod gen9;
+mod special;
+
+pub use special::*;
+
+use crate::types::GameVersion;
+
+type LocationTable = [(u16, &'static str)];
+
+fn find_location(table: &'static LocationTable, id: u16) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&id, |(location, _)| *location)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+fn game_locations(version: GameVersion) -> Option<&'static LocationTable> {
+    match version {
+        GameVersion::X | GameVersion::Y | GameVersion::AlphaSapphire | GameVersion::OmegaRuby => {
+            Some(&gen6::LOCATIONS)
+        }
+        GameVersion::Sun | GameVersion::Moon | GameVersion::UltraSun | GameVersion::UltraMoon => {
+            Some(&gen7::LOCATIONS)
+        }
+        GameVersion::Sword | GameVersion::Shield => Some(&gen8::LOCATIONS),
+        GameVersion::LegendsArceus => Some(&gen8a::LOCATIONS),
+        GameVersion::Scarlet | GameVersion::Violet => Some(&gen9::LOCATIONS),
+        _ => None,
+    }
+}
+
+/// Resolves a met or egg location id to its name.
+///
+/// Ids in the special ranges (link trades, transfers, HOME, day care, etc.)
+/// are shared by every Gen6+ game.  All other ids are looked up in the table
+/// of the game the Pokemon originated from, since that is the game that
+/// wrote the location.  The game tables are partial, so unlisted ids give
+/// `None`.
+pub fn location_name(version: GameVersion, id: u16) -> Option<&'static str> {
+    if id == NO_LOCATION {
+        return None;
+    }
+
+    if is_special_location(id) {
+        return find_location(&special::LOCATIONS, id);
+    }
+
+    game_locations(version).and_then(|table| find_location(table, id))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn is_sorted(table: &LocationTable) -> bool {
+        table.windows(2).all(|pair| pair[0].0 < pair[1].0)
+    }
+
+    #[test]
+    fn tables_should_be_sorted() {
+        assert!(is_sorted(&special::LOCATIONS));
+        assert!(is_sorted(&gen6::LOCATIONS));
+        assert!(is_sorted(&gen7::LOCATIONS));
+        assert!(is_sorted(&gen8::LOCATIONS));
+        assert!(is_sorted(&gen8a::LOCATIONS));
+        assert!(is_sorted(&gen9::LOCATIONS));
+    }
+
+    #[test]
+    fn should_resolve_game_locations() {
+        assert_eq!(location_name(GameVersion::Y, 140), Some("Terminus Cave"));
+        assert_eq!(location_name(GameVersion::Shield, 244), Some("Max Lair"));
+        assert_eq!(
+            location_name(GameVersion::Sword, 126),
+            Some("Watchtower Ruins")
+        );
+        assert_eq!(location_name(GameVersion::X, 18), Some("Santalune City"));
+        assert_eq!(
+            location_name(GameVersion::AlphaSapphire, 268),
+            Some("Route 134")
+        );
+        assert_eq!(
+            location_name(GameVersion::Sword, 164),
+            Some("Fields of Honor")
+        );
+    }
+
+    #[test]
+    fn should_use_the_origin_game_table() {
+        assert_eq!(location_name(GameVersion::X, 154), None);
+        assert_eq!(
+            location_name(GameVersion::Sword, 140),
+            Some("Motostoke Riverbank")
+        );
+    }
+
+    #[test]
+    fn should_resolve_special_ranges_for_any_game() {
+        assert_eq!(
+            location_name(GameVersion::X, POKE_TRANSFER),
+            Some("Poké Transfer")
+        );
+        assert_eq!(
+            location_name(GameVersion::Scarlet, HOME),
+            Some("Pokémon HOME")
+        );
+        assert_eq!(
+            location_name(GameVersion::Red, TRANSFER_VC_KANTO),
+            Some("the Kanto region")
+        );
+        assert_eq!(
+            location_name(GameVersion::Violet, TERA_RAID),
+            Some("a Tera Raid Battle")
+        );
+        assert_eq!(
+            location_name(GameVersion::UltraMoon, DAY_CARE),
+            Some("Day-Care Couple")
+        );
+    }
+
+    #[test]
+    fn should_return_none_for_unknown_locations() {
+        assert_eq!(location_name(GameVersion::X, NO_LOCATION), None);
+        assert_eq!(location_name(GameVersion::Black, 16), None);
+        assert_eq!(location_name(GameVersion::Scarlet, 29999), None);
+    }
+}
@@ -0,0 +1,43 @@
+/// Stored when a Pokemon has no egg location.
+pub const NO_LOCATION: u16 = 0;
+/// Pokemon moved forward with Pokemon Bank's Poke Transfer.
+pub const POKE_TRANSFER: u16 = 30001;
+pub const LINK_TRADE: u16 = 30002;
+/// Pokemon moved into a game through Pokemon HOME.
+pub const HOME: u16 = 30012;
+/// Gen1 Virtual Console transfers.
+pub const TRANSFER_VC_KANTO: u16 = 30013;
+/// Gen2 Virtual Console transfers.
+pub const TRANSFER_VC_JOHTO: u16 = 30017;
+/// Scarlet and Violet Tera Raid Battles.
+pub const TERA_RAID: u16 = 30024;
+pub const STRANGER: u16 = 60001;
+pub const DAY_CARE: u16 = 60002;
+
+pub(super) const LOCATIONS: [(u16, &str); 20] = [
+    (POKE_TRANSFER, "Poké Transfer"),
+    (LINK_TRADE, "a Link Trade"),
+    (30003, "the Kanto region"),
+    (30004, "the Johto region"),
+    (30005, "the Hoenn region"),
+    (30006, "the Sinnoh region"),
+    (30007, "a distant land"),
+    (30009, "the Unova region"),
+    (30010, "the Kalos region"),
+    (30011, "Pokémon Link"),
+    (HOME, "Pokémon HOME"),
+    (TRANSFER_VC_KANTO, "the Kanto region"),
+    (30015, "the Alola region"),
+    (30016, "Poké Pelago"),
+    (TRANSFER_VC_JOHTO, "the Johto region"),
+    (TERA_RAID, "a Tera Raid Battle"),
+    (40001, "a lovely place"),
+    (STRANGER, "a stranger"),
+    (DAY_CARE, "Day-Care Couple"),
+    (60003, "a Pokémon Breeder"),
+];
+
+/// Whether an id falls into one of the ranges every Gen6+ game shares.
+pub fn is_special_location(id: u16) -> bool {
+    id >= 30000
+}
@@ -0,0 +1,174 @@
+use crate::items;
+use crate::locations;
+use crate::types;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+/// What a memory's text variable refers to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MemoryArg {
+    None,
+    Location,
+    Species,
+    Move,
+    Item,
+}
+
+/// A single trainer memory as stored by Gen6+ formats.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Memory {
+    pub id: u8,
+    pub intensity: u8,
+    pub text_var: u16,
+    pub feeling: u8,
+}
+
+impl Memory {
+    pub fn is_empty(&self) -> bool {
+        self.id == 0
+    }
+
+    pub fn arg(&self) -> MemoryArg {
+        find_memory(self.id).map_or(MemoryArg::None, |(_, arg)| arg)
+    }
+}
+
+// {0} is the nickname, {1} the trainer and {2} the text variable.  Only the
+// first Gen6 memories are listed, so later ids render as `None`.
+#[rustfmt::skip]
+const MEMORIES: [(u8, &str, MemoryArg); 16] = [
+    (1, "{0} remembers arriving via Link Trade at {2} and becoming {1}'s friend.", MemoryArg::Location),
+    (2, "{0} remembers hatching from an Egg at {2} and seeing {1} for the first time.", MemoryArg::Location),
+    (3, "{0} remembers being caught by {1} at {2}.", MemoryArg::Location),
+    (4, "{0} remembers being received in a trade by {1} at {2}.", MemoryArg::Location),
+    (5, "{0} remembers going to a Pokémon Center at {2} with {1}.", MemoryArg::Location),
+    (6, "{0} remembers traveling to {2} with {1}.", MemoryArg::Location),
+    (7, "{0} remembers fainting in battle while traveling with {1}.", MemoryArg::None),
+    (8, "{0} remembers losing a battle against a {2} with {1}.", MemoryArg::Species),
+    (9, "{0} remembers winning a battle against a {2} with {1}.", MemoryArg::Species),
+    (10, "{0} remembers being given a {2} by {1}.", MemoryArg::Item),
+    (11, "{0} remembers battling alongside a {2} with {1}.", MemoryArg::Species),
+    (12, "{0} remembers {1} using {2} on it.", MemoryArg::Item),
+    (13, "{0} remembers relaxing with {1} at {2}.", MemoryArg::Location),
+    (14, "{0} remembers learning {2} while with {1}.", MemoryArg::Move),
+    (15, "{0} remembers seeing a {2} with {1}.", MemoryArg::Species),
+    (16, "{0} remembers shopping at {2} with {1}.", MemoryArg::Location),
+];
+
+fn find_memory(id: u8) -> Option<(&'static str, MemoryArg)> {
+    MEMORIES
+        .iter()
+        .find(|(memory, _, _)| *memory == id)
+        .map(|(_, text, arg)| (*text, *arg))
+}
+
+fn text_var_name(memory: &Memory, arg: MemoryArg, version: types::GameVersion) -> String {
+    let var = memory.text_var;
+    match arg {
+        MemoryArg::None => String::new(),
+        MemoryArg::Location => locations::location_name(version, var)
+            .map_or_else(|| format!("location {}", var), ToString::to_string),
+        MemoryArg::Species => types::Species::from(var).to_string(),
+        MemoryArg::Move => types::Move::from(var).to_string(),
+        MemoryArg::Item => {
+            items::item_name(var).map_or_else(|| format!("item {}", var), ToString::to_string)
+        }
+    }
+}
+
+/// Renders a memory's English text with the nickname, trainer and text
+/// variable filled in.
+///
+/// Locations are resolved against `version`, the game the memory was made in.
+/// Returns `None` for empty or unknown memories.
+pub fn memory_text(
+    memory: &Memory,
+    nickname: &str,
+    trainer: &str,
+    version: types::GameVersion,
+) -> Option<String> {
+    if memory.is_empty() {
+        return None;
+    }
+
+    let (text, arg) = find_memory(memory.id)?;
+    let var = text_var_name(memory, arg, version);
+
+    Some(
+        text.replace("{0}", nickname)
+            .replace("{1}", trainer)
+            .replace("{2}", &var),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_substitute_locations() {
+        let memory = Memory {
+            id: 3,
+            intensity: 1,
+            text_var: 140,
+            feeling: 0,
+        };
+        assert_eq!(
+            memory_text(&memory, "Zygarde", "PKHeX", types::GameVersion::X),
+            Some("Zygarde remembers being caught by PKHeX at Terminus Cave.".to_string())
+        );
+    }
+
+    #[test]
+    fn should_substitute_species() {
+        let memory = Memory {
+            id: 9,
+            intensity: 3,
+            text_var: 25,
+            feeling: 5,
+        };
+        assert_eq!(
+            memory_text(&memory, "Eevee", "Ash", types::GameVersion::Scarlet),
+            Some("Eevee remembers winning a battle against a Pikachu with Ash.".to_string())
+        );
+    }
+
+    #[test]
+    fn should_substitute_items() {
+        let memory = Memory {
+            id: 10,
+            intensity: 2,
+            text_var: 234,
+            feeling: 0,
+        };
+        assert_eq!(
+            memory_text(&memory, "Snorlax", "Red", types::GameVersion::X),
+            Some("Snorlax remembers being given a Leftovers by Red.".to_string())
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_location_ids() {
+        let memory = Memory {
+            id: 6,
+            intensity: 1,
+            text_var: 9999,
+            feeling: 0,
+        };
+        assert_eq!(
+            memory_text(&memory, "Zubat", "Rei", types::GameVersion::LegendsArceus),
+            Some("Zubat remembers traveling to location 9999 with Rei.".to_string())
+        );
+    }
+
+    #[test]
+    fn should_return_none_for_empty_memories() {
+        let memory = Memory::default();
+        assert_eq!(
+            memory_text(&memory, "Zubat", "Rei", types::GameVersion::LegendsArceus),
+            None
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use crate::impl_read_prop;
-use crate::pkx::Pkx;
+use crate::impl_write_prop;
+use crate::pkx::{Pkx, PkxMut};
 use crate::poke_crypto::PokeCrypto;
 use crate::reader::Reader;
 use crate::strings::string_converter8;
+use crate::types;
+use crate::writer::Writer;
 use alloc::string::String;
 
 pub struct Pa8 {
@@ -23,6 +26,12 @@ impl Reader for Pa8 {
     }
 }
 
+impl Writer for Pa8 {
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
 impl PokeCrypto for Pa8 {
     const PARTY_SIZE: usize = 0x178;
     const STORED_SIZE: usize = 0x168;
@@ -38,6 +47,8 @@ impl PokeCrypto for Pa8 {
 }
 
 impl Pa8 {
+    pub const MOVE_SHOP_COUNT: usize = 64;
+
     pub fn new(mut data: [u8; Self::STORED_SIZE]) -> Self {
         Self::decrypt_raw(&mut data);
         Self { data }
@@ -51,6 +62,62 @@ impl Pa8 {
 
         Self::default()
     }
+
+    impl_read_prop!(minted_nature: u8 = 0x21);
+    impl_read_prop!(pub alpha_move: u16 = 0x3e);
+    impl_read_prop!(pub height_scalar: u8 = 0x50);
+    impl_read_prop!(pub weight_scalar: u8 = 0x51);
+    impl_read_prop!(pub gv_hp: u8 = 0xa4);
+    impl_read_prop!(pub gv_atk: u8 = 0xa5);
+    impl_read_prop!(pub gv_def: u8 = 0xa6);
+    impl_read_prop!(pub gv_spe: u8 = 0xa7);
+    impl_read_prop!(pub gv_spa: u8 = 0xa8);
+    impl_read_prop!(pub gv_spd: u8 = 0xa9);
+    impl_read_prop!(pub height_absolute: f32 = 0xac);
+    impl_read_prop!(pub weight_absolute: f32 = 0xb0);
+    impl_write_prop!(pub set_minted_nature: u8 = 0x21);
+    impl_write_prop!(pub set_height_scalar: u8 = 0x50);
+    impl_write_prop!(pub set_weight_scalar: u8 = 0x51);
+    impl_write_prop!(pub set_gv_hp: u8 = 0xa4);
+    impl_write_prop!(pub set_gv_atk: u8 = 0xa5);
+    impl_write_prop!(pub set_gv_def: u8 = 0xa6);
+    impl_write_prop!(pub set_gv_spe: u8 = 0xa7);
+    impl_write_prop!(pub set_gv_spa: u8 = 0xa8);
+    impl_write_prop!(pub set_gv_spd: u8 = 0xa9);
+    impl_write_prop!(pub set_height_absolute: f32 = 0xac);
+    impl_write_prop!(pub set_weight_absolute: f32 = 0xb0);
+
+    pub fn minted_nature_t(&self) -> types::Nature {
+        self.minted_nature().into()
+    }
+
+    pub fn alpha_move_t(&self) -> types::Move {
+        self.alpha_move().into()
+    }
+
+    pub fn is_alpha(&self) -> bool {
+        (self.read::<u8>(0x16) >> 5) & 1 == 1
+    }
+
+    pub fn is_noble(&self) -> bool {
+        (self.read::<u8>(0x16) >> 6) & 1 == 1
+    }
+
+    /// Whether the move shop move with the given index has been purchased.
+    pub fn has_purchased_move(&self, index: usize) -> bool {
+        index < Self::MOVE_SHOP_COUNT && self.read_flag(0x155, index)
+    }
+
+    /// Whether the move shop move with the given index has been mastered.
+    pub fn has_mastered_move(&self, index: usize) -> bool {
+        index < Self::MOVE_SHOP_COUNT && self.read_flag(0x15d, index)
+    }
+
+    /// Pokemon from Legends: Arceus can only be in a Strange Ball or a Hisuian
+    /// ball, while Pokemon brought in through HOME can't be in either.
+    pub fn has_valid_ball(&self) -> bool {
+        self.ball_t().is_valid_for(self.version_t())
+    }
 }
 
 impl Pkx for Pa8 {
@@ -62,7 +129,6 @@ impl Pkx for Pa8 {
     impl_read_prop!(sid16: u16 = 0x0e);
     impl_read_prop!(exp: u32 = 0x10);
     impl_read_prop!(ability: u16 = 0x14);
-    impl_read_prop!(ability_number: u8 = 0x16);
     impl_read_prop!(pid: u32 = 0x1c);
     impl_read_prop!(nature: u8 = 0x20);
     impl_read_prop!(form: u8 = 0x24);
@@ -72,6 +138,8 @@ impl Pkx for Pa8 {
     impl_read_prop!(ev_spe: u8 = 0x29);
     impl_read_prop!(ev_spa: u8 = 0x2a);
     impl_read_prop!(ev_spd: u8 = 0x2b);
+    impl_read_prop!(ribbon_count_memory_contest: u8 = 0x3c);
+    impl_read_prop!(ribbon_count_memory_battle: u8 = 0x3d);
     impl_read_prop!(move1: u16 = 0x54);
     impl_read_prop!(move2: u16 = 0x56);
     impl_read_prop!(move3: u16 = 0x58);
@@ -84,14 +152,30 @@ impl Pkx for Pa8 {
     impl_read_prop!(move2_pp_ups: u8 = 0x87);
     impl_read_prop!(move3_pp_ups: u8 = 0x88);
     impl_read_prop!(move4_pp_ups: u8 = 0x89);
+    impl_read_prop!(relearn_move1: u16 = 0x8a);
+    impl_read_prop!(relearn_move2: u16 = 0x8c);
+    impl_read_prop!(relearn_move3: u16 = 0x8e);
+    impl_read_prop!(relearn_move4: u16 = 0x90);
     // impl_read_prop!(stat_hp_current: u16 = 0x92);
     impl_read_prop!(iv32: u32 = 0x94);
     impl_read_prop!(status_condition: u32 = 0x9c);
     impl_read_prop!(current_handler: u8 = 0xd4);
+    impl_read_prop!(ht_gender: u8 = 0xd2);
     impl_read_prop!(ht_friendship: u8 = 0xd8);
+    impl_read_prop!(ht_intensity: u8 = 0xd9);
+    impl_read_prop!(ht_memory: u8 = 0xda);
+    impl_read_prop!(ht_feeling: u8 = 0xdb);
+    impl_read_prop!(ht_text_var: u16 = 0xdc);
+    impl_read_prop!(version: u8 = 0xee);
     impl_read_prop!(language: u8 = 0xf2);
-    impl_read_prop!(ot_friendship: u8 = 0x11a);
+    impl_read_prop!(ot_friendship: u8 = 0x12a);
+    impl_read_prop!(ot_intensity: u8 = 0x12b);
+    impl_read_prop!(ot_memory: u8 = 0x12c);
+    impl_read_prop!(ot_text_var: u16 = 0x12e);
+    impl_read_prop!(ot_feeling: u8 = 0x130);
     impl_read_prop!(ball: u8 = 0x137);
+    impl_read_prop!(egg_location: u16 = 0x138);
+    impl_read_prop!(met_location: u16 = 0x13a);
     // impl_read_prop!(stat_level: u8 = 0x168);
     // impl_read_prop!(stat_hp_max: u16 = 0x16a);
     // impl_read_prop!(stat_atk: u16 = 0x16c);
@@ -140,9 +224,117 @@ impl Pkx for Pa8 {
         self.read::<u8>(0x13D) & !0x80
     }
 
+    fn ability_number(&self) -> u8 {
+        self.read::<u8>(0x16) & 7
+    }
+
     fn valid_checksum(&self) -> bool {
         self.checksum() == self.calculate_checksum()
     }
+
+    fn has_ribbon(&self, ribbon: types::Ribbon) -> bool {
+        let index = ribbon as usize;
+        if index < 64 {
+            return self.read_flag(0x34, index);
+        }
+        self.read_flag(0x40, index - 64)
+    }
+}
+
+impl PkxMut for Pa8 {
+    impl_write_prop!(set_encryption_constant: u32 = 0x00);
+    impl_write_prop!(set_pid: u32 = 0x1c);
+    impl_write_prop!(set_species: u16 = 0x08);
+    impl_write_prop!(set_form: u8 = 0x24);
+    impl_write_prop!(set_held_item: u16 = 0x0a);
+    impl_write_prop!(set_tid16: u16 = 0x0c);
+    impl_write_prop!(set_sid16: u16 = 0x0e);
+    impl_write_prop!(set_exp: u32 = 0x10);
+    impl_write_prop!(set_ability: u16 = 0x14);
+    impl_write_prop!(set_nature: u8 = 0x20);
+    impl_write_prop!(set_ev_hp: u8 = 0x26);
+    impl_write_prop!(set_ev_atk: u8 = 0x27);
+    impl_write_prop!(set_ev_def: u8 = 0x28);
+    impl_write_prop!(set_ev_spe: u8 = 0x29);
+    impl_write_prop!(set_ev_spa: u8 = 0x2a);
+    impl_write_prop!(set_ev_spd: u8 = 0x2b);
+    impl_write_prop!(set_move1: u16 = 0x54);
+    impl_write_prop!(set_move2: u16 = 0x56);
+    impl_write_prop!(set_move3: u16 = 0x58);
+    impl_write_prop!(set_move4: u16 = 0x5a);
+    impl_write_prop!(set_move1_pp: u8 = 0x5c);
+    impl_write_prop!(set_move2_pp: u8 = 0x5d);
+    impl_write_prop!(set_move3_pp: u8 = 0x5e);
+    impl_write_prop!(set_move4_pp: u8 = 0x5f);
+    impl_write_prop!(set_move1_pp_ups: u8 = 0x86);
+    impl_write_prop!(set_move2_pp_ups: u8 = 0x87);
+    impl_write_prop!(set_move3_pp_ups: u8 = 0x88);
+    impl_write_prop!(set_move4_pp_ups: u8 = 0x89);
+    impl_write_prop!(set_relearn_move1: u16 = 0x8a);
+    impl_write_prop!(set_relearn_move2: u16 = 0x8c);
+    impl_write_prop!(set_relearn_move3: u16 = 0x8e);
+    impl_write_prop!(set_relearn_move4: u16 = 0x90);
+    impl_write_prop!(set_iv32: u32 = 0x94);
+    impl_write_prop!(set_ball: u8 = 0x137);
+    impl_write_prop!(set_met_location: u16 = 0x13a);
+    impl_write_prop!(set_egg_location: u16 = 0x138);
+    impl_write_prop!(set_version: u8 = 0xee);
+    impl_write_prop!(set_language: u8 = 0xf2);
+    impl_write_prop!(set_current_handler: u8 = 0xd4);
+    impl_write_prop!(set_ht_gender: u8 = 0xd2);
+    impl_write_prop!(set_ot_friendship: u8 = 0x12a);
+    impl_write_prop!(set_ht_friendship: u8 = 0xd8);
+    impl_write_prop!(set_ot_memory: u8 = 0x12c);
+    impl_write_prop!(set_ot_intensity: u8 = 0x12b);
+    impl_write_prop!(set_ot_text_var: u16 = 0x12e);
+    impl_write_prop!(set_ot_feeling: u8 = 0x130);
+    impl_write_prop!(set_ht_memory: u8 = 0xda);
+    impl_write_prop!(set_ht_intensity: u8 = 0xd9);
+    impl_write_prop!(set_ht_text_var: u16 = 0xdc);
+    impl_write_prop!(set_ht_feeling: u8 = 0xdb);
+    impl_write_prop!(set_ribbon_count_memory_contest: u8 = 0x3c);
+    impl_write_prop!(set_ribbon_count_memory_battle: u8 = 0x3d);
+
+    fn set_ability_number(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x16) & !7) | (value & 7);
+        self.write(0x16, byte);
+    }
+
+    fn set_gender(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x22) & !(3 << 2)) | ((value & 3) << 2);
+        self.write(0x22, byte);
+    }
+
+    fn set_nickname(&mut self, value: &str) {
+        string_converter8::set_string(&mut self.data[0x60..][..26], value);
+    }
+
+    fn set_ht_name(&mut self, value: &str) {
+        string_converter8::set_string(&mut self.data[0xb8..][..26], value);
+    }
+
+    fn set_ot_name(&mut self, value: &str) {
+        string_converter8::set_string(&mut self.data[0x110..][..26], value);
+    }
+
+    fn set_ot_gender(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x13d) & 0x7f) | (value << 7);
+        self.write(0x13d, byte);
+    }
+
+    fn set_met_level(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x13d) & 0x80) | (value & 0x7f);
+        self.write(0x13d, byte);
+    }
+
+    fn set_ribbon(&mut self, ribbon: types::Ribbon, value: bool) {
+        let index = ribbon as usize;
+        if index < 64 {
+            self.write_flag(0x34, index, value);
+        } else {
+            self.write_flag(0x40, index - 64, value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -151,6 +343,7 @@ mod test {
     use super::*;
     use crate::impl_test;
     use crate::types;
+    use alloc::vec::Vec;
 
     const TEST_EKX: [u8; Pkm::STORED_SIZE] = [
         0x8d, 0x74, 0xa8, 0x50, 0x00, 0x00, 0x4a, 0x04, 0x63, 0x9d, 0xed, 0xef, 0x45, 0xff, 0x8d,
@@ -211,25 +404,25 @@ mod test {
 
         #[test]
         fn encrypted() {
-            assert_eq!(Pkm::is_encrypted(&TEST_EKX), true)
+            assert!(Pkm::is_encrypted(&TEST_EKX))
         }
 
         #[test]
         fn decrypted() {
-            assert_eq!(Pkm::is_encrypted(&TEST_PKX), false)
+            assert!(!Pkm::is_encrypted(&TEST_PKX))
         }
     }
 
     #[test]
     fn should_decrypt() {
-        let mut ekx = TEST_EKX.clone();
+        let mut ekx = TEST_EKX;
         Pkm::decrypt_raw(&mut ekx);
         assert_eq!(ekx, TEST_PKX);
     }
 
     #[test]
     fn should_encrypt() {
-        let mut pkx = TEST_PKX.clone();
+        let mut pkx = TEST_PKX;
         Pkm::encrypt_raw(&mut pkx);
         assert_eq!(pkx, TEST_EKX);
     }
@@ -265,6 +458,8 @@ mod test {
     impl_test!(move2_pp_ups, 0);
     impl_test!(move3_pp_ups, 0);
     impl_test!(move4_pp_ups, 0);
+    impl_test!(relearn_move1_t, types::Move::None);
+    impl_test!(iter_relearn_moves, [types::Move::None; 4]);
     impl_test!(iv_hp, 7);
     impl_test!(iv_atk, 25);
     impl_test!(iv_def, 26);
@@ -277,11 +472,11 @@ mod test {
     impl_test!(ev_spa, 0);
     impl_test!(ev_spd, 0);
     impl_test!(ev_spe, 0);
-    impl_test!(ot_friendship, 0);
+    impl_test!(ot_friendship, 50);
     impl_test!(ht_friendship, 0);
     impl_test!(is_egg, false);
     impl_test!(current_handler, 0);
-    impl_test!(current_friendship, 0);
+    impl_test!(current_friendship, 50);
     impl_test!(sanity, 0);
     impl_test!(checksum, 0x44a);
     impl_test!(calculate_checksum, 0x44a);
@@ -292,6 +487,28 @@ mod test {
     impl_test!(ot_name, "PKHeX");
     impl_test!(ht_name, "");
     impl_test!(status_condition, 0);
+    impl_test!(ot_memory, 0);
+    impl_test!(ball_t, types::Ball::HisuiPoke);
+    impl_test!(has_valid_ball, true);
+    impl_test!(is_alpha, false);
+    impl_test!(is_noble, false);
+    impl_test!(alpha_move_t, types::Move::None);
+    impl_test!(height_scalar, 102);
+    impl_test!(weight_scalar, 137);
+    impl_test!(height_absolute, 76.8);
+    impl_test!(weight_absolute, 73.072945);
+    impl_test!(gv_hp, 0);
+    impl_test!(gv_atk, 0);
+    impl_test!(gv_def, 0);
+    impl_test!(gv_spa, 0);
+    impl_test!(gv_spd, 0);
+    impl_test!(gv_spe, 0);
+    impl_test!(ht_memory, 0);
+    impl_test!(ribbon_count_memory_contest, 0);
+    impl_test!(ribbon_count_memory_battle, 0);
+    impl_test!(version_t, types::GameVersion::LegendsArceus);
+    impl_test!(met_location, 25);
+    impl_test!(egg_location, 0);
     // impl_test!(stat_level, 0);
     // impl_test!(stat_hp_max, 0);
     // impl_test!(stat_atk, 0);
@@ -301,10 +518,94 @@ mod test {
     // impl_test!(stat_spd, 0);
     // impl_test!(stat_hp_current, 56);
 
+    #[test]
+    fn should_read_ribbons() {
+        let mut pkx = TEST_PKX;
+        pkx[0x3c] = 2;
+        pkx[0x44] = 0b0000_0100;
+        let pkx = Pkm::new(pkx);
+        let ribbons: Vec<types::Ribbon> = pkx.ribbons().collect();
+        assert_eq!(ribbons, [types::Ribbon::Hisui]);
+    }
+
+    #[test]
+    fn should_read_alpha_fields() {
+        let mut pkx = TEST_PKX;
+        pkx[0x16] |= 0b0110_0000;
+        pkx[0x3e] = 0x10;
+        pkx[0xa4] = 3;
+        let pkx = Pkm::new(pkx);
+        assert!(pkx.is_alpha());
+        assert!(pkx.is_noble());
+        assert_eq!(pkx.alpha_move_t(), types::Move::Gust);
+        assert_eq!(pkx.gv_hp(), 3);
+        assert_eq!(pkx.ability_number_t(), types::AbilityNumber::First);
+    }
+
+    #[test]
+    fn should_read_move_shop_flags() {
+        let mut pkx = TEST_PKX;
+        pkx[0x155] = 0b0000_0011;
+        pkx[0x15d] = 0b0000_0010;
+        let pkx = Pkm::new(pkx);
+        assert!(pkx.has_purchased_move(0));
+        assert!(pkx.has_purchased_move(1));
+        assert!(!pkx.has_mastered_move(0));
+        assert!(pkx.has_mastered_move(1));
+        assert!(!pkx.has_purchased_move(Pkm::MOVE_SHOP_COUNT));
+    }
+
+    #[test]
+    fn should_reject_foreign_balls_for_hisui_pokemon() {
+        let mut pkx = TEST_PKX;
+        pkx[0x137] = types::Ball::Poke as u8;
+        let pkx = Pkm::new(pkx);
+        assert!(!pkx.has_valid_ball());
+    }
+
+    #[test]
+    fn should_reject_strange_balls_from_other_games() {
+        let mut pkx = TEST_PKX;
+        pkx[0x137] = types::Ball::Strange as u8;
+        let mut pkx = Pkm::new(pkx);
+        assert!(pkx.has_valid_ball());
+
+        pkx.set_version(types::GameVersion::Sword as u8);
+        assert!(!pkx.has_valid_ball());
+    }
+
+    #[test]
+    fn should_write_fields() {
+        let mut pkx = Pkm::new(TEST_EKX);
+        pkx.set_species(types::Species::Pikachu as u16);
+        pkx.set_nickname("Sparky♂");
+        pkx.set_is_nicknamed(true);
+        pkx.set_met_level(42);
+        pkx.set_ot_gender(1);
+        pkx.set_gender(1);
+        pkx.set_iv_spd(30);
+        pkx.set_ability_number(4);
+        pkx.set_form(1);
+        pkx.set_ribbon(types::Ribbon::ChampionKalos, true);
+        pkx.refresh_checksum();
+
+        assert!(pkx.is_valid());
+        assert_eq!(pkx.species_t(), types::Species::Pikachu);
+        assert_eq!(pkx.nickname(), "Sparky♂");
+        assert!(pkx.is_nicknamed());
+        assert_eq!(pkx.met_level(), 42);
+        assert_eq!(pkx.ot_gender_t(), types::Gender::Female);
+        assert_eq!(pkx.gender_t(), types::Gender::Female);
+        assert_eq!(pkx.iv_spd(), 30);
+        assert_eq!(pkx.ability_number_t(), types::AbilityNumber::Hidden);
+        assert_eq!(pkx.form(), 1);
+        assert!(pkx.has_ribbon(types::Ribbon::ChampionKalos));
+    }
+
     #[test]
     fn should_return_not_shiny_for_default() {
         let pkx = Pkm::default();
-        assert_eq!(pkx.is_shiny(), false)
+        assert!(!pkx.is_shiny())
     }
 
     #[test]
@@ -338,7 +639,7 @@ mod test {
             0xf8, 0xe8, 0xa0, 0xa5, 0x36, 0x82, 0x37, 0x55, 0xfa, 0x98,
         ];
         let pkx = Pkm::new(shiny_ekx);
-        assert_eq!(pkx.is_shiny(), true);
+        assert!(pkx.is_shiny());
         assert_eq!(pkx.shiny_type(), Some(types::Shiny::Square));
     }
 }
@@ -0,0 +1,216 @@
+use crate::pkx::PkxMut;
+use crate::types::PartySlot;
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+
+const PARTY_SLOTS: [PartySlot; 6] = [
+    PartySlot::Slot1,
+    PartySlot::Slot2,
+    PartySlot::Slot3,
+    PartySlot::Slot4,
+    PartySlot::Slot5,
+    PartySlot::Slot6,
+];
+
+/// The six party slots, as laid out in RAM and in saves.
+///
+/// Each member keeps its party stats so they survive reordering.
+pub struct Party<T> {
+    members: [T; 6],
+    stats: [Vec<u8>; 6],
+}
+
+impl<T: PkxMut + Default> Party<T> {
+    pub const SIZE: usize = T::PARTY_SIZE * 6;
+
+    /// Reads six consecutive party sized entries, decrypting them if needed.
+    pub fn new(data: &[u8]) -> Option<Self> {
+        let data = data.get(..Self::SIZE)?;
+        let mut entries = data.chunks_exact(T::PARTY_SIZE).map(|entry| {
+            let mut entry = entry.to_vec();
+            T::decrypt_raw(&mut entry);
+            entry
+        });
+
+        let mut members: [T; 6] = Default::default();
+        let mut stats: [Vec<u8>; 6] = Default::default();
+        for (member, member_stats) in members.iter_mut().zip(stats.iter_mut()) {
+            let entry = entries.next()?;
+            *member = T::from_bytes(&entry)?;
+            *member_stats = entry[T::STORED_SIZE..].to_vec();
+        }
+
+        Some(Self { members, stats })
+    }
+
+    /// The number of slots holding a Pokemon.
+    pub fn count(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|member| member.species() != 0)
+            .count()
+    }
+
+    /// Iterates the slots holding a Pokemon.
+    pub fn iter(&self) -> impl Iterator<Item = (PartySlot, &T)> {
+        PARTY_SLOTS
+            .into_iter()
+            .zip(self.members.iter())
+            .filter(|(_, member)| member.species() != 0)
+    }
+
+    pub fn swap(&mut self, a: PartySlot, b: PartySlot) {
+        self.members.swap(a as usize, b as usize);
+        self.stats.swap(a as usize, b as usize);
+    }
+
+    /// Moves a member to another slot, shifting the members in between.
+    pub fn move_slot(&mut self, from: PartySlot, to: PartySlot) {
+        let (from, to) = (from as usize, to as usize);
+        if from < to {
+            self.members[from..=to].rotate_left(1);
+            self.stats[from..=to].rotate_left(1);
+        } else {
+            self.members[to..=from].rotate_right(1);
+            self.stats[to..=from].rotate_right(1);
+        }
+    }
+
+    /// Moves every member to the front, keeping their order, the way the
+    /// games keep the party.
+    pub fn compact(&mut self) {
+        let mut next = 0;
+        for index in 0..self.members.len() {
+            if self.members[index].species() != 0 {
+                self.members.swap(next, index);
+                self.stats.swap(next, index);
+                next += 1;
+            }
+        }
+    }
+
+    /// The party encrypted back into six party sized entries.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::SIZE);
+
+        for (member, stats) in self.members.iter().zip(self.stats.iter()) {
+            let mut entry = member.as_slice()[..T::STORED_SIZE].to_vec();
+            entry.extend_from_slice(stats);
+            T::encrypt_raw(&mut entry);
+            data.extend_from_slice(&entry);
+        }
+
+        data
+    }
+}
+
+impl<T> Index<PartySlot> for Party<T> {
+    type Output = T;
+
+    fn index(&self, slot: PartySlot) -> &T {
+        &self.members[slot as usize]
+    }
+}
+
+impl<T> IndexMut<PartySlot> for Party<T> {
+    fn index_mut(&mut self, slot: PartySlot) -> &mut T {
+        &mut self.members[slot as usize]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pk7::Pk7;
+    use crate::pkx::Pkx;
+    use crate::poke_crypto::PokeCrypto;
+    use crate::reader::Reader;
+    use crate::types::Species;
+
+    fn entry(species: Species, level: u8) -> Vec<u8> {
+        let mut pkm = Pk7::default();
+        pkm.set_encryption_constant(species as u32 * 0x1001);
+        pkm.set_species(species as u16);
+        pkm.refresh_checksum();
+
+        let mut entry = pkm.as_slice().to_vec();
+        entry.resize(Pk7::PARTY_SIZE, 0);
+        entry[0xec] = level;
+        Pk7::encrypt_raw(&mut entry);
+        entry
+    }
+
+    fn party() -> Party<Pk7> {
+        let mut data = Vec::new();
+        data.extend(entry(Species::Pikachu, 5));
+        data.extend(entry(Species::Eevee, 10));
+        data.extend(entry(Species::Mew, 15));
+        data.resize(Party::<Pk7>::SIZE, 0);
+        Party::new(&data).unwrap()
+    }
+
+    fn species(party: &Party<Pk7>) -> Vec<Species> {
+        party.iter().map(|(_, pkm)| pkm.species_t()).collect()
+    }
+
+    #[test]
+    fn should_parse_encrypted_entries() {
+        let party = party();
+        assert_eq!(party.count(), 3);
+        assert_eq!(party[PartySlot::Slot2].species_t(), Species::Eevee);
+        assert!(party[PartySlot::Slot3].is_valid());
+        assert!(Party::<Pk7>::new(&[0; 0x10]).is_none());
+    }
+
+    #[test]
+    fn should_swap_slots() {
+        let mut party = party();
+        party.swap(PartySlot::Slot1, PartySlot::Slot3);
+        assert_eq!(
+            species(&party),
+            [Species::Mew, Species::Eevee, Species::Pikachu]
+        );
+    }
+
+    #[test]
+    fn should_move_slots() {
+        let mut party = party();
+        party.move_slot(PartySlot::Slot1, PartySlot::Slot3);
+        assert_eq!(
+            species(&party),
+            [Species::Eevee, Species::Mew, Species::Pikachu]
+        );
+
+        party.move_slot(PartySlot::Slot3, PartySlot::Slot1);
+        assert_eq!(
+            species(&party),
+            [Species::Pikachu, Species::Eevee, Species::Mew]
+        );
+    }
+
+    #[test]
+    fn should_compact_slots() {
+        let mut party = party();
+        party.swap(PartySlot::Slot2, PartySlot::Slot6);
+        party.compact();
+        assert_eq!(party.iter().last().unwrap().0, PartySlot::Slot3);
+    }
+
+    #[test]
+    fn should_re_encrypt_entries_with_their_stats() {
+        let mut party = party();
+        party.swap(PartySlot::Slot1, PartySlot::Slot2);
+
+        let data = party.to_bytes();
+        assert_eq!(data.len(), Party::<Pk7>::SIZE);
+        assert_eq!(data[..Pk7::PARTY_SIZE], entry(Species::Eevee, 10));
+        assert_eq!(
+            data[Pk7::PARTY_SIZE..][..Pk7::PARTY_SIZE],
+            entry(Species::Pikachu, 5)
+        );
+
+        let mut stats = data[..Pk7::PARTY_SIZE].to_vec();
+        Pk7::decrypt_raw(&mut stats);
+        assert_eq!(stats[0xec], 10);
+    }
+}
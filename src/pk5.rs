@@ -0,0 +1,238 @@
+use crate::impl_read_prop;
+use crate::poke_crypto::{decrypt_gen5, encrypt_gen5, PokeCrypto};
+use crate::reader::Reader;
+use crate::strings::string_converter5;
+use crate::types;
+use crate::writer::Writer;
+use alloc::string::String;
+
+/// A Gen5 Pokemon, kept so Pokemon can be moved forward with Poke Transfer.
+///
+/// Gen5 predates the fields [Pkx](crate::Pkx) covers, like the encryption
+/// constant and memories, so only its own getters are available.
+pub struct Pk5 {
+    data: [u8; Self::STORED_SIZE],
+}
+
+impl Default for Pk5 {
+    fn default() -> Self {
+        Self {
+            data: [0; Self::STORED_SIZE],
+        }
+    }
+}
+
+impl Reader for Pk5 {
+    fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Writer for Pk5 {
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl PokeCrypto for Pk5 {
+    const PARTY_SIZE: usize = 0xdc;
+    const STORED_SIZE: usize = 0x88;
+    const BLOCK_SIZE: usize = 0x20;
+
+    /// The four bytes after the last Sinnoh ribbons are unused and always
+    /// zero once decrypted.
+    fn is_encrypted(data: &[u8]) -> bool {
+        data.len() >= Self::STORED_SIZE && data.read::<u32>(0x64) != 0
+    }
+
+    fn checksum(&self) -> u16 {
+        self.read(0x06)
+    }
+
+    fn encrypt_raw(data: &mut [u8]) {
+        if data.len() >= Self::STORED_SIZE && !Self::is_encrypted(data) {
+            encrypt_gen5(data, Self::BLOCK_SIZE, Self::STORED_SIZE)
+        }
+    }
+
+    fn decrypt_raw(data: &mut [u8]) {
+        if Self::is_encrypted(data) {
+            decrypt_gen5(data, Self::BLOCK_SIZE, Self::STORED_SIZE)
+        }
+    }
+}
+
+impl Pk5 {
+    pub fn new(mut data: [u8; Self::STORED_SIZE]) -> Self {
+        Self::decrypt_raw(&mut data);
+        Self { data }
+    }
+
+    impl_read_prop!(pub pid: u32 = 0x00);
+    impl_read_prop!(pub sanity: u16 = 0x04);
+    impl_read_prop!(pub species: u16 = 0x08);
+    impl_read_prop!(pub held_item: u16 = 0x0a);
+    impl_read_prop!(pub tid16: u16 = 0x0c);
+    impl_read_prop!(pub sid16: u16 = 0x0e);
+    impl_read_prop!(pub exp: u32 = 0x10);
+    impl_read_prop!(pub ot_friendship: u8 = 0x14);
+    impl_read_prop!(pub ability: u8 = 0x15);
+    impl_read_prop!(pub markings: u8 = 0x16);
+    impl_read_prop!(pub language: u8 = 0x17);
+    impl_read_prop!(pub ev_hp: u8 = 0x18);
+    impl_read_prop!(pub ev_atk: u8 = 0x19);
+    impl_read_prop!(pub ev_def: u8 = 0x1a);
+    impl_read_prop!(pub ev_spe: u8 = 0x1b);
+    impl_read_prop!(pub ev_spa: u8 = 0x1c);
+    impl_read_prop!(pub ev_spd: u8 = 0x1d);
+    impl_read_prop!(pub contest_cool: u8 = 0x1e);
+    impl_read_prop!(pub contest_beauty: u8 = 0x1f);
+    impl_read_prop!(pub contest_cute: u8 = 0x20);
+    impl_read_prop!(pub contest_smart: u8 = 0x21);
+    impl_read_prop!(pub contest_tough: u8 = 0x22);
+    impl_read_prop!(pub contest_sheen: u8 = 0x23);
+    impl_read_prop!(pub move1: u16 = 0x28);
+    impl_read_prop!(pub move2: u16 = 0x2a);
+    impl_read_prop!(pub move3: u16 = 0x2c);
+    impl_read_prop!(pub move4: u16 = 0x2e);
+    impl_read_prop!(pub move1_pp: u8 = 0x30);
+    impl_read_prop!(pub move2_pp: u8 = 0x31);
+    impl_read_prop!(pub move3_pp: u8 = 0x32);
+    impl_read_prop!(pub move4_pp: u8 = 0x33);
+    impl_read_prop!(pub move1_pp_ups: u8 = 0x34);
+    impl_read_prop!(pub move2_pp_ups: u8 = 0x35);
+    impl_read_prop!(pub move3_pp_ups: u8 = 0x36);
+    impl_read_prop!(pub move4_pp_ups: u8 = 0x37);
+    impl_read_prop!(pub iv32: u32 = 0x38);
+    impl_read_prop!(pub nature: u8 = 0x41);
+    impl_read_prop!(pub version: u8 = 0x5f);
+    impl_read_prop!(pub egg_location: u16 = 0x7e);
+    impl_read_prop!(pub met_location: u16 = 0x80);
+    impl_read_prop!(pub pokerus: u8 = 0x82);
+    impl_read_prop!(pub ball: u8 = 0x83);
+
+    pub fn species_t(&self) -> types::Species {
+        self.species().into()
+    }
+
+    pub fn is_egg(&self) -> bool {
+        (self.iv32() >> 30) & 1 == 1
+    }
+
+    pub fn is_nicknamed(&self) -> bool {
+        (self.iv32() >> 31) & 1 == 1
+    }
+
+    pub fn is_fateful_encounter(&self) -> bool {
+        self.read::<u8>(0x40) & 1 == 1
+    }
+
+    pub fn gender(&self) -> u8 {
+        (self.read::<u8>(0x40) >> 1) & 3
+    }
+
+    pub fn form(&self) -> u8 {
+        self.read::<u8>(0x40) >> 3
+    }
+
+    pub fn has_hidden_ability(&self) -> bool {
+        self.read::<u8>(0x42) & 1 == 1
+    }
+
+    pub fn nickname(&self) -> String {
+        string_converter5::get_string(&self.data[0x48..][..22])
+    }
+
+    pub fn ot_name(&self) -> String {
+        string_converter5::get_string(&self.data[0x68..][..16])
+    }
+
+    pub fn set_nickname(&mut self, value: &str) {
+        string_converter5::set_string(&mut self.data[0x48..][..22], value);
+    }
+
+    pub fn set_ot_name(&mut self, value: &str) {
+        string_converter5::set_string(&mut self.data[0x68..][..16], value);
+    }
+
+    pub fn ot_gender(&self) -> u8 {
+        self.read::<u8>(0x84) >> 7
+    }
+
+    pub fn met_level(&self) -> u8 {
+        self.read::<u8>(0x84) & 0x7f
+    }
+
+    pub fn valid_checksum(&self) -> bool {
+        self.checksum() == self.calculate_checksum()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.sanity() == 0 && self.valid_checksum() && self.species() != 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pk5() -> Pk5 {
+        let mut pkm = Pk5::default();
+        pkm.write(0x00, 0x8765_4321u32);
+        pkm.write(0x08, types::Species::Snivy as u16);
+        pkm.write(0x28, 33u16);
+        pkm.write(0x40, (1u8 << 3) | (1 << 1) | 1);
+        pkm.set_nickname("SNIVY");
+        let checksum = pkm.calculate_checksum();
+        pkm.write(0x06, checksum);
+        pkm
+    }
+
+    #[test]
+    fn should_read_fields() {
+        let pkm = pk5();
+        assert!(pkm.is_valid());
+        assert_eq!(pkm.species_t(), types::Species::Snivy);
+        assert_eq!(pkm.nickname(), "SNIVY");
+        assert_eq!(pkm.form(), 1);
+        assert_eq!(pkm.gender(), 1);
+        assert!(pkm.is_fateful_encounter());
+    }
+
+    #[test]
+    fn should_round_trip_encryption() {
+        let pkm = pk5();
+        let mut data = pkm.data;
+        Pk5::encrypt_raw(&mut data);
+        assert!(Pk5::is_encrypted(&data));
+        assert_ne!(data, pkm.data);
+
+        let decrypted = Pk5::new(data);
+        assert_eq!(decrypted.data, pkm.data);
+    }
+
+    #[test]
+    fn should_not_decrypt_data_with_bad_checksums() {
+        let mut pkm = pk5();
+        pkm.write(0x08, types::Species::Tepig as u16);
+        assert!(!Pk5::is_encrypted(&pkm.data));
+
+        let pkm = Pk5::new(pkm.data);
+        assert_eq!(pkm.species_t(), types::Species::Tepig);
+        assert!(!pkm.is_valid());
+    }
+
+    #[test]
+    fn should_encrypt_party_stats_with_the_pid() {
+        let pkm = pk5();
+        let mut data = [0; Pk5::PARTY_SIZE];
+        data[..Pk5::STORED_SIZE].copy_from_slice(&pkm.data);
+        data[0x8c] = 5;
+        Pk5::encrypt_raw(&mut data);
+        assert_ne!(data[0x8c], 5);
+
+        Pk5::decrypt_raw(&mut data);
+        assert_eq!(data[0x8c], 5);
+        assert_eq!(data[..Pk5::STORED_SIZE], pkm.data);
+    }
+}
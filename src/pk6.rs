@@ -1,8 +1,11 @@
 use crate::impl_read_prop;
-use crate::pkx::Pkx;
+use crate::impl_write_prop;
+use crate::pkx::{Pkx, PkxMut};
 use crate::poke_crypto::PokeCrypto;
 use crate::reader::Reader;
 use crate::strings::string_converter6;
+use crate::types;
+use crate::writer::Writer;
 use alloc::string::String;
 
 pub struct Pk6 {
@@ -23,6 +26,12 @@ impl Reader for Pk6 {
     }
 }
 
+impl Writer for Pk6 {
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
 impl PokeCrypto for Pk6 {
     const PARTY_SIZE: usize = 0x104;
     const STORED_SIZE: usize = 0xE8;
@@ -38,6 +47,8 @@ impl PokeCrypto for Pk6 {
 }
 
 impl Pk6 {
+    pub const SUPER_TRAINING_MEDAL_COUNT: usize = 30;
+
     pub fn new(mut data: [u8; Self::STORED_SIZE]) -> Self {
         Self::decrypt_raw(&mut data);
         Self { data }
@@ -51,6 +62,89 @@ impl Pk6 {
 
         Self::default()
     }
+
+    impl_read_prop!(pub training_bag_hits: u8 = 0x16);
+    impl_read_prop!(pub training_bag: u8 = 0x17);
+    impl_read_prop!(pub contest_cool: u8 = 0x24);
+    impl_read_prop!(pub contest_beauty: u8 = 0x25);
+    impl_read_prop!(pub contest_cute: u8 = 0x26);
+    impl_read_prop!(pub contest_smart: u8 = 0x27);
+    impl_read_prop!(pub contest_tough: u8 = 0x28);
+    impl_read_prop!(pub contest_sheen: u8 = 0x29);
+    impl_write_prop!(pub set_contest_cool: u8 = 0x24);
+    impl_write_prop!(pub set_contest_beauty: u8 = 0x25);
+    impl_write_prop!(pub set_contest_cute: u8 = 0x26);
+    impl_write_prop!(pub set_contest_smart: u8 = 0x27);
+    impl_write_prop!(pub set_contest_tough: u8 = 0x28);
+    impl_write_prop!(pub set_contest_sheen: u8 = 0x29);
+    impl_read_prop!(pub markings: u8 = 0x2a);
+    impl_read_prop!(pokerus: u8 = 0x2b);
+    impl_write_prop!(pub set_markings: u8 = 0x2a);
+    impl_write_prop!(pub set_pokerus: u8 = 0x2b);
+    impl_read_prop!(pub super_training_flags: u32 = 0x2c);
+    impl_read_prop!(pub ht_affection: u8 = 0xa3);
+    impl_read_prop!(pub fullness: u8 = 0xae);
+    impl_read_prop!(pub enjoyment: u8 = 0xaf);
+    impl_read_prop!(pub ot_affection: u8 = 0xcb);
+    impl_read_prop!(pub country: u8 = 0xe0);
+    impl_read_prop!(pub region: u8 = 0xe1);
+    impl_read_prop!(pub console_region: u8 = 0xe2);
+
+    pub fn pokerus_strain(&self) -> u8 {
+        self.pokerus() >> 4
+    }
+
+    pub fn pokerus_days(&self) -> u8 {
+        self.pokerus() & 0xf
+    }
+
+    pub fn is_pokerus_infected(&self) -> bool {
+        self.pokerus_strain() != 0
+    }
+
+    pub fn is_pokerus_cured(&self) -> bool {
+        self.is_pokerus_infected() && self.pokerus_days() == 0
+    }
+
+    /// Whether the super training medal with the given index (0-29) has been earned.
+    pub fn has_super_training_medal(&self, index: usize) -> bool {
+        index < Self::SUPER_TRAINING_MEDAL_COUNT
+            && (self.super_training_flags() >> (index + 2)) & 1 == 1
+    }
+
+    pub fn super_training_medal_count(&self) -> usize {
+        (0..Self::SUPER_TRAINING_MEDAL_COUNT)
+            .filter(|index| self.has_super_training_medal(*index))
+            .count()
+    }
+
+    pub fn is_fateful_encounter(&self) -> bool {
+        self.read::<u8>(0x1d) & 1 == 1
+    }
+
+    pub fn set_fateful_encounter(&mut self, value: bool) {
+        self.write_flag(0x1d, 0, value);
+    }
+
+    pub fn is_secret_super_training_unlocked(&self) -> bool {
+        self.read::<u8>(0x72) & 1 == 1
+    }
+
+    pub fn is_secret_super_training_complete(&self) -> bool {
+        (self.read::<u8>(0x72) >> 1) & 1 == 1
+    }
+
+    pub fn console_region_t(&self) -> types::ConsoleRegion {
+        self.console_region().into()
+    }
+
+    /// The five most recent places the Pokemon has been traded to, newest first.
+    pub fn geolocations(&self) -> [types::Geolocation; 5] {
+        core::array::from_fn(|index| types::Geolocation {
+            region: self.read::<u8>(0x94 + index as u64 * 2),
+            country: self.read::<u8>(0x95 + index as u64 * 2),
+        })
+    }
 }
 
 impl Pkx for Pk6 {
@@ -70,6 +164,8 @@ impl Pkx for Pk6 {
     impl_read_prop!(ev_spe: u8 = 0x21);
     impl_read_prop!(ev_spa: u8 = 0x22);
     impl_read_prop!(ev_spd: u8 = 0x23);
+    impl_read_prop!(ribbon_count_memory_contest: u8 = 0x38);
+    impl_read_prop!(ribbon_count_memory_battle: u8 = 0x39);
     impl_read_prop!(move1: u16 = 0x5a);
     impl_read_prop!(move2: u16 = 0x5c);
     impl_read_prop!(move3: u16 = 0x5e);
@@ -82,11 +178,27 @@ impl Pkx for Pk6 {
     impl_read_prop!(move2_pp_ups: u8 = 0x67);
     impl_read_prop!(move3_pp_ups: u8 = 0x68);
     impl_read_prop!(move4_pp_ups: u8 = 0x69);
+    impl_read_prop!(relearn_move1: u16 = 0x6a);
+    impl_read_prop!(relearn_move2: u16 = 0x6c);
+    impl_read_prop!(relearn_move3: u16 = 0x6e);
+    impl_read_prop!(relearn_move4: u16 = 0x70);
     impl_read_prop!(iv32: u32 = 0x74);
     impl_read_prop!(current_handler: u8 = 0x93);
+    impl_read_prop!(ht_gender: u8 = 0x92);
     impl_read_prop!(ht_friendship: u8 = 0xa2);
+    impl_read_prop!(ht_intensity: u8 = 0xa4);
+    impl_read_prop!(ht_memory: u8 = 0xa5);
+    impl_read_prop!(ht_feeling: u8 = 0xa6);
+    impl_read_prop!(ht_text_var: u16 = 0xa8);
     impl_read_prop!(ot_friendship: u8 = 0xca);
+    impl_read_prop!(ot_intensity: u8 = 0xcc);
+    impl_read_prop!(ot_memory: u8 = 0xcd);
+    impl_read_prop!(ot_text_var: u16 = 0xce);
+    impl_read_prop!(ot_feeling: u8 = 0xd0);
+    impl_read_prop!(egg_location: u16 = 0xd8);
+    impl_read_prop!(met_location: u16 = 0xda);
     impl_read_prop!(ball: u8 = 0xdc);
+    impl_read_prop!(version: u8 = 0xdf);
     impl_read_prop!(language: u8 = 0xe3);
     impl_read_prop!(status_condition: u32 = 0xe8);
     // impl_read_prop!(stat_level: u8 = 0xec);
@@ -149,6 +261,111 @@ impl Pkx for Pk6 {
     fn valid_checksum(&self) -> bool {
         self.checksum() == self.calculate_checksum()
     }
+
+    fn has_ribbon(&self, ribbon: types::Ribbon) -> bool {
+        let index = ribbon as usize;
+        index <= types::Ribbon::MasterToughness as usize && self.read_flag(0x30, index)
+    }
+}
+
+impl PkxMut for Pk6 {
+    impl_write_prop!(set_encryption_constant: u32 = 0x00);
+    impl_write_prop!(set_pid: u32 = 0x18);
+    impl_write_prop!(set_species: u16 = 0x08);
+    impl_write_prop!(set_held_item: u16 = 0x0a);
+    impl_write_prop!(set_tid16: u16 = 0x0c);
+    impl_write_prop!(set_sid16: u16 = 0x0e);
+    impl_write_prop!(set_exp: u32 = 0x10);
+    impl_write_prop!(set_nature: u8 = 0x1c);
+    impl_write_prop!(set_ev_hp: u8 = 0x1e);
+    impl_write_prop!(set_ev_atk: u8 = 0x1f);
+    impl_write_prop!(set_ev_def: u8 = 0x20);
+    impl_write_prop!(set_ev_spe: u8 = 0x21);
+    impl_write_prop!(set_ev_spa: u8 = 0x22);
+    impl_write_prop!(set_ev_spd: u8 = 0x23);
+    impl_write_prop!(set_move1: u16 = 0x5a);
+    impl_write_prop!(set_move2: u16 = 0x5c);
+    impl_write_prop!(set_move3: u16 = 0x5e);
+    impl_write_prop!(set_move4: u16 = 0x60);
+    impl_write_prop!(set_move1_pp: u8 = 0x62);
+    impl_write_prop!(set_move2_pp: u8 = 0x63);
+    impl_write_prop!(set_move3_pp: u8 = 0x64);
+    impl_write_prop!(set_move4_pp: u8 = 0x65);
+    impl_write_prop!(set_move1_pp_ups: u8 = 0x66);
+    impl_write_prop!(set_move2_pp_ups: u8 = 0x67);
+    impl_write_prop!(set_move3_pp_ups: u8 = 0x68);
+    impl_write_prop!(set_move4_pp_ups: u8 = 0x69);
+    impl_write_prop!(set_relearn_move1: u16 = 0x6a);
+    impl_write_prop!(set_relearn_move2: u16 = 0x6c);
+    impl_write_prop!(set_relearn_move3: u16 = 0x6e);
+    impl_write_prop!(set_relearn_move4: u16 = 0x70);
+    impl_write_prop!(set_iv32: u32 = 0x74);
+    impl_write_prop!(set_ball: u8 = 0xdc);
+    impl_write_prop!(set_met_location: u16 = 0xda);
+    impl_write_prop!(set_egg_location: u16 = 0xd8);
+    impl_write_prop!(set_version: u8 = 0xdf);
+    impl_write_prop!(set_language: u8 = 0xe3);
+    impl_write_prop!(set_current_handler: u8 = 0x93);
+    impl_write_prop!(set_ht_gender: u8 = 0x92);
+    impl_write_prop!(set_ot_friendship: u8 = 0xca);
+    impl_write_prop!(set_ht_friendship: u8 = 0xa2);
+    impl_write_prop!(set_ot_memory: u8 = 0xcd);
+    impl_write_prop!(set_ot_intensity: u8 = 0xcc);
+    impl_write_prop!(set_ot_text_var: u16 = 0xce);
+    impl_write_prop!(set_ot_feeling: u8 = 0xd0);
+    impl_write_prop!(set_ht_memory: u8 = 0xa5);
+    impl_write_prop!(set_ht_intensity: u8 = 0xa4);
+    impl_write_prop!(set_ht_text_var: u16 = 0xa8);
+    impl_write_prop!(set_ht_feeling: u8 = 0xa6);
+    impl_write_prop!(set_ribbon_count_memory_contest: u8 = 0x38);
+    impl_write_prop!(set_ribbon_count_memory_battle: u8 = 0x39);
+
+    fn set_form(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x1d) & 0x07) | (value << 3);
+        self.write(0x1d, byte);
+    }
+
+    fn set_ability(&mut self, value: u16) {
+        self.write(0x14, value as u8);
+    }
+
+    fn set_ability_number(&mut self, value: u8) {
+        self.write(0x15, value);
+    }
+
+    fn set_gender(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x1d) & !(3 << 1)) | ((value & 3) << 1);
+        self.write(0x1d, byte);
+    }
+
+    fn set_nickname(&mut self, value: &str) {
+        string_converter6::set_string(&mut self.data[0x40..][..26], value);
+    }
+
+    fn set_ht_name(&mut self, value: &str) {
+        string_converter6::set_string(&mut self.data[0x78..][..26], value);
+    }
+
+    fn set_ot_name(&mut self, value: &str) {
+        string_converter6::set_string(&mut self.data[0xb0..][..26], value);
+    }
+
+    fn set_ot_gender(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0xdd) & 0x7f) | (value << 7);
+        self.write(0xdd, byte);
+    }
+
+    fn set_met_level(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0xdd) & 0x80) | (value & 0x7f);
+        self.write(0xdd, byte);
+    }
+
+    fn set_ribbon(&mut self, ribbon: types::Ribbon, value: bool) {
+        let index = ribbon as usize;
+        if index <= types::Ribbon::MasterToughness as usize {
+            self.write_flag(0x30, index, value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +374,7 @@ mod test {
     use super::*;
     use crate::impl_test;
     use crate::types;
+    use alloc::vec::Vec;
 
     const TEST_EKX: [u8; Pkm::STORED_SIZE] = [
         0xa9, 0x21, 0x71, 0xc5, 0x00, 0x00, 0xcb, 0x2d, 0x65, 0x7b, 0x73, 0x52, 0xd9, 0xef, 0xae,
@@ -201,25 +419,25 @@ mod test {
 
         #[test]
         fn encrypted() {
-            assert_eq!(Pkm::is_encrypted(&TEST_EKX), true)
+            assert!(Pkm::is_encrypted(&TEST_EKX))
         }
 
         #[test]
         fn decrypted() {
-            assert_eq!(Pkm::is_encrypted(&TEST_PKX), false)
+            assert!(!Pkm::is_encrypted(&TEST_PKX))
         }
     }
 
     #[test]
     fn should_decrypt() {
-        let mut ekx = TEST_EKX.clone();
+        let mut ekx = TEST_EKX;
         Pkm::decrypt_raw(&mut ekx);
         assert_eq!(ekx, TEST_PKX);
     }
 
     #[test]
     fn should_encrypt() {
-        let mut pkx = TEST_PKX.clone();
+        let mut pkx = TEST_PKX;
         Pkm::encrypt_raw(&mut pkx);
         assert_eq!(pkx, TEST_EKX);
     }
@@ -255,6 +473,8 @@ mod test {
     impl_test!(move2_pp_ups, 0);
     impl_test!(move3_pp_ups, 0);
     impl_test!(move4_pp_ups, 0);
+    impl_test!(relearn_move1_t, types::Move::None);
+    impl_test!(iter_relearn_moves, [types::Move::None; 4]);
     impl_test!(iv_hp, 3);
     impl_test!(iv_atk, 12);
     impl_test!(iv_def, 12);
@@ -282,6 +502,30 @@ mod test {
     impl_test!(ot_name, "PKHeX");
     impl_test!(ht_name, "");
     impl_test!(status_condition, 0);
+    impl_test!(ot_intensity, 1);
+    impl_test!(ot_memory, 1);
+    impl_test!(ot_text_var, 40);
+    impl_test!(ot_feeling, 14);
+    impl_test!(ht_memory, 0);
+    impl_test!(ribbon_count_memory_contest, 0);
+    impl_test!(ribbon_count_memory_battle, 0);
+    impl_test!(version_t, types::GameVersion::Y);
+    impl_test!(met_location, 56);
+    impl_test!(egg_location, 0);
+    impl_test!(met_location_name, Some("Glittering Cave"));
+    impl_test!(egg_location_name, None);
+    impl_test!(contest_cool, 0);
+    impl_test!(contest_sheen, 0);
+    impl_test!(pokerus_strain, 0);
+    impl_test!(is_pokerus_infected, false);
+    impl_test!(super_training_medal_count, 0);
+    impl_test!(fullness, 0);
+    impl_test!(enjoyment, 0);
+    impl_test!(ot_affection, 0);
+    impl_test!(country, 49);
+    impl_test!(region, 52);
+    impl_test!(console_region_t, types::ConsoleRegion::Americas);
+    impl_test!(training_bag, 0);
     // impl_test!(stat_level, 0);
     // impl_test!(stat_hp_max, 0);
     // impl_test!(stat_atk, 0);
@@ -291,9 +535,104 @@ mod test {
     // impl_test!(stat_spd, 0);
     // impl_test!(stat_hp_current, 0);
 
+    #[test]
+    fn should_read_ribbons() {
+        let mut pkx = TEST_PKX;
+        pkx[0x30] = 0b1000_0001;
+        pkx[0x35] = 0b0010_0000;
+        pkx[0x38] = 3;
+        let pkx = Pkm::new(pkx);
+        let ribbons: Vec<types::Ribbon> = pkx.ribbons().collect();
+        assert_eq!(
+            ribbons,
+            [
+                types::Ribbon::ChampionKalos,
+                types::Ribbon::Effort,
+                types::Ribbon::MasterToughness
+            ]
+        );
+    }
+
+    #[test]
+    fn should_render_ot_memory() {
+        let pkx = Pkm::new(TEST_EKX);
+        assert_eq!(
+            pkx.ot_memory_text().as_deref(),
+            Some("Ferroseed remembers arriving via Link Trade at Connecting Cave and becoming PKHeX's friend.")
+        );
+        assert_eq!(pkx.ht_memory_text(types::GameVersion::Y), None);
+    }
+
+    #[test]
+    fn should_read_pokerus() {
+        let mut pkx = TEST_PKX;
+        pkx[0x2b] = 0x30;
+        let pkx = Pkm::new(pkx);
+        assert_eq!(pkx.pokerus_strain(), 3);
+        assert_eq!(pkx.pokerus_days(), 0);
+        assert!(pkx.is_pokerus_cured());
+    }
+
+    #[test]
+    fn should_read_super_training_medals() {
+        let mut pkx = TEST_PKX;
+        pkx[0x2c] = 0b0000_0111;
+        pkx[0x2f] = 0b1000_0000;
+        let pkx = Pkm::new(pkx);
+        assert!(pkx.has_super_training_medal(0));
+        assert!(pkx.has_super_training_medal(29));
+        assert!(!pkx.has_super_training_medal(1));
+        assert_eq!(pkx.super_training_medal_count(), 2);
+    }
+
+    #[test]
+    fn should_read_geolocations() {
+        let mut pkx = TEST_PKX;
+        pkx[0x94] = 7;
+        pkx[0x95] = 49;
+        let pkx = Pkm::new(pkx);
+        let geolocations = pkx.geolocations();
+        assert_eq!(
+            geolocations[0],
+            types::Geolocation {
+                country: 49,
+                region: 7
+            }
+        );
+        assert!(geolocations[1].is_empty());
+    }
+
+    #[test]
+    fn should_write_fields() {
+        let mut pkx = Pkm::new(TEST_EKX);
+        pkx.set_species(types::Species::Pikachu as u16);
+        pkx.set_nickname("Sparky♂");
+        pkx.set_is_nicknamed(true);
+        pkx.set_met_level(42);
+        pkx.set_ot_gender(1);
+        pkx.set_gender(1);
+        pkx.set_iv_spd(30);
+        pkx.set_ability_number(4);
+        pkx.set_form(1);
+        pkx.set_ribbon(types::Ribbon::ChampionKalos, true);
+        pkx.refresh_checksum();
+
+        assert!(pkx.is_valid());
+        assert_eq!(pkx.species_t(), types::Species::Pikachu);
+        assert_eq!(pkx.nickname(), "Sparky♂");
+        assert!(pkx.is_nicknamed());
+        assert_eq!(pkx.met_level(), 42);
+        assert_eq!(pkx.ot_gender_t(), types::Gender::Female);
+        assert_eq!(pkx.gender_t(), types::Gender::Female);
+        assert_eq!(pkx.iv_spd(), 30);
+        assert_eq!(pkx.ability_number_t(), types::AbilityNumber::Hidden);
+        assert_eq!(pkx.form(), 1);
+        assert!(pkx.has_ribbon(types::Ribbon::ChampionKalos));
+    }
+
     #[test]
     fn should_return_not_shiny_for_default() {
         let pkx = Pkm::default();
-        assert_eq!(pkx.is_shiny(), false)
+        assert!(!pkx.is_shiny())
     }
 }
@@ -1,8 +1,11 @@
 use crate::impl_read_prop;
-use crate::pkx::Pkx;
+use crate::impl_write_prop;
+use crate::pkx::{Pkx, PkxMut};
 use crate::poke_crypto::PokeCrypto;
 use crate::reader::Reader;
 use crate::strings::string_converter8;
+use crate::types;
+use crate::writer::Writer;
 use alloc::string::String;
 
 pub struct Pk9 {
@@ -23,6 +26,12 @@ impl Reader for Pk9 {
     }
 }
 
+impl Writer for Pk9 {
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
 impl PokeCrypto for Pk9 {
     const PARTY_SIZE: usize = 0x158;
     const STORED_SIZE: usize = 0x148;
@@ -38,6 +47,8 @@ impl PokeCrypto for Pk9 {
 }
 
 impl Pk9 {
+    pub const RECORD_COUNT: usize = 200;
+
     pub fn new(mut data: [u8; Self::STORED_SIZE]) -> Self {
         Self::decrypt_raw(&mut data);
         Self { data }
@@ -51,6 +62,55 @@ impl Pk9 {
 
         Self::default()
     }
+
+    impl_read_prop!(minted_nature: u8 = 0x21);
+    impl_read_prop!(pub height_scalar: u8 = 0x48);
+    impl_read_prop!(pub weight_scalar: u8 = 0x49);
+    impl_read_prop!(pub scale: u8 = 0x4a);
+    impl_read_prop!(pub tera_type_original: u8 = 0x94);
+    impl_read_prop!(pub tera_type_override: u8 = 0x95);
+    impl_write_prop!(pub set_minted_nature: u8 = 0x21);
+    impl_write_prop!(pub set_height_scalar: u8 = 0x48);
+    impl_write_prop!(pub set_weight_scalar: u8 = 0x49);
+    impl_write_prop!(pub set_scale: u8 = 0x4a);
+    impl_write_prop!(pub set_tera_type_original: u8 = 0x94);
+    impl_write_prop!(pub set_tera_type_override: u8 = 0x95);
+    impl_read_prop!(pub obedience_level: u8 = 0x11f);
+
+    pub fn minted_nature_t(&self) -> types::Nature {
+        self.minted_nature().into()
+    }
+
+    pub fn tera_type_original_t(&self) -> types::TeraType {
+        self.tera_type_original().into()
+    }
+
+    pub fn tera_type_override_t(&self) -> Option<types::TeraType> {
+        match self.tera_type_override() {
+            types::TeraType::OVERRIDE_NONE => None,
+            tera_type => Some(tera_type.into()),
+        }
+    }
+
+    /// The Tera Type the Pokemon terastallizes into, taking overrides into account.
+    pub fn tera_type(&self) -> types::TeraType {
+        self.tera_type_override_t()
+            .unwrap_or_else(|| self.tera_type_original_t())
+    }
+
+    /// Whether the TM with the given record index has been learned.
+    pub fn has_record_flag(&self, index: usize) -> bool {
+        index < Self::RECORD_COUNT && self.read_flag(0x127, index)
+    }
+
+    pub fn record_flags(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..Self::RECORD_COUNT).filter(|index| self.has_record_flag(*index))
+    }
+
+    /// The ribbon or mark displayed next to the Pokemon's name, if any.
+    pub fn affixed_ribbon(&self) -> Option<types::Ribbon> {
+        types::Ribbon::try_from(self.read::<u8>(0xd4)).ok()
+    }
 }
 
 impl Pkx for Pk9 {
@@ -62,7 +122,6 @@ impl Pkx for Pk9 {
     impl_read_prop!(sid16: u16 = 0x0e);
     impl_read_prop!(exp: u32 = 0x10);
     impl_read_prop!(ability: u16 = 0x14);
-    impl_read_prop!(ability_number: u8 = 0x16);
     impl_read_prop!(pid: u32 = 0x1c);
     impl_read_prop!(nature: u8 = 0x20);
     impl_read_prop!(form: u8 = 0x24);
@@ -72,6 +131,8 @@ impl Pkx for Pk9 {
     impl_read_prop!(ev_spe: u8 = 0x29);
     impl_read_prop!(ev_spa: u8 = 0x2a);
     impl_read_prop!(ev_spd: u8 = 0x2b);
+    impl_read_prop!(ribbon_count_memory_contest: u8 = 0x3c);
+    impl_read_prop!(ribbon_count_memory_battle: u8 = 0x3d);
     impl_read_prop!(move1: u16 = 0x72);
     impl_read_prop!(move2: u16 = 0x74);
     impl_read_prop!(move3: u16 = 0x76);
@@ -84,13 +145,29 @@ impl Pkx for Pk9 {
     impl_read_prop!(move2_pp_ups: u8 = 0x7f);
     impl_read_prop!(move3_pp_ups: u8 = 0x80);
     impl_read_prop!(move4_pp_ups: u8 = 0x81);
+    impl_read_prop!(relearn_move1: u16 = 0x82);
+    impl_read_prop!(relearn_move2: u16 = 0x84);
+    impl_read_prop!(relearn_move3: u16 = 0x86);
+    impl_read_prop!(relearn_move4: u16 = 0x88);
     // impl_read_prop!(stat_hp_current: u16 = 0x8a);
     impl_read_prop!(iv32: u32 = 0x8c);
     impl_read_prop!(status_condition: u32 = 0x90);
+    impl_read_prop!(ht_intensity: u8 = 0xc9);
+    impl_read_prop!(ht_memory: u8 = 0xca);
+    impl_read_prop!(ht_feeling: u8 = 0xcb);
+    impl_read_prop!(ht_text_var: u16 = 0xcc);
     impl_read_prop!(language: u8 = 0xd5);
     impl_read_prop!(current_handler: u8 = 0xc4);
+    impl_read_prop!(ht_gender: u8 = 0xc2);
     impl_read_prop!(ht_friendship: u8 = 0xc8);
+    impl_read_prop!(version: u8 = 0xce);
     impl_read_prop!(ot_friendship: u8 = 0x112);
+    impl_read_prop!(ot_intensity: u8 = 0x113);
+    impl_read_prop!(ot_memory: u8 = 0x114);
+    impl_read_prop!(ot_text_var: u16 = 0x116);
+    impl_read_prop!(ot_feeling: u8 = 0x118);
+    impl_read_prop!(egg_location: u16 = 0x120);
+    impl_read_prop!(met_location: u16 = 0x122);
     impl_read_prop!(ball: u8 = 0x124);
     // impl_read_prop!(stat_level: u8 = 0x148);
     // impl_read_prop!(stat_hp_max: u16 = 0x14a);
@@ -140,9 +217,117 @@ impl Pkx for Pk9 {
         (byte >> 1) & 3
     }
 
+    fn ability_number(&self) -> u8 {
+        self.read::<u8>(0x16) & 7
+    }
+
     fn valid_checksum(&self) -> bool {
         self.checksum() == self.calculate_checksum()
     }
+
+    fn has_ribbon(&self, ribbon: types::Ribbon) -> bool {
+        let index = ribbon as usize;
+        if index < 64 {
+            return self.read_flag(0x34, index);
+        }
+        self.read_flag(0x40, index - 64)
+    }
+}
+
+impl PkxMut for Pk9 {
+    impl_write_prop!(set_encryption_constant: u32 = 0x00);
+    impl_write_prop!(set_pid: u32 = 0x1c);
+    impl_write_prop!(set_species: u16 = 0x08);
+    impl_write_prop!(set_form: u8 = 0x24);
+    impl_write_prop!(set_held_item: u16 = 0x0a);
+    impl_write_prop!(set_tid16: u16 = 0x0c);
+    impl_write_prop!(set_sid16: u16 = 0x0e);
+    impl_write_prop!(set_exp: u32 = 0x10);
+    impl_write_prop!(set_ability: u16 = 0x14);
+    impl_write_prop!(set_nature: u8 = 0x20);
+    impl_write_prop!(set_ev_hp: u8 = 0x26);
+    impl_write_prop!(set_ev_atk: u8 = 0x27);
+    impl_write_prop!(set_ev_def: u8 = 0x28);
+    impl_write_prop!(set_ev_spe: u8 = 0x29);
+    impl_write_prop!(set_ev_spa: u8 = 0x2a);
+    impl_write_prop!(set_ev_spd: u8 = 0x2b);
+    impl_write_prop!(set_move1: u16 = 0x72);
+    impl_write_prop!(set_move2: u16 = 0x74);
+    impl_write_prop!(set_move3: u16 = 0x76);
+    impl_write_prop!(set_move4: u16 = 0x78);
+    impl_write_prop!(set_move1_pp: u8 = 0x7a);
+    impl_write_prop!(set_move2_pp: u8 = 0x7b);
+    impl_write_prop!(set_move3_pp: u8 = 0x7c);
+    impl_write_prop!(set_move4_pp: u8 = 0x7d);
+    impl_write_prop!(set_move1_pp_ups: u8 = 0x7e);
+    impl_write_prop!(set_move2_pp_ups: u8 = 0x7f);
+    impl_write_prop!(set_move3_pp_ups: u8 = 0x80);
+    impl_write_prop!(set_move4_pp_ups: u8 = 0x81);
+    impl_write_prop!(set_relearn_move1: u16 = 0x82);
+    impl_write_prop!(set_relearn_move2: u16 = 0x84);
+    impl_write_prop!(set_relearn_move3: u16 = 0x86);
+    impl_write_prop!(set_relearn_move4: u16 = 0x88);
+    impl_write_prop!(set_iv32: u32 = 0x8c);
+    impl_write_prop!(set_ball: u8 = 0x124);
+    impl_write_prop!(set_met_location: u16 = 0x122);
+    impl_write_prop!(set_egg_location: u16 = 0x120);
+    impl_write_prop!(set_version: u8 = 0xce);
+    impl_write_prop!(set_language: u8 = 0xd5);
+    impl_write_prop!(set_current_handler: u8 = 0xc4);
+    impl_write_prop!(set_ht_gender: u8 = 0xc2);
+    impl_write_prop!(set_ot_friendship: u8 = 0x112);
+    impl_write_prop!(set_ht_friendship: u8 = 0xc8);
+    impl_write_prop!(set_ot_memory: u8 = 0x114);
+    impl_write_prop!(set_ot_intensity: u8 = 0x113);
+    impl_write_prop!(set_ot_text_var: u16 = 0x116);
+    impl_write_prop!(set_ot_feeling: u8 = 0x118);
+    impl_write_prop!(set_ht_memory: u8 = 0xca);
+    impl_write_prop!(set_ht_intensity: u8 = 0xc9);
+    impl_write_prop!(set_ht_text_var: u16 = 0xcc);
+    impl_write_prop!(set_ht_feeling: u8 = 0xcb);
+    impl_write_prop!(set_ribbon_count_memory_contest: u8 = 0x3c);
+    impl_write_prop!(set_ribbon_count_memory_battle: u8 = 0x3d);
+
+    fn set_ability_number(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x16) & !7) | (value & 7);
+        self.write(0x16, byte);
+    }
+
+    fn set_gender(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x22) & !(3 << 1)) | ((value & 3) << 1);
+        self.write(0x22, byte);
+    }
+
+    fn set_nickname(&mut self, value: &str) {
+        string_converter8::set_string(&mut self.data[0x58..][..26], value);
+    }
+
+    fn set_ht_name(&mut self, value: &str) {
+        string_converter8::set_string(&mut self.data[0xa8..][..26], value);
+    }
+
+    fn set_ot_name(&mut self, value: &str) {
+        string_converter8::set_string(&mut self.data[0xf8..][..26], value);
+    }
+
+    fn set_ot_gender(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x125) & 0x7f) | (value << 7);
+        self.write(0x125, byte);
+    }
+
+    fn set_met_level(&mut self, value: u8) {
+        let byte = (self.read::<u8>(0x125) & 0x80) | (value & 0x7f);
+        self.write(0x125, byte);
+    }
+
+    fn set_ribbon(&mut self, ribbon: types::Ribbon, value: bool) {
+        let index = ribbon as usize;
+        if index < 64 {
+            self.write_flag(0x34, index, value);
+        } else {
+            self.write_flag(0x40, index - 64, value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -151,6 +336,7 @@ mod test {
     use super::*;
     use crate::impl_test;
     use crate::types;
+    use alloc::vec::Vec;
 
     const TEST_EKX: [u8; Pkm::STORED_SIZE] = [
         0x4e, 0x37, 0x50, 0x03, 0x00, 0x00, 0x83, 0x6c, 0xd0, 0x6e, 0x06, 0xa2, 0x62, 0x1b, 0xc5,
@@ -207,25 +393,25 @@ mod test {
 
         #[test]
         fn encrypted() {
-            assert_eq!(Pkm::is_encrypted(&TEST_EKX), true)
+            assert!(Pkm::is_encrypted(&TEST_EKX))
         }
 
         #[test]
         fn decrypted() {
-            assert_eq!(Pkm::is_encrypted(&TEST_PKX), false)
+            assert!(!Pkm::is_encrypted(&TEST_PKX))
         }
     }
 
     #[test]
     fn should_decrypt() {
-        let mut ekx = TEST_EKX.clone();
+        let mut ekx = TEST_EKX;
         Pkm::decrypt_raw(&mut ekx);
         assert_eq!(ekx, TEST_PKX);
     }
 
     #[test]
     fn should_encrypt() {
-        let mut pkx = TEST_PKX.clone();
+        let mut pkx = TEST_PKX;
         Pkm::encrypt_raw(&mut pkx);
         assert_eq!(pkx, TEST_EKX);
     }
@@ -263,6 +449,8 @@ mod test {
     impl_test!(move2_pp_ups, 0);
     impl_test!(move3_pp_ups, 0);
     impl_test!(move4_pp_ups, 0);
+    impl_test!(relearn_move1_t, types::Move::None);
+    impl_test!(iter_relearn_moves, [types::Move::None; 4]);
 
     impl_test!(iv_hp, 31);
     impl_test!(iv_atk, 8);
@@ -293,6 +481,22 @@ mod test {
     impl_test!(ot_name, "PKHeX");
     impl_test!(ht_name, "");
     impl_test!(status_condition, 0);
+    impl_test!(ot_memory, 0);
+    impl_test!(ht_memory, 0);
+    impl_test!(ribbon_count_memory_contest, 0);
+    impl_test!(ribbon_count_memory_battle, 0);
+    impl_test!(affixed_ribbon, None);
+    impl_test!(height_scalar, 138);
+    impl_test!(weight_scalar, 111);
+    impl_test!(scale, 92);
+    impl_test!(tera_type_original_t, types::TeraType::Ice);
+    impl_test!(tera_type_override_t, None);
+    impl_test!(tera_type, types::TeraType::Ice);
+    impl_test!(obedience_level, 12);
+    impl_test!(version_t, types::GameVersion::Scarlet);
+    impl_test!(met_location, 30024);
+    impl_test!(egg_location, 0);
+    impl_test!(met_location_name, Some("a Tera Raid Battle"));
     // impl_test!(stat_level, 0);
     // impl_test!(stat_hp_max, 0);
     // impl_test!(stat_atk, 0);
@@ -302,9 +506,81 @@ mod test {
     // impl_test!(stat_spd, 0);
     // impl_test!(stat_hp_current, 180);
 
+    #[test]
+    fn should_read_ribbons() {
+        let mut pkx = TEST_PKX;
+        pkx[0x34] = 0b0000_0001;
+        pkx[0x45] = 0b0010_0000;
+        let pkx = Pkm::new(pkx);
+        let ribbons: Vec<types::Ribbon> = pkx.ribbons().collect();
+        assert_eq!(
+            ribbons,
+            [types::Ribbon::ChampionKalos, types::Ribbon::MarkTitan]
+        );
+    }
+
+    #[test]
+    fn should_prefer_tera_type_override() {
+        let mut pkx = TEST_PKX;
+        pkx[0x95] = 99;
+        let pkx = Pkm::new(pkx);
+        assert_eq!(pkx.tera_type_original_t(), types::TeraType::Ice);
+        assert_eq!(pkx.tera_type_override_t(), Some(types::TeraType::Stellar));
+        assert_eq!(pkx.tera_type(), types::TeraType::Stellar);
+    }
+
+    #[test]
+    fn should_read_record_flags() {
+        let mut pkx = TEST_PKX;
+        pkx[0x127] = 0b0000_0101;
+        pkx[0x13f] = 0b1000_0000;
+        let pkx = Pkm::new(pkx);
+        let flags: Vec<usize> = pkx.record_flags().collect();
+        assert_eq!(flags, [0, 2, 199]);
+        assert!(!pkx.has_record_flag(200));
+    }
+
+    #[test]
+    fn should_read_marks() {
+        let mut pkx = TEST_PKX;
+        pkx[0x34] = 0b0000_0001;
+        pkx[0x45] = 0b0010_0000;
+        let pkx = Pkm::new(pkx);
+        let marks: Vec<types::Ribbon> = pkx.marks().collect();
+        assert_eq!(marks, [types::Ribbon::MarkTitan]);
+    }
+
+    #[test]
+    fn should_write_fields() {
+        let mut pkx = Pkm::new(TEST_EKX);
+        pkx.set_species(types::Species::Pikachu as u16);
+        pkx.set_nickname("Sparky♂");
+        pkx.set_is_nicknamed(true);
+        pkx.set_met_level(42);
+        pkx.set_ot_gender(1);
+        pkx.set_gender(1);
+        pkx.set_iv_spd(30);
+        pkx.set_ability_number(4);
+        pkx.set_form(1);
+        pkx.set_ribbon(types::Ribbon::ChampionKalos, true);
+        pkx.refresh_checksum();
+
+        assert!(pkx.is_valid());
+        assert_eq!(pkx.species_t(), types::Species::Pikachu);
+        assert_eq!(pkx.nickname(), "Sparky♂");
+        assert!(pkx.is_nicknamed());
+        assert_eq!(pkx.met_level(), 42);
+        assert_eq!(pkx.ot_gender_t(), types::Gender::Female);
+        assert_eq!(pkx.gender_t(), types::Gender::Female);
+        assert_eq!(pkx.iv_spd(), 30);
+        assert_eq!(pkx.ability_number_t(), types::AbilityNumber::Hidden);
+        assert_eq!(pkx.form(), 1);
+        assert!(pkx.has_ribbon(types::Ribbon::ChampionKalos));
+    }
+
     #[test]
     fn should_return_not_shiny_for_default() {
         let pkx = Pkm::default();
-        assert_eq!(pkx.is_shiny(), false)
+        assert!(!pkx.is_shiny())
     }
 }
@@ -0,0 +1,166 @@
+use crate::pkx::{Pkx, PkxMut};
+use crate::poke_crypto::PokeCrypto;
+use crate::reader::Reader;
+use crate::types::BoxSlot;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// PC boxes holding Pokemon of a single format.
+pub trait PkmStorage {
+    type Pkm: PkxMut + Default;
+
+    fn box_count(&self) -> u8;
+
+    /// The raw bytes of a slot, which are stored or party sized depending on
+    /// the game.  `None` if the slot is out of range.
+    fn slot_data(&self, slot: BoxSlot) -> Option<&[u8]>;
+    fn slot_data_mut(&mut self, slot: BoxSlot) -> Option<&mut [u8]>;
+
+    fn box_name(&self, _box_index: u8) -> Option<String> {
+        None
+    }
+
+    fn box_wallpaper(&self, _box_index: u8) -> Option<u8> {
+        None
+    }
+
+    fn contains(&self, slot: BoxSlot) -> bool {
+        slot.box_index < self.box_count() && slot.slot < BoxSlot::SLOT_COUNT
+    }
+
+    fn pkm(&self, slot: BoxSlot) -> Option<Self::Pkm> {
+        Self::Pkm::from_bytes(self.slot_data(slot)?)
+    }
+
+    /// Stores a Pokemon, encrypted.  Any party stats in the slot are cleared.
+    fn set_pkm(&mut self, slot: BoxSlot, pkm: &Self::Pkm) {
+        let Some(entry) = self.slot_data_mut(slot) else {
+            return;
+        };
+
+        let stored = &pkm.as_slice()[..Self::Pkm::STORED_SIZE];
+        entry.fill(0);
+        entry[..stored.len()].copy_from_slice(stored);
+        Self::Pkm::encrypt_raw(entry);
+    }
+
+    fn slots(&self) -> impl Iterator<Item = BoxSlot> {
+        let total = self.box_count() as usize * BoxSlot::SLOT_COUNT as usize;
+        (0..total).map(BoxSlot::from_index)
+    }
+
+    /// Iterates the slots holding a Pokemon.
+    fn iter_pkm(&self) -> impl Iterator<Item = (BoxSlot, Self::Pkm)> + '_ {
+        self.slots().filter_map(|slot| {
+            self.pkm(slot)
+                .filter(|pkm| pkm.species() != 0)
+                .map(|pkm| (slot, pkm))
+        })
+    }
+}
+
+/// Boxes stored back to back in a plain buffer, like a box dump from RAM.
+pub struct BoxStorage<T> {
+    data: Vec<u8>,
+    slot_size: usize,
+    pkm: PhantomData<T>,
+}
+
+impl<T: PkxMut + Default> BoxStorage<T> {
+    /// Reads boxes of stored sized slots.
+    pub fn new(data: Vec<u8>) -> Self {
+        Self::with_slot_size(data, T::STORED_SIZE)
+    }
+
+    /// Reads boxes with a different slot size, e.g. `T::PARTY_SIZE` for the
+    /// Switch games.
+    pub fn with_slot_size(data: Vec<u8>, slot_size: usize) -> Self {
+        Self {
+            data,
+            slot_size: slot_size.max(T::STORED_SIZE),
+            pkm: PhantomData,
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl<T: PkxMut + Default> PkmStorage for BoxStorage<T> {
+    type Pkm = T;
+
+    fn box_count(&self) -> u8 {
+        let box_size = self.slot_size * BoxSlot::SLOT_COUNT as usize;
+        (self.data.len() / box_size).min(BoxSlot::BOX_COUNT as usize) as u8
+    }
+
+    fn slot_data(&self, slot: BoxSlot) -> Option<&[u8]> {
+        if !self.contains(slot) {
+            return None;
+        }
+
+        self.data
+            .get(slot.index() * self.slot_size..)?
+            .get(..self.slot_size)
+    }
+
+    fn slot_data_mut(&mut self, slot: BoxSlot) -> Option<&mut [u8]> {
+        if !self.contains(slot) {
+            return None;
+        }
+
+        let slot_size = self.slot_size;
+        self.data
+            .get_mut(slot.index() * slot_size..)?
+            .get_mut(..slot_size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pk8::Pk8;
+    use crate::types::Species;
+    use alloc::vec;
+
+    fn pkm(species: Species) -> Pk8 {
+        let mut pkm = Pk8::default();
+        pkm.set_encryption_constant(0x0bad_cafe);
+        pkm.set_species(species as u16);
+        pkm.refresh_checksum();
+        pkm
+    }
+
+    #[test]
+    fn should_count_boxes() {
+        let storage = BoxStorage::<Pk8>::new(vec![0; 2 * 30 * Pk8::STORED_SIZE + 1]);
+        assert_eq!(storage.box_count(), 2);
+        assert_eq!(storage.slots().count(), 60);
+        assert!(storage.pkm(BoxSlot::new(2, 0)).is_none());
+    }
+
+    #[test]
+    fn should_iterate_non_empty_slots() {
+        let data = vec![0; 32 * 30 * Pk8::PARTY_SIZE];
+        let mut storage = BoxStorage::<Pk8>::with_slot_size(data, Pk8::PARTY_SIZE);
+        storage.set_pkm(BoxSlot::new(0, 3), &pkm(Species::Wooloo));
+        storage.set_pkm(BoxSlot::new(31, 29), &pkm(Species::Rookidee));
+
+        let found: Vec<(BoxSlot, Species)> = storage
+            .iter_pkm()
+            .map(|(slot, pkm)| (slot, pkm.species_t()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (BoxSlot::new(0, 3), Species::Wooloo),
+                (BoxSlot::new(31, 29), Species::Rookidee)
+            ]
+        );
+
+        let data = storage.into_bytes();
+        assert!(Pk8::is_encrypted(&data[3 * Pk8::PARTY_SIZE..]));
+    }
+}
@@ -1,4 +1,8 @@
+use crate::locations;
+use crate::memories;
+use crate::poke_crypto::PokeCrypto;
 use crate::types;
+use crate::writer::Writer;
 use alloc::string::String;
 
 pub trait Pkx: Sized {
@@ -23,6 +27,9 @@ pub trait Pkx: Sized {
     fn ot_gender(&self) -> u8;
     fn ball(&self) -> u8;
     fn met_level(&self) -> u8;
+    fn met_location(&self) -> u16;
+    fn egg_location(&self) -> u16;
+    fn version(&self) -> u8;
 
     // Battle
     fn move1(&self) -> u16;
@@ -37,6 +44,10 @@ pub trait Pkx: Sized {
     fn move2_pp_ups(&self) -> u8;
     fn move3_pp_ups(&self) -> u8;
     fn move4_pp_ups(&self) -> u8;
+    fn relearn_move1(&self) -> u16;
+    fn relearn_move2(&self) -> u16;
+    fn relearn_move3(&self) -> u16;
+    fn relearn_move4(&self) -> u16;
     fn ev_hp(&self) -> u8;
     fn ev_atk(&self) -> u8;
     fn ev_def(&self) -> u8;
@@ -63,6 +74,67 @@ pub trait Pkx: Sized {
     }
     fn status_condition(&self) -> u32;
 
+    // Ribbons
+    fn has_ribbon(&self, ribbon: types::Ribbon) -> bool;
+    fn ribbon_count_memory_contest(&self) -> u8;
+    fn ribbon_count_memory_battle(&self) -> u8;
+
+    fn ribbons(&self) -> impl Iterator<Item = types::Ribbon> + '_ {
+        types::Ribbon::iter().filter(|ribbon| self.has_ribbon(*ribbon))
+    }
+
+    fn marks(&self) -> impl Iterator<Item = types::Ribbon> + '_ {
+        self.ribbons().filter(types::Ribbon::is_mark)
+    }
+
+    // Memories
+    fn ot_memory(&self) -> u8;
+    fn ot_intensity(&self) -> u8;
+    fn ot_text_var(&self) -> u16;
+    fn ot_feeling(&self) -> u8;
+    fn ht_memory(&self) -> u8;
+    fn ht_intensity(&self) -> u8;
+    fn ht_text_var(&self) -> u16;
+    fn ht_feeling(&self) -> u8;
+
+    fn ot_memory_t(&self) -> memories::Memory {
+        memories::Memory {
+            id: self.ot_memory(),
+            intensity: self.ot_intensity(),
+            text_var: self.ot_text_var(),
+            feeling: self.ot_feeling(),
+        }
+    }
+
+    fn ht_memory_t(&self) -> memories::Memory {
+        memories::Memory {
+            id: self.ht_memory(),
+            intensity: self.ht_intensity(),
+            text_var: self.ht_text_var(),
+            feeling: self.ht_feeling(),
+        }
+    }
+
+    /// The original trainer's memory, made in the game the Pokemon came from.
+    fn ot_memory_text(&self) -> Option<String> {
+        memories::memory_text(
+            &self.ot_memory_t(),
+            &self.nickname(),
+            &self.ot_name(),
+            self.version_t(),
+        )
+    }
+
+    /// The handling trainer's memory, made in `version`.
+    fn ht_memory_text(&self, version: types::GameVersion) -> Option<String> {
+        memories::memory_text(
+            &self.ht_memory_t(),
+            &self.nickname(),
+            &self.ht_name(),
+            version,
+        )
+    }
+
     // Cannot implement until stat loading and box -> party conversion is added
     // fn stat_level(&self) -> u8;
     // fn stat_hp_max(&self) -> u16;
@@ -84,6 +156,7 @@ pub trait Pkx: Sized {
     fn ht_friendship(&self) -> u8;
     fn current_handler(&self) -> u8;
     fn ht_name(&self) -> String;
+    fn ht_gender(&self) -> u8;
 
     fn tsv(&self) -> u16 {
         (self.tid16() ^ self.sid16()) >> 4
@@ -142,6 +215,34 @@ pub trait Pkx: Sized {
     fn move4_t(&self) -> types::Move {
         self.move4().into()
     }
+    fn relearn_move1_t(&self) -> types::Move {
+        self.relearn_move1().into()
+    }
+    fn relearn_move2_t(&self) -> types::Move {
+        self.relearn_move2().into()
+    }
+    fn relearn_move3_t(&self) -> types::Move {
+        self.relearn_move3().into()
+    }
+    fn relearn_move4_t(&self) -> types::Move {
+        self.relearn_move4().into()
+    }
+    fn iter_moves(&self) -> [types::Move; 4] {
+        [
+            self.move1_t(),
+            self.move2_t(),
+            self.move3_t(),
+            self.move4_t(),
+        ]
+    }
+    fn iter_relearn_moves(&self) -> [types::Move; 4] {
+        [
+            self.relearn_move1_t(),
+            self.relearn_move2_t(),
+            self.relearn_move3_t(),
+            self.relearn_move4_t(),
+        ]
+    }
     fn ot_gender_t(&self) -> types::Gender {
         self.ot_gender().into()
     }
@@ -166,6 +267,20 @@ pub trait Pkx: Sized {
     fn hidden_power_t(&self) -> types::HiddenPower {
         self.hidden_power().into()
     }
+    fn ball_t(&self) -> types::Ball {
+        self.ball().into()
+    }
+    fn version_t(&self) -> types::GameVersion {
+        self.version().into()
+    }
+
+    fn met_location_name(&self) -> Option<&'static str> {
+        locations::location_name(self.version_t(), self.met_location())
+    }
+
+    fn egg_location_name(&self) -> Option<&'static str> {
+        locations::location_name(self.version_t(), self.egg_location())
+    }
 
     fn gender_ratio(&self) -> types::GenderRatio {
         self.species_t().get_gender_ratio()
@@ -175,3 +290,141 @@ pub trait Pkx: Sized {
         self.sanity() == 0 && self.valid_checksum() && self.species_t() != types::Species::None
     }
 }
+
+/// Setters for the fields shared by every Gen6+ format.
+///
+/// Setters only change the decrypted data; call [PkxMut::refresh_checksum]
+/// once done editing so the Pokemon stays valid.
+pub trait PkxMut: Pkx + PokeCrypto + Writer {
+    fn set_encryption_constant(&mut self, value: u32);
+    fn set_pid(&mut self, value: u32);
+    fn set_species(&mut self, value: u16);
+    fn set_form(&mut self, value: u8);
+    fn set_held_item(&mut self, value: u16);
+    fn set_tid16(&mut self, value: u16);
+    fn set_sid16(&mut self, value: u16);
+    fn set_exp(&mut self, value: u32);
+    fn set_ability(&mut self, value: u16);
+    fn set_ability_number(&mut self, value: u8);
+    fn set_nature(&mut self, value: u8);
+    fn set_gender(&mut self, value: u8);
+    fn set_ev_hp(&mut self, value: u8);
+    fn set_ev_atk(&mut self, value: u8);
+    fn set_ev_def(&mut self, value: u8);
+    fn set_ev_spe(&mut self, value: u8);
+    fn set_ev_spa(&mut self, value: u8);
+    fn set_ev_spd(&mut self, value: u8);
+    fn set_move1(&mut self, value: u16);
+    fn set_move2(&mut self, value: u16);
+    fn set_move3(&mut self, value: u16);
+    fn set_move4(&mut self, value: u16);
+    fn set_move1_pp(&mut self, value: u8);
+    fn set_move2_pp(&mut self, value: u8);
+    fn set_move3_pp(&mut self, value: u8);
+    fn set_move4_pp(&mut self, value: u8);
+    fn set_move1_pp_ups(&mut self, value: u8);
+    fn set_move2_pp_ups(&mut self, value: u8);
+    fn set_move3_pp_ups(&mut self, value: u8);
+    fn set_move4_pp_ups(&mut self, value: u8);
+    fn set_relearn_move1(&mut self, value: u16);
+    fn set_relearn_move2(&mut self, value: u16);
+    fn set_relearn_move3(&mut self, value: u16);
+    fn set_relearn_move4(&mut self, value: u16);
+    fn set_iv32(&mut self, value: u32);
+    fn set_nickname(&mut self, value: &str);
+    fn set_ot_name(&mut self, value: &str);
+    fn set_ht_name(&mut self, value: &str);
+    fn set_ht_gender(&mut self, value: u8);
+    fn set_ot_gender(&mut self, value: u8);
+    fn set_met_level(&mut self, value: u8);
+    fn set_ball(&mut self, value: u8);
+    fn set_met_location(&mut self, value: u16);
+    fn set_egg_location(&mut self, value: u16);
+    fn set_version(&mut self, value: u8);
+    fn set_language(&mut self, value: u8);
+    fn set_current_handler(&mut self, value: u8);
+    fn set_ot_friendship(&mut self, value: u8);
+    fn set_ht_friendship(&mut self, value: u8);
+    fn set_ot_memory(&mut self, value: u8);
+    fn set_ot_intensity(&mut self, value: u8);
+    fn set_ot_text_var(&mut self, value: u16);
+    fn set_ot_feeling(&mut self, value: u8);
+    fn set_ht_memory(&mut self, value: u8);
+    fn set_ht_intensity(&mut self, value: u8);
+    fn set_ht_text_var(&mut self, value: u16);
+    fn set_ht_feeling(&mut self, value: u8);
+    fn set_ribbon(&mut self, ribbon: types::Ribbon, value: bool);
+    fn set_ribbon_count_memory_contest(&mut self, value: u8);
+    fn set_ribbon_count_memory_battle(&mut self, value: u8);
+
+    fn set_moves(&mut self, moves: [types::Move; 4]) {
+        self.set_move1(moves[0] as u16);
+        self.set_move2(moves[1] as u16);
+        self.set_move3(moves[2] as u16);
+        self.set_move4(moves[3] as u16);
+    }
+
+    fn set_relearn_moves(&mut self, moves: [types::Move; 4]) {
+        self.set_relearn_move1(moves[0] as u16);
+        self.set_relearn_move2(moves[1] as u16);
+        self.set_relearn_move3(moves[2] as u16);
+        self.set_relearn_move4(moves[3] as u16);
+    }
+
+    /// Restores every move's PP, counting the PP Ups applied to it.
+    fn heal_pp(&mut self) {
+        let pp = |move_id: u16, pp_ups: u8| types::Move::from(move_id).max_pp(pp_ups);
+        self.set_move1_pp(pp(self.move1(), self.move1_pp_ups()));
+        self.set_move2_pp(pp(self.move2(), self.move2_pp_ups()));
+        self.set_move3_pp(pp(self.move3(), self.move3_pp_ups()));
+        self.set_move4_pp(pp(self.move4(), self.move4_pp_ups()));
+    }
+
+    fn set_iv_bits(&mut self, shift: u32, mask: u32, value: u32) {
+        let iv32 = (self.iv32() & !(mask << shift)) | ((value & mask) << shift);
+        self.set_iv32(iv32);
+    }
+    fn set_iv_hp(&mut self, value: u8) {
+        self.set_iv_bits(0, 0x1f, value.into())
+    }
+    fn set_iv_atk(&mut self, value: u8) {
+        self.set_iv_bits(5, 0x1f, value.into())
+    }
+    fn set_iv_def(&mut self, value: u8) {
+        self.set_iv_bits(10, 0x1f, value.into())
+    }
+    fn set_iv_spe(&mut self, value: u8) {
+        self.set_iv_bits(15, 0x1f, value.into())
+    }
+    fn set_iv_spa(&mut self, value: u8) {
+        self.set_iv_bits(20, 0x1f, value.into())
+    }
+    fn set_iv_spd(&mut self, value: u8) {
+        self.set_iv_bits(25, 0x1f, value.into())
+    }
+    fn set_is_egg(&mut self, value: bool) {
+        self.set_iv_bits(30, 1, value.into())
+    }
+    fn set_is_nicknamed(&mut self, value: bool) {
+        self.set_iv_bits(31, 1, value.into())
+    }
+
+    fn refresh_checksum(&mut self) {
+        let checksum = self.calculate_checksum();
+        self.write(0x06, checksum);
+    }
+
+    /// Reads a Pokemon from stored or party sized data, decrypting it if
+    /// needed.  Party stats are ignored.
+    fn from_bytes(data: &[u8]) -> Option<Self>
+    where
+        Self: Default,
+    {
+        let mut pkm = Self::default();
+        let stored = data.get(..Self::STORED_SIZE)?;
+        let dst = pkm.as_mut_slice();
+        dst.copy_from_slice(stored);
+        Self::decrypt_raw(dst);
+        Some(pkm)
+    }
+}
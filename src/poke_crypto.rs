@@ -1,5 +1,7 @@
 use crate::reader::Reader;
-use core::{convert::TryInto, usize};
+use crate::rng::LCRNG;
+use alloc::vec::Vec;
+use core::convert::TryInto;
 
 #[rustfmt::skip]
 const BLOCK_SWAP_DST: [usize; 96] = [
@@ -46,11 +48,12 @@ const BLOCK_POSITION_INVERT: [usize; 32] =
     0, 1, 2, 4, 3, 5, 6, 7, // duplicates of 0-7 to eliminate modulus
 ];
 
-fn crypt_pkm(out: &mut [u8], mut seed: u32) {
-    out.chunks_mut(2).skip(4).for_each(|bytes| {
-        seed = 0x41c64e6du32.wrapping_mul(seed).wrapping_add(0x6073);
-        bytes[0] ^= (seed >> 16) as u8;
-        bytes[1] ^= (seed >> 24) as u8;
+fn crypt(out: &mut [u8], seed: u32) {
+    let mut rng = LCRNG::new(seed);
+    out.chunks_mut(2).for_each(|bytes| {
+        let value = rng.next_u16();
+        bytes[0] ^= value as u8;
+        bytes[1] ^= (value >> 8) as u8;
     });
 }
 
@@ -75,7 +78,7 @@ fn shuffle_array(data: &mut [u8], sv: usize, block_size: usize) {
 fn decrypt(ekx: &mut [u8], block_size: usize) {
     let seed = ekx.read(0);
     let sv = ((seed as usize) >> 13) & 31;
-    crypt_pkm(ekx, seed);
+    crypt(&mut ekx[8..], seed);
     shuffle_array(ekx, sv, block_size);
 }
 
@@ -83,10 +86,30 @@ fn encrypt(pkx: &mut [u8], block_size: usize) {
     let seed = pkx.read(0);
     let sv = ((seed as usize) >> 13) & 31;
     shuffle_array(pkx, BLOCK_POSITION_INVERT[sv], block_size);
-    crypt_pkm(pkx, seed);
+    crypt(&mut pkx[8..], seed);
 }
 
-fn calculate_checksum(pkx: &[u8]) -> u16 {
+/// Gen4 and Gen5 shuffle blocks by the PID, but seed the stored bytes with the
+/// checksum and the party stats with the PID.
+pub(crate) fn decrypt_gen5(ekx: &mut [u8], block_size: usize, stored_size: usize) {
+    let pid: u32 = ekx.read(0);
+    let checksum: u16 = ekx.read(6);
+    let sv = ((pid as usize) >> 13) & 31;
+    crypt(&mut ekx[8..stored_size], checksum.into());
+    crypt(&mut ekx[stored_size..], pid);
+    shuffle_array(ekx, sv, block_size);
+}
+
+pub(crate) fn encrypt_gen5(pkx: &mut [u8], block_size: usize, stored_size: usize) {
+    let pid: u32 = pkx.read(0);
+    let checksum: u16 = pkx.read(6);
+    let sv = ((pid as usize) >> 13) & 31;
+    shuffle_array(pkx, BLOCK_POSITION_INVERT[sv], block_size);
+    crypt(&mut pkx[8..stored_size], checksum.into());
+    crypt(&mut pkx[stored_size..], pid);
+}
+
+pub(crate) fn calculate_checksum(pkx: &[u8]) -> u16 {
     let mut checksum = 0u16;
 
     for chunks in pkx.chunks_exact(2) {
@@ -122,4 +145,11 @@ pub trait PokeCrypto: Reader {
         let data = self.as_slice();
         calculate_checksum(&data[8..Self::STORED_SIZE])
     }
+
+    /// The stored bytes, encrypted the way boxes keep them.
+    fn encrypted_bytes(&self) -> Vec<u8> {
+        let mut data = self.as_slice()[..Self::STORED_SIZE].to_vec();
+        Self::encrypt_raw(&mut data);
+        data
+    }
 }
@@ -21,6 +21,12 @@ pub trait Reader {
         let _ = Read::read(&mut cursor, &mut buf);
         buf
     }
+
+    /// Reads bit `index` of a little endian bitfield starting at `offset`.
+    fn read_flag(&self, offset: u64, index: usize) -> bool {
+        let byte = self.read::<u8>(offset + (index >> 3) as u64);
+        (byte >> (index & 7)) & 1 == 1
+    }
 }
 
 impl<T> Reader for T
@@ -34,8 +40,8 @@ where
 
 #[macro_export]
 macro_rules! impl_read_prop {
-    ($name:ident : $type:ty = $address:expr) => {
-        fn $name(&self) -> $type {
+    ($vis:vis $name:ident : $type:ty = $address:expr) => {
+        $vis fn $name(&self) -> $type {
             self.read($address)
         }
     };
@@ -0,0 +1,73 @@
+const MULT: u64 = 0x5d588b656c078965;
+const ADD: u64 = 0x269ec3;
+const REVERSE_MULT: u64 = 0xdedcedae9638806d;
+const REVERSE_ADD: u64 = 0x9b1ae6e9a384e6f9;
+
+/// The 64-bit linear congruential generator Gen5 games draw PIDs and most
+/// other values from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BWRNG {
+    seed: u64,
+}
+
+impl BWRNG {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_mul(MULT).wrapping_add(ADD);
+        self.seed
+    }
+
+    /// The upper half of the next seed, which is what the games use.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// A number below `max`, scaled from the upper half of the next seed.
+    pub fn next_int(&mut self, max: u32) -> u32 {
+        ((self.next_u32() as u64 * max as u64) >> 32) as u32
+    }
+
+    /// Steps back to the seed before the current one.
+    pub fn prev_u64(&mut self) -> u64 {
+        self.seed = self
+            .seed
+            .wrapping_mul(REVERSE_MULT)
+            .wrapping_add(REVERSE_ADD);
+        self.seed
+    }
+
+    pub fn advance(&mut self, advances: u32) {
+        for _ in 0..advances {
+            self.next_u64();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_generate_numbers() {
+        let mut rng = BWRNG::new(0);
+        assert_eq!(rng.next_u64(), 0x269ec3);
+        assert_eq!(rng.next_u64(), 0x7188d00c55ae9cb2);
+        assert_eq!(rng.next_int(u32::MAX), 0xaf528d2);
+    }
+
+    #[test]
+    fn should_step_back() {
+        let mut rng = BWRNG::new(0x1234_5678_9abc_def0);
+        rng.advance(3);
+        rng.prev_u64();
+        rng.prev_u64();
+        assert_eq!(rng.prev_u64(), 0x1234_5678_9abc_def0);
+    }
+}
@@ -0,0 +1,69 @@
+const MULT: u32 = 0x41c64e6d;
+const ADD: u32 = 0x6073;
+const REVERSE_MULT: u32 = 0xeeb9eb65;
+const REVERSE_ADD: u32 = 0x0a3561a1;
+
+/// The linear congruential generator Gen3-5 games use for PIDs and IVs, and
+/// every game since uses to encrypt Pokemon data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LCRNG {
+    seed: u32,
+}
+
+impl LCRNG {
+    pub fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        self.seed = self.seed.wrapping_mul(MULT).wrapping_add(ADD);
+        self.seed
+    }
+
+    /// The upper half of the next seed, which is what the games use.
+    pub fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    /// Steps back to the seed before the current one.
+    pub fn prev_u32(&mut self) -> u32 {
+        self.seed = self
+            .seed
+            .wrapping_mul(REVERSE_MULT)
+            .wrapping_add(REVERSE_ADD);
+        self.seed
+    }
+
+    pub fn advance(&mut self, advances: u32) {
+        for _ in 0..advances {
+            self.next_u32();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_generate_numbers() {
+        let mut rng = LCRNG::new(0);
+        assert_eq!(rng.next_u32(), 0x6073);
+        assert_eq!(rng.next_u32(), 0xe97e7b6a);
+        assert_eq!(rng.next_u16(), 0x5271);
+    }
+
+    #[test]
+    fn should_step_back() {
+        let mut rng = LCRNG::new(0x1234_5678);
+        rng.advance(10);
+        for _ in 0..10 {
+            rng.prev_u32();
+        }
+        assert_eq!(rng.seed(), 0x1234_5678);
+    }
+}
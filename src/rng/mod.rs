@@ -0,0 +1,19 @@
+mod bwrng;
+mod lcrng;
+mod mt;
+mod pid_iv;
+mod raid8;
+mod raid9;
+mod sfmt;
+mod tinymt;
+mod xoroshiro;
+
+pub use bwrng::*;
+pub use lcrng::*;
+pub use mt::*;
+pub use pid_iv::*;
+pub use raid8::*;
+pub use raid9::*;
+pub use sfmt::*;
+pub use tinymt::*;
+pub use xoroshiro::*;
@@ -0,0 +1,69 @@
+const SIZE: usize = 624;
+const PERIOD: usize = 397;
+const MATRIX_A: u32 = 0x9908b0df;
+const UPPER_MASK: u32 = 0x8000_0000;
+const LOWER_MASK: u32 = 0x7fff_ffff;
+
+/// The 32-bit Mersenne Twister Gen5 games draw IVs from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MT19937 {
+    state: [u32; SIZE],
+    index: usize,
+}
+
+impl MT19937 {
+    pub fn new(seed: u32) -> Self {
+        let mut state = [0; SIZE];
+        state[0] = seed;
+        for index in 1..SIZE {
+            let prev = state[index - 1];
+            state[index] = 1812433253u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(index as u32);
+        }
+
+        Self { state, index: SIZE }
+    }
+
+    fn twist(&mut self) {
+        for index in 0..SIZE {
+            let y =
+                (self.state[index] & UPPER_MASK) | (self.state[(index + 1) % SIZE] & LOWER_MASK);
+            let mag = if y & 1 == 1 { MATRIX_A } else { 0 };
+            self.state[index] = self.state[(index + PERIOD) % SIZE] ^ (y >> 1) ^ mag;
+        }
+        self.index = 0;
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        if self.index >= SIZE {
+            self.twist();
+        }
+
+        let mut y = self.state[self.index];
+        self.index += 1;
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c5680;
+        y ^= (y << 15) & 0xefc60000;
+        y ^ (y >> 18)
+    }
+
+    pub fn advance(&mut self, advances: u32) {
+        for _ in 0..advances {
+            self.next_u32();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_generate_numbers() {
+        let mut rng = MT19937::new(5489);
+        assert_eq!(rng.next_u32(), 3499211612);
+        assert_eq!(rng.next_u32(), 581869302);
+        assert_eq!(rng.next_u32(), 3890346734);
+    }
+}
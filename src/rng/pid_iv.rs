@@ -0,0 +1,336 @@
+use super::{BWRNG, LCRNG, MT19937};
+use alloc::vec::Vec;
+
+/// How many rejected PIDs a Method J or K search walks back through before
+/// giving up.  Each PID has a 1 in 25 chance of being kept, so this is never
+/// reached in practice.
+const MAX_REJECTED_PIDS: usize = 1000;
+
+/// A pattern Gen3 and Gen4 games use to draw a Pokemon's PID and IVs from the
+/// LCRNG.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PidIvMethod {
+    /// The PID and then the IVs, used by most fixed encounters.
+    Method1,
+    /// A skipped call between the PID and the IVs, used by Gen3 wild
+    /// encounters.
+    Method2,
+    /// A skipped call between the two halves of the IVs.
+    Method4,
+    /// Diamond, Pearl and Platinum wild encounters.  A nature is drawn first
+    /// and PIDs are drawn until one has it, followed by Method 1 IVs.
+    MethodJ,
+    /// HeartGold and SoulSilver wild encounters, which are like Method J but
+    /// take the nature as a remainder instead of a quotient.
+    MethodK,
+}
+
+/// A seed and the method that generate a PID and IVs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PidIvSeed {
+    pub method: PidIvMethod,
+    pub seed: u32,
+}
+
+fn draw_pid(rng: &mut LCRNG) -> u32 {
+    let low = rng.next_u16() as u32;
+    let high = rng.next_u16() as u32;
+    (high << 16) | low
+}
+
+/// Splits two IV halves into IVs in HP, Atk, Def, Spe, SpA, SpD order.
+fn split_ivs(iv1: u16, iv2: u16) -> [u8; 6] {
+    let iv = |half: u16, shift: u16| ((half >> shift) & 0x1f) as u8;
+    [
+        iv(iv1, 0),
+        iv(iv1, 5),
+        iv(iv1, 10),
+        iv(iv2, 0),
+        iv(iv2, 5),
+        iv(iv2, 10),
+    ]
+}
+
+/// Joins IVs in HP, Atk, Def, Spe, SpA, SpD order into the two halves the
+/// games draw.
+fn join_ivs(ivs: [u8; 6]) -> (u16, u16) {
+    let [hp, atk, def, spe, spa, spd] = ivs.map(|iv| (iv & 0x1f) as u16);
+    (
+        hp | (atk << 5) | (def << 10),
+        spe | (spa << 5) | (spd << 10),
+    )
+}
+
+impl PidIvMethod {
+    /// The nature a Method J or K nature call draws.
+    fn nature(&self, value: u16) -> Option<u32> {
+        match self {
+            Self::MethodJ => Some((value / 0xa3e) as u32),
+            Self::MethodK => Some((value % 25) as u32),
+            _ => None,
+        }
+    }
+
+    /// Generates the PID and IVs `seed` gives, with IVs in HP, Atk, Def, Spe,
+    /// SpA, SpD order.
+    pub fn generate(&self, seed: u32) -> (u32, [u8; 6]) {
+        let mut rng = LCRNG::new(seed);
+        let pid = match self {
+            Self::MethodJ | Self::MethodK => {
+                let nature = self.nature(rng.next_u16());
+                loop {
+                    let pid = draw_pid(&mut rng);
+                    if Some(pid % 25) == nature {
+                        break pid;
+                    }
+                }
+            }
+            _ => draw_pid(&mut rng),
+        };
+
+        if *self == Self::Method2 {
+            rng.next_u32();
+        }
+        let iv1 = rng.next_u16();
+        if *self == Self::Method4 {
+            rng.next_u32();
+        }
+        let iv2 = rng.next_u16();
+
+        (pid, split_ivs(iv1, iv2))
+    }
+
+    /// Whether the calls after the PID give `ivs`.
+    fn matches_ivs(&self, mut rng: LCRNG, ivs: (u16, u16)) -> bool {
+        if *self == Self::Method2 {
+            rng.next_u32();
+        }
+        if rng.next_u16() & 0x7fff != ivs.0 {
+            return false;
+        }
+        if *self == Self::Method4 {
+            rng.next_u32();
+        }
+        rng.next_u16() & 0x7fff == ivs.1
+    }
+
+    /// Walks back from the call before a Method 1 PID through PIDs the game
+    /// would have rejected, collecting the seeds of calls that could have
+    /// drawn the nature.
+    fn nature_seeds(&self, before_pid: u32, pid: u32, seeds: &mut Vec<PidIvSeed>) {
+        let nature = pid % 25;
+        let mut rng = LCRNG::new(before_pid);
+        for _ in 0..MAX_REJECTED_PIDS {
+            let high = (rng.seed() >> 16) as u16;
+            if self.nature(high) == Some(nature) {
+                let mut nature_call = rng;
+                seeds.push(PidIvSeed {
+                    method: *self,
+                    seed: nature_call.prev_u32(),
+                });
+            }
+
+            // Or the call drew the upper half of an earlier PID, which
+            // must have had the wrong nature for the game to draw again.
+            let low = rng.prev_u32() >> 16;
+            let earlier_pid = ((high as u32) << 16) | low;
+            if earlier_pid % 25 == nature {
+                return;
+            }
+            rng.prev_u32();
+        }
+    }
+}
+
+/// Finds the seeds that generate `pid` and `ivs` with the Gen3 and Gen4
+/// methods, with IVs in HP, Atk, Def, Spe, SpA, SpD order.
+///
+/// The lower half of the PID is the upper half of the first seed drawn, so
+/// only the other 16 bits have to be searched.  Method J and K seeds are found
+/// by walking back from the Method 1 seeds.
+///
+/// Gen5 seeds are searched with [find_gen5_frames] instead.  Encounters
+/// affected by a Synchronize or Cute Charm lead aren't searched.
+pub fn find_pid_iv_seeds(pid: u32, ivs: [u8; 6]) -> Vec<PidIvSeed> {
+    let ivs = join_ivs(ivs);
+    let mut seeds = Vec::new();
+
+    for lower in 0..=0xffff {
+        let first = ((pid & 0xffff) << 16) | lower;
+        let mut rng = LCRNG::new(first);
+        if rng.next_u16() as u32 != pid >> 16 {
+            continue;
+        }
+
+        let before_pid = LCRNG::new(first).prev_u32();
+        for method in [
+            PidIvMethod::Method1,
+            PidIvMethod::Method2,
+            PidIvMethod::Method4,
+        ] {
+            if !method.matches_ivs(rng, ivs) {
+                continue;
+            }
+
+            seeds.push(PidIvSeed {
+                method,
+                seed: before_pid,
+            });
+            if method == PidIvMethod::Method1 {
+                PidIvMethod::MethodJ.nature_seeds(before_pid, pid, &mut seeds);
+                PidIvMethod::MethodK.nature_seeds(before_pid, pid, &mut seeds);
+            }
+        }
+    }
+
+    seeds
+}
+
+/// PID bits Gen5 wild encounters overwrite after drawing the PID: the ability
+/// bit is flipped and the top bit is set from the trainer IDs.
+const GEN5_ADJUSTED_PID_BITS: u32 = 0x8001_0000;
+
+/// Where a Gen5 Pokemon's PID and IVs were drawn, counted in calls from the
+/// initial seed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Gen5Frame {
+    pub pid_advances: u32,
+    pub iv_advances: u32,
+}
+
+/// Draws a Gen5 PID, which the games scale from the full 32-bit range rather
+/// than taking the call as is.
+pub fn gen5_pid(rng: &mut BWRNG) -> u32 {
+    rng.next_int(u32::MAX)
+}
+
+/// Draws Gen5 IVs in HP, Atk, Def, Spe, SpA, SpD order, each from the top five
+/// bits of a call.
+pub fn gen5_ivs(rng: &mut MT19937) -> [u8; 6] {
+    core::array::from_fn(|_| (rng.next_u32() >> 27) as u8)
+}
+
+/// Finds the calls within `max_advances` of a Gen5 initial seed that draw
+/// `pid` and `ivs`, with IVs in HP, Atk, Def, Spe, SpA, SpD order.
+///
+/// The PID generator is seeded with the whole initial seed and the IV
+/// generator with its upper half.  The initial seed comes from the date, time
+/// and console, so unlike Gen3 and Gen4 it can't be found from the PID and IVs
+/// and has to be known already.  The bits wild encounters overwrite aren't
+/// compared.
+pub fn find_gen5_frames(
+    initial_seed: u64,
+    pid: u32,
+    ivs: [u8; 6],
+    max_advances: u32,
+) -> Vec<Gen5Frame> {
+    let mut rng = BWRNG::new(initial_seed);
+    let pid_advances: Vec<u32> = (0..=max_advances)
+        .filter(|_| (gen5_pid(&mut rng) ^ pid) & !GEN5_ADJUSTED_PID_BITS == 0)
+        .collect();
+    if pid_advances.is_empty() {
+        return Vec::new();
+    }
+
+    let mut rng = MT19937::new((initial_seed >> 32) as u32);
+    let calls: Vec<u8> = (0..max_advances + 6)
+        .map(|_| (rng.next_u32() >> 27) as u8)
+        .collect();
+    let iv_advances = calls
+        .windows(6)
+        .enumerate()
+        .filter(|(_, window)| **window == ivs)
+        .map(|(advances, _)| advances as u32);
+
+    iv_advances
+        .flat_map(|iv_advances| {
+            pid_advances.iter().map(move |&pid_advances| Gen5Frame {
+                pid_advances,
+                iv_advances,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const METHODS: [PidIvMethod; 5] = [
+        PidIvMethod::Method1,
+        PidIvMethod::Method2,
+        PidIvMethod::Method4,
+        PidIvMethod::MethodJ,
+        PidIvMethod::MethodK,
+    ];
+
+    #[test]
+    fn should_generate_method1() {
+        assert_eq!(
+            PidIvMethod::Method1.generate(0),
+            (0xe97e0000, [17, 19, 20, 16, 13, 12])
+        );
+    }
+
+    #[test]
+    fn should_keep_natures_for_methods_j_and_k() {
+        let (pid, _) = PidIvMethod::MethodJ.generate(0x1234_5678);
+        let nature = LCRNG::new(0x1234_5678).next_u16() / 0xa3e;
+        assert_eq!(pid % 25, nature as u32);
+
+        let (pid, _) = PidIvMethod::MethodK.generate(0x1234_5678);
+        let nature = LCRNG::new(0x1234_5678).next_u16() % 25;
+        assert_eq!(pid % 25, nature as u32);
+    }
+
+    #[test]
+    fn should_find_seeds() {
+        for method in METHODS {
+            for seed in [0, 0x1234_5678, 0xdead_beef] {
+                let (pid, ivs) = method.generate(seed);
+                let seeds = find_pid_iv_seeds(pid, ivs);
+                assert!(
+                    seeds.contains(&PidIvSeed { method, seed }),
+                    "{method:?} {seed:#x}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn should_find_gen5_frames() {
+        let seed = 0x0123_4567_89ab_cdef;
+        let mut rng = BWRNG::new(seed);
+        rng.advance(7);
+        let pid = gen5_pid(&mut rng) ^ 0x1_0000;
+        let mut mt = MT19937::new(0x0123_4567);
+        mt.advance(3);
+        let ivs = gen5_ivs(&mut mt);
+
+        assert_eq!(
+            find_gen5_frames(seed, pid, ivs, 10),
+            [Gen5Frame {
+                pid_advances: 7,
+                iv_advances: 3
+            }]
+        );
+        assert_eq!(find_gen5_frames(seed, pid, ivs, 5), Vec::new());
+    }
+
+    #[test]
+    fn should_scale_gen5_pids() {
+        let mut rng = BWRNG::new(0x269ec3);
+        assert_eq!(gen5_pid(&mut rng), 0x7188d00b);
+        assert_eq!(
+            gen5_ivs(&mut MT19937::new(0x1234_5678)),
+            [24, 1, 20, 28, 22, 12]
+        );
+    }
+
+    #[test]
+    fn should_not_find_seeds_for_unrelated_ivs() {
+        let (pid, ivs) = PidIvMethod::Method1.generate(0x1234_5678);
+        let ivs = ivs.map(|iv| iv ^ 1);
+        assert_eq!(find_pid_iv_seeds(pid, ivs), Vec::new());
+    }
+}
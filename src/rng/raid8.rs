@@ -0,0 +1,440 @@
+use super::Xoroshiro128Plus;
+use crate::encounters::ShinyRule;
+use crate::personal::personal_info;
+use crate::pk8::Pk8;
+use crate::pkx::{Pkx, PkxMut};
+use crate::types::{Gender, GenderRatio, Species, TrainerInfo};
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+/// The parts of a Sword and Shield den or raid encounter that change what a
+/// seed generates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Raid8Encounter {
+    pub species: Species,
+    pub flawless_ivs: u8,
+    /// 0-2 for a fixed ability, 3 for a random first or second ability and 4
+    /// for any ability, including the hidden one.
+    pub ability_type: u8,
+    /// `Random` raids are shiny when the PID is shiny against a random
+    /// trainer ID.
+    pub shiny: ShinyRule,
+}
+
+/// What a raid seed generates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Raid8Pokemon {
+    pub seed: u64,
+    pub encryption_constant: u32,
+    pub pid: u32,
+    /// IVs in HP, Atk, Def, Spe, SpA, SpD order.
+    pub ivs: [u8; 6],
+    pub ability_number: u8,
+    pub gender: Gender,
+    pub nature: u8,
+    pub height_scalar: u8,
+    pub weight_scalar: u8,
+}
+
+impl Raid8Pokemon {
+    /// Sets the generated fields on `pk8`, leaving everything else, like the
+    /// species and the trainer, to the caller.
+    pub fn apply_to(&self, pk8: &mut Pk8) {
+        pk8.set_encryption_constant(self.encryption_constant);
+        pk8.set_pid(self.pid);
+
+        let [hp, atk, def, spe, spa, spd] = self.ivs;
+        pk8.set_iv_hp(hp);
+        pk8.set_iv_atk(atk);
+        pk8.set_iv_def(def);
+        pk8.set_iv_spe(spe);
+        pk8.set_iv_spa(spa);
+        pk8.set_iv_spd(spd);
+
+        pk8.set_ability_number(self.ability_number);
+        if let Some(ability) = personal_info(pk8.species_t(), pk8.form())
+            .and_then(|info| info.ability(self.ability_number))
+        {
+            pk8.set_ability(ability as u16);
+        }
+
+        pk8.set_gender(self.gender as u8);
+        pk8.set_nature(self.nature);
+        pk8.set_minted_nature(self.nature);
+        pk8.set_height_scalar(self.height_scalar);
+        pk8.set_weight_scalar(self.weight_scalar);
+        pk8.refresh_checksum();
+    }
+}
+
+fn shiny_xor(pid: u32, tid_sid: u32) -> u32 {
+    (pid >> 16) ^ (pid & 0xffff) ^ (tid_sid >> 16) ^ (tid_sid & 0xffff)
+}
+
+/// Makes the drawn PID shiny or not for `trainer`, the way Gen8 and Gen9
+/// raids do.
+pub(crate) fn raid_pid(
+    pid: u32,
+    fake_tid_sid: u32,
+    shiny: ShinyRule,
+    trainer: &TrainerInfo,
+) -> u32 {
+    let fake_xor = shiny_xor(pid, fake_tid_sid);
+    let tid_sid = ((trainer.sid16 as u32) << 16) | trainer.tid16 as u32;
+    let is_shiny = match shiny {
+        ShinyRule::Random => fake_xor < 16,
+        ShinyRule::Never => false,
+        ShinyRule::Always => true,
+    };
+
+    if is_shiny {
+        // Square shines only come from an exact match on the random trainer.
+        let xor = u32::from(fake_xor != 0);
+        let high = (trainer.tid16 ^ trainer.sid16) as u32 ^ (pid & 0xffff) ^ xor;
+        (high << 16) | (pid & 0xffff)
+    } else if shiny_xor(pid, tid_sid) < 16 {
+        pid ^ 0x1000_0000
+    } else {
+        pid
+    }
+}
+
+/// Whether `pid` could come from the drawn PID.  Shiny PIDs only keep their
+/// lower half, and the others may have been flipped to keep them from being
+/// shiny.
+pub(crate) fn pid_matches(drawn: u32, pid: u32, is_shiny: bool) -> bool {
+    match is_shiny {
+        true => drawn & 0xffff == pid & 0xffff,
+        false => drawn == pid || drawn ^ 0x1000_0000 == pid,
+    }
+}
+
+pub(crate) fn pkx_ivs<T: Pkx>(pkx: &T) -> [u8; 6] {
+    [
+        pkx.iv_hp(),
+        pkx.iv_atk(),
+        pkx.iv_def(),
+        pkx.iv_spe(),
+        pkx.iv_spa(),
+        pkx.iv_spd(),
+    ]
+}
+
+/// The game rolls IVs in HP, Atk, Def, SpA, SpD, Spe order.
+pub(crate) fn roll_ivs(rng: &mut Xoroshiro128Plus, flawless_ivs: u8) -> [u8; 6] {
+    let mut ivs = [None; 6];
+    for _ in 0..flawless_ivs.min(6) {
+        loop {
+            let stat = rng.next_int(6) as usize;
+            if ivs[stat].is_none() {
+                ivs[stat] = Some(31);
+                break;
+            }
+        }
+    }
+
+    let [hp, atk, def, spa, spd, spe] = ivs.map(|iv| iv.unwrap_or_else(|| rng.next_int(32) as u8));
+    [hp, atk, def, spe, spa, spd]
+}
+
+pub(crate) fn roll_ability_number(rng: &mut Xoroshiro128Plus, ability_type: u8) -> u8 {
+    match ability_type {
+        ability_type @ 0..=2 => 1 << ability_type,
+        3 => 1 << rng.next_int(2),
+        _ => 1 << rng.next_int(3),
+    }
+}
+
+/// Sizes lean towards the middle since they're the sum of two rolls.
+pub(crate) fn roll_scalar(rng: &mut Xoroshiro128Plus) -> u8 {
+    (rng.next_int(0x81) + rng.next_int(0x80)) as u8
+}
+
+fn random_gender(ratio: GenderRatio, rng: &mut Xoroshiro128Plus) -> Gender {
+    let threshold = match ratio {
+        GenderRatio::Genderless => return Gender::Genderless,
+        GenderRatio::MaleOnly => return Gender::Male,
+        GenderRatio::FemaleOnly => return Gender::Female,
+        GenderRatio::OneToSeven => 31,
+        GenderRatio::OneToThree => 63,
+        GenderRatio::OneToOne => 127,
+        GenderRatio::ThreeToOne => 191,
+        GenderRatio::SevenToOne => 225,
+    };
+
+    if rng.next_int(253) + 1 < threshold {
+        Gender::Female
+    } else {
+        Gender::Male
+    }
+}
+
+impl Raid8Encounter {
+    /// Generates the Pokemon `seed` gives `trainer`.  Toxtricity picks its
+    /// nature from a list that depends on its form, which isn't handled.
+    pub fn generate(&self, seed: u64, trainer: &TrainerInfo) -> Raid8Pokemon {
+        let mut rng = Xoroshiro128Plus::new(seed);
+        let encryption_constant = rng.next_int(0xffffffff) as u32;
+        let fake_tid_sid = rng.next_int(0xffffffff) as u32;
+        let pid = raid_pid(
+            rng.next_int(0xffffffff) as u32,
+            fake_tid_sid,
+            self.shiny,
+            trainer,
+        );
+
+        let ivs = roll_ivs(&mut rng, self.flawless_ivs);
+
+        let ability_number = roll_ability_number(&mut rng, self.ability_type);
+
+        let gender = random_gender(self.species.get_gender_ratio(), &mut rng);
+        let nature = rng.next_int(25) as u8;
+        let height_scalar = roll_scalar(&mut rng);
+        let weight_scalar = roll_scalar(&mut rng);
+
+        Raid8Pokemon {
+            seed,
+            encryption_constant,
+            pid,
+            ivs,
+            ability_number,
+            gender,
+            nature,
+            height_scalar,
+            weight_scalar,
+        }
+    }
+}
+
+/// Linear equations over the upper half of a seed, kept in echelon form with
+/// one row per pivot bit.
+#[derive(Clone, Copy)]
+struct Equations {
+    rows: [(u32, u32); 32],
+    pivots: u32,
+}
+
+impl Equations {
+    /// Adds `mask · x = value`, returning `None` if it contradicts the others.
+    fn add(mut self, mut mask: u32, mut value: u32) -> Option<Self> {
+        while mask != 0 {
+            let pivot = 31 - mask.leading_zeros() as usize;
+            if self.pivots & (1 << pivot) == 0 {
+                self.rows[pivot] = (mask, value);
+                self.pivots |= 1 << pivot;
+                return Some(self);
+            }
+
+            mask ^= self.rows[pivot].0;
+            value ^= self.rows[pivot].1;
+        }
+
+        (value == 0).then_some(self)
+    }
+
+    /// Every solution, trying both values of each bit without a pivot.
+    fn solutions(&self) -> Vec<u32> {
+        let free_bits = 32 - self.pivots.count_ones();
+        (0..1u64 << free_bits)
+            .map(|mut choice| {
+                let mut x = 0u32;
+                for bit in 0..32 {
+                    let value = if self.pivots & (1 << bit) == 0 {
+                        let value = choice as u32 & 1;
+                        choice >>= 1;
+                        value
+                    } else {
+                        // Rows only use their pivot and the bits below it, which are solved.
+                        let (mask, value) = self.rows[bit];
+                        (value ^ (mask & x).count_ones()) & 1
+                    };
+                    x |= value << bit;
+                }
+                x
+            })
+            .collect()
+    }
+}
+
+/// The low halves of both state words once the EC and trainer ID are drawn.
+fn pid_state(seed: u64) -> (u32, u32) {
+    let mut rng = Xoroshiro128Plus::new(seed);
+    rng.advance(2);
+    let [s0, s1] = rng.state();
+    (s0 as u32, s1 as u32)
+}
+
+/// Finds the seeds for the upper half of a seed, given its lower half and
+/// the lowest `bits` of the PID it draws.
+///
+/// The state is linear in the seed, and the PID is the sum of both state
+/// words.  Working up from the lowest bit, each PID bit and carry leaves two
+/// choices for the state bits, each of which is one more equation on the
+/// seed, so contradicting choices are dropped early.
+fn search_upper_halves(lower: u32, pid: u32, bits: usize) -> Vec<u32> {
+    let base = pid_state(lower as u64);
+    let columns: [(u32, u32); 32] = core::array::from_fn(|bit| {
+        let (s0, s1) = pid_state(((1u64 << bit) << 32) | lower as u64);
+        (s0 ^ base.0, s1 ^ base.1)
+    });
+    let row = |select: fn(&(u32, u32)) -> u32, bit: usize| {
+        columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| (select(column) >> bit) & 1 == 1)
+            .fold(0u32, |mask, (index, _)| mask | (1 << index))
+    };
+
+    let mut results = Vec::new();
+    let mut pending = Vec::from([(
+        0,
+        0,
+        Equations {
+            rows: [(0, 0); 32],
+            pivots: 0,
+        },
+    )]);
+    while let Some((bit, carry, equations)) = pending.pop() {
+        if bit == bits {
+            results.extend(equations.solutions());
+            continue;
+        }
+
+        for s0_bit in 0..2 {
+            let s1_bit = ((pid >> bit) & 1) ^ s0_bit ^ carry;
+            let next = equations
+                .add(row(|c| c.0, bit), s0_bit ^ ((base.0 >> bit) & 1))
+                .and_then(|e| e.add(row(|c| c.1, bit), s1_bit ^ ((base.1 >> bit) & 1)));
+            if let Some(next) = next {
+                let next_carry = (s0_bit & s1_bit) | (carry & (s0_bit | s1_bit));
+                pending.push((bit + 1, next_carry, next));
+            }
+        }
+    }
+    results
+}
+
+/// Finds the raid seeds that could have generated `pkm`'s EC, PID and IVs,
+/// for any number of guaranteed perfect IVs.
+///
+/// The first number drawn is the EC, so it gives the lower half of the seed.
+/// The upper half is solved for from the PID, only using its lower half when
+/// the Pokemon is shiny since the game rewrites the rest.
+pub fn find_raid8_seeds<T: Pkx>(pkm: &T) -> Vec<u64> {
+    raid8_seeds(pkm, 1..=5)
+}
+
+pub(crate) fn raid8_seeds<T: Pkx>(pkm: &T, flawless_ivs: RangeInclusive<u8>) -> Vec<u64> {
+    let lower = pkm
+        .encryption_constant()
+        .wrapping_sub(Xoroshiro128Plus::SEED_CONSTANT as u32);
+    let pid = pkm.pid();
+    let is_shiny = pkm.shiny_xor() < 16;
+    let targets = match is_shiny {
+        true => Vec::from([(pid & 0xffff, 16)]),
+        false => Vec::from([(pid, 32), (pid ^ 0x1000_0000, 32)]),
+    };
+    let ivs = pkx_ivs(pkm);
+
+    let mut seeds: Vec<u64> = targets
+        .into_iter()
+        .flat_map(|(pid, bits)| search_upper_halves(lower, pid, bits))
+        .map(|upper| ((upper as u64) << 32) | lower as u64)
+        .filter(|seed| {
+            let mut rng = Xoroshiro128Plus::new(*seed);
+            let encryption_constant = rng.next_int(0xffffffff) as u32;
+            rng.next_int(0xffffffff);
+            let drawn_pid = rng.next_int(0xffffffff) as u32;
+
+            encryption_constant == pkm.encryption_constant()
+                && pid_matches(drawn_pid, pid, is_shiny)
+                && flawless_ivs
+                    .clone()
+                    .any(|flawless| roll_ivs(&mut rng.clone(), flawless) == ivs)
+        })
+        .collect();
+    seeds.sort_unstable();
+    seeds.dedup();
+    seeds
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::GameVersion;
+    use alloc::string::ToString;
+
+    fn trainer() -> TrainerInfo {
+        TrainerInfo {
+            ot_name: "Gloria".to_string(),
+            tid16: 12345,
+            sid16: 54321,
+            gender: 1,
+            language: 2,
+            version: GameVersion::Sword,
+        }
+    }
+
+    fn raid(shiny: ShinyRule) -> Raid8Encounter {
+        Raid8Encounter {
+            species: Species::Eevee,
+            flawless_ivs: 4,
+            ability_type: 4,
+            shiny,
+        }
+    }
+
+    fn raid_pk8(raid: &Raid8Encounter, seed: u64) -> Pk8 {
+        let trainer = trainer();
+        let mut pk8 = Pk8::default();
+        pk8.set_species(raid.species as u16);
+        pk8.set_tid16(trainer.tid16);
+        pk8.set_sid16(trainer.sid16);
+        raid.generate(seed, &trainer).apply_to(&mut pk8);
+        pk8
+    }
+
+    #[test]
+    fn should_generate_raids() {
+        let raid = raid(ShinyRule::Random);
+        let pokemon = raid.generate(0x0123_4567_89ab_cdef, &trainer());
+        let mut rng = Xoroshiro128Plus::new(0x0123_4567_89ab_cdef);
+        assert_eq!(pokemon.encryption_constant, rng.next_u64() as u32);
+        assert!(pokemon.ivs.iter().filter(|iv| **iv == 31).count() >= 4);
+
+        let pk8 = raid_pk8(&raid, 0x0123_4567_89ab_cdef);
+        assert!(pk8.is_valid());
+        assert_eq!(pk8.encryption_constant(), pokemon.encryption_constant);
+        assert_eq!(pk8.ability_number(), pokemon.ability_number);
+        assert_eq!(pk8.height_scalar(), pokemon.height_scalar);
+    }
+
+    #[test]
+    fn should_force_shininess() {
+        let pk8 = raid_pk8(&raid(ShinyRule::Always), 0x1111_2222_3333_4444);
+        assert!(pk8.is_shiny());
+
+        let pk8 = raid_pk8(&raid(ShinyRule::Never), 0x1111_2222_3333_4444);
+        assert!(!pk8.is_shiny());
+    }
+
+    #[test]
+    fn should_find_raid_seeds() {
+        for seed in [
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+            0xb7e1_5162_8aed_2a6a,
+        ] {
+            let pk8 = raid_pk8(&raid(ShinyRule::Random), seed);
+            assert!(find_raid8_seeds(&pk8).contains(&seed));
+        }
+    }
+
+    #[test]
+    fn should_find_shiny_raid_seeds() {
+        let seed = 0x1111_2222_3333_4444;
+        let pk8 = raid_pk8(&raid(ShinyRule::Always), seed);
+        // Only half of a shiny PID is random, so some other seeds match too.
+        assert!(find_raid8_seeds(&pk8).contains(&seed));
+    }
+}
@@ -0,0 +1,443 @@
+use super::raid8::{pid_matches, pkx_ivs, raid_pid, roll_ability_number, roll_ivs, roll_scalar};
+use super::Xoroshiro128Plus;
+use crate::encounters::ShinyRule;
+use crate::personal::personal_info;
+use crate::pk9::Pk9;
+use crate::pkx::{Pkx, PkxMut};
+use crate::types::{Gender, GenderRatio, Species, TeraType, TrainerInfo};
+use core::ops::RangeInclusive;
+
+/// Where a Tera raid's Tera Type comes from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RaidTeraType {
+    /// The species' primary type.
+    Default,
+    Random,
+    Fixed(TeraType),
+}
+
+/// How far a player is in Scarlet and Violet, which decides the star levels
+/// their raids can roll.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RaidProgress {
+    /// One and two star raids.
+    Start,
+    /// Up to three stars, after the first few badges.
+    ThreeStars,
+    /// Up to four stars, after most of the badges.
+    FourStars,
+    /// Three to five stars, after the credits.
+    FiveStars,
+    /// Three to five stars with more five star raids, once six star raids
+    /// are unlocked.
+    SixStars,
+}
+
+impl RaidProgress {
+    /// The star level a roll out of 100 gives a raid that isn't a six star
+    /// raid.
+    fn star_level(&self, roll: u64) -> u8 {
+        match self {
+            Self::Start => match roll {
+                81.. => 2,
+                _ => 1,
+            },
+            Self::ThreeStars => match roll {
+                71.. => 3,
+                31.. => 2,
+                _ => 1,
+            },
+            Self::FourStars => match roll {
+                71.. => 4,
+                41.. => 3,
+                21.. => 2,
+                _ => 1,
+            },
+            Self::FiveStars => match roll {
+                76.. => 5,
+                41.. => 4,
+                _ => 3,
+            },
+            Self::SixStars => match roll {
+                71.. => 5,
+                31.. => 4,
+                _ => 3,
+            },
+        }
+    }
+}
+
+/// An entry in a Scarlet and Violet Tera raid table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Raid9Encounter {
+    pub species: Species,
+    pub form: u8,
+    pub stars: u8,
+    /// How likely the encounter is against the others with its star level.
+    pub rate: u16,
+    pub tera_type: RaidTeraType,
+    pub flawless_ivs: u8,
+    /// 0-2 for a fixed ability, 3 for a random first or second ability and 4
+    /// for any ability, including the hidden one.
+    pub ability_type: u8,
+    pub shiny: ShinyRule,
+    /// The nature, or `None` for a random one.
+    pub nature: Option<u8>,
+    /// The scale, or `None` for a random one.
+    pub scale: Option<u8>,
+}
+
+/// What a Tera raid seed generates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Raid9Pokemon {
+    pub seed: u32,
+    pub species: Species,
+    pub form: u8,
+    pub tera_type: TeraType,
+    pub encryption_constant: u32,
+    pub pid: u32,
+    /// IVs in HP, Atk, Def, Spe, SpA, SpD order.
+    pub ivs: [u8; 6],
+    pub ability_number: u8,
+    pub gender: Gender,
+    pub nature: u8,
+    pub height_scalar: u8,
+    pub weight_scalar: u8,
+    pub scale: u8,
+}
+
+impl Raid9Pokemon {
+    /// Sets the generated fields on `pk9`, leaving everything else, like the
+    /// trainer and the met data, to the caller.
+    pub fn apply_to(&self, pk9: &mut Pk9) {
+        pk9.set_species(self.species as u16);
+        pk9.set_form(self.form);
+        pk9.set_encryption_constant(self.encryption_constant);
+        pk9.set_pid(self.pid);
+
+        let [hp, atk, def, spe, spa, spd] = self.ivs;
+        pk9.set_iv_hp(hp);
+        pk9.set_iv_atk(atk);
+        pk9.set_iv_def(def);
+        pk9.set_iv_spe(spe);
+        pk9.set_iv_spa(spa);
+        pk9.set_iv_spd(spd);
+
+        pk9.set_ability_number(self.ability_number);
+        if let Some(ability) = personal_info(self.species, self.form)
+            .and_then(|info| info.ability(self.ability_number))
+        {
+            pk9.set_ability(ability as u16);
+        }
+
+        pk9.set_gender(self.gender as u8);
+        pk9.set_nature(self.nature);
+        pk9.set_minted_nature(self.nature);
+        pk9.set_height_scalar(self.height_scalar);
+        pk9.set_weight_scalar(self.weight_scalar);
+        pk9.set_scale(self.scale);
+        pk9.set_tera_type_original(self.tera_type as u8);
+        pk9.set_tera_type_override(TeraType::OVERRIDE_NONE);
+        pk9.refresh_checksum();
+    }
+}
+
+/// Gen9 rolls genders as a percentage instead of against the ratio itself.
+fn random_gender(ratio: GenderRatio, rng: &mut Xoroshiro128Plus) -> Gender {
+    let threshold = match ratio {
+        GenderRatio::Genderless => return Gender::Genderless,
+        GenderRatio::MaleOnly => return Gender::Male,
+        GenderRatio::FemaleOnly => return Gender::Female,
+        GenderRatio::OneToSeven => 12,
+        GenderRatio::OneToThree => 25,
+        GenderRatio::OneToOne => 50,
+        GenderRatio::ThreeToOne => 75,
+        GenderRatio::SevenToOne => 89,
+    };
+
+    if rng.next_int(100) < threshold {
+        Gender::Female
+    } else {
+        Gender::Male
+    }
+}
+
+impl Raid9Encounter {
+    /// Generates the Pokemon `seed` gives `trainer`.
+    pub fn generate(&self, seed: u32, trainer: &TrainerInfo) -> Raid9Pokemon {
+        // The Tera Type is drawn from a generator of its own.
+        let tera_type = match self.tera_type {
+            RaidTeraType::Default => personal_info(self.species, self.form)
+                .map_or(TeraType::Normal, |info| info.types[0]),
+            RaidTeraType::Random => {
+                TeraType::from(Xoroshiro128Plus::new(seed as u64).next_int(18) as u8)
+            }
+            RaidTeraType::Fixed(tera_type) => tera_type,
+        };
+
+        let mut rng = Xoroshiro128Plus::new(seed as u64);
+        let encryption_constant = rng.next_int(0xffffffff) as u32;
+        let fake_tid_sid = rng.next_int(0xffffffff) as u32;
+        let pid = raid_pid(
+            rng.next_int(0xffffffff) as u32,
+            fake_tid_sid,
+            self.shiny,
+            trainer,
+        );
+
+        let ivs = roll_ivs(&mut rng, self.flawless_ivs);
+        let ability_number = roll_ability_number(&mut rng, self.ability_type);
+        let gender = random_gender(self.species.get_gender_ratio(), &mut rng);
+        let nature = match self.nature {
+            Some(nature) => nature,
+            None => rng.next_int(25) as u8,
+        };
+        let height_scalar = roll_scalar(&mut rng);
+        let weight_scalar = roll_scalar(&mut rng);
+        let scale = match self.scale {
+            Some(scale) => scale,
+            None => roll_scalar(&mut rng),
+        };
+
+        Raid9Pokemon {
+            seed,
+            species: self.species,
+            form: self.form,
+            tera_type,
+            encryption_constant,
+            pid,
+            ivs,
+            ability_number,
+            gender,
+            nature,
+            height_scalar,
+            weight_scalar,
+            scale,
+        }
+    }
+}
+
+/// The star level a raid seed rolls.  Six star raids come from their own
+/// dens and always have six stars.
+pub fn raid9_star_level(seed: u32, progress: RaidProgress, is_six_star: bool) -> u8 {
+    let roll = Xoroshiro128Plus::new(seed as u64).next_int(100);
+    match is_six_star {
+        true => 6,
+        false => progress.star_level(roll),
+    }
+}
+
+/// Picks the encounter `seed` lands on in a raid table.  The star level is
+/// rolled first, and the encounter is then rolled against the rates of the
+/// encounters with that star level.  Scarlet and Violet have their own rates,
+/// so `encounters` should be the table for the player's game.
+pub fn select_raid9_encounter(
+    seed: u32,
+    progress: RaidProgress,
+    is_six_star: bool,
+    encounters: &[Raid9Encounter],
+) -> Option<&Raid9Encounter> {
+    let stars = raid9_star_level(seed, progress, is_six_star);
+    let mut rng = Xoroshiro128Plus::new(seed as u64);
+    rng.next_int(100);
+
+    let candidates = || {
+        encounters
+            .iter()
+            .filter(move |encounter| encounter.stars == stars)
+    };
+    let total: u64 = candidates().map(|encounter| encounter.rate as u64).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rng.next_int(total);
+    candidates().find(|encounter| {
+        let rate = encounter.rate as u64;
+        if roll < rate {
+            return true;
+        }
+
+        roll -= rate;
+        false
+    })
+}
+
+/// Finds the raid seed that generated `pkm`, for any number of guaranteed
+/// perfect IVs.
+///
+/// Raid seeds are only 32 bits and the EC is the first number drawn, so the
+/// seed is the EC minus the lower half of the generator's fixed state.  The
+/// PID and IVs then confirm it.
+pub fn find_raid9_seed<T: Pkx>(pkm: &T) -> Option<u32> {
+    raid9_seed(pkm, 1..=6)
+}
+
+pub(crate) fn raid9_seed<T: Pkx>(pkm: &T, mut flawless_ivs: RangeInclusive<u8>) -> Option<u32> {
+    let seed = pkm
+        .encryption_constant()
+        .wrapping_sub(Xoroshiro128Plus::SEED_CONSTANT as u32);
+
+    let mut rng = Xoroshiro128Plus::new(seed as u64);
+    let encryption_constant = rng.next_int(0xffffffff) as u32;
+    rng.next_int(0xffffffff);
+    let drawn_pid = rng.next_int(0xffffffff) as u32;
+    let ivs = pkx_ivs(pkm);
+
+    let is_match = encryption_constant == pkm.encryption_constant()
+        && pid_matches(drawn_pid, pkm.pid(), pkm.shiny_xor() < 16)
+        && flawless_ivs.any(|flawless| roll_ivs(&mut rng.clone(), flawless) == ivs);
+    is_match.then_some(seed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::GameVersion;
+    use alloc::string::ToString;
+
+    fn trainer() -> TrainerInfo {
+        TrainerInfo {
+            ot_name: "Nemona".to_string(),
+            tid16: 4321,
+            sid16: 8765,
+            gender: 1,
+            language: 2,
+            version: GameVersion::Scarlet,
+        }
+    }
+
+    fn raid(tera_type: RaidTeraType, shiny: ShinyRule) -> Raid9Encounter {
+        Raid9Encounter {
+            species: Species::Eevee,
+            form: 0,
+            stars: 3,
+            rate: 10,
+            tera_type,
+            flawless_ivs: 4,
+            ability_type: 4,
+            shiny,
+            nature: None,
+            scale: None,
+        }
+    }
+
+    fn raid_pk9(raid: &Raid9Encounter, seed: u32) -> Pk9 {
+        let trainer = trainer();
+        let mut pk9 = Pk9::default();
+        pk9.set_tid16(trainer.tid16);
+        pk9.set_sid16(trainer.sid16);
+        raid.generate(seed, &trainer).apply_to(&mut pk9);
+        pk9
+    }
+
+    #[test]
+    fn should_generate_raids() {
+        let raid = raid(RaidTeraType::Random, ShinyRule::Random);
+        let pokemon = raid.generate(0x1234_5678, &trainer());
+        let mut rng = Xoroshiro128Plus::new(0x1234_5678);
+        let tera_roll = rng.next_int(18) as u8;
+        assert_eq!(pokemon.tera_type, TeraType::from(tera_roll));
+        assert_eq!(
+            pokemon.encryption_constant,
+            0x1234_5678u32.wrapping_add(0x229d_6a5b)
+        );
+        assert!(pokemon.ivs.iter().filter(|iv| **iv == 31).count() >= 4);
+
+        let pk9 = raid_pk9(&raid, 0x1234_5678);
+        assert!(pk9.is_valid());
+        assert_eq!(pk9.species_t(), Species::Eevee);
+        assert_eq!(pk9.tera_type(), TeraType::from(tera_roll));
+        assert_eq!(pk9.scale(), pokemon.scale);
+    }
+
+    #[test]
+    fn should_keep_fixed_values() {
+        let mut raid = raid(RaidTeraType::Fixed(TeraType::Dragon), ShinyRule::Always);
+        raid.nature = Some(3);
+        raid.scale = Some(255);
+        raid.ability_type = 2;
+
+        let pk9 = raid_pk9(&raid, 0x8765_4321);
+        assert!(pk9.is_shiny());
+        assert_eq!(pk9.tera_type(), TeraType::Dragon);
+        assert_eq!(pk9.nature(), 3);
+        assert_eq!(pk9.scale(), 255);
+        assert_eq!(pk9.ability_number(), 4);
+
+        let pokemon = raid.generate(0x8765_4321, &trainer());
+        raid.tera_type = RaidTeraType::Default;
+        raid.species = Species::Rookidee;
+        assert_eq!(
+            raid.generate(0x8765_4321, &trainer()).tera_type,
+            TeraType::Flying
+        );
+        assert_eq!(pokemon.nature, 3);
+    }
+
+    #[test]
+    fn should_roll_star_levels() {
+        let roll = Xoroshiro128Plus::new(0x1234_5678).next_int(100);
+        let stars = raid9_star_level(0x1234_5678, RaidProgress::FiveStars, false);
+        assert_eq!(stars, RaidProgress::FiveStars.star_level(roll));
+        assert!((3..=5).contains(&stars));
+        assert_eq!(raid9_star_level(0x1234_5678, RaidProgress::Start, true), 6);
+
+        assert_eq!(RaidProgress::Start.star_level(80), 1);
+        assert_eq!(RaidProgress::Start.star_level(81), 2);
+        assert_eq!(RaidProgress::FourStars.star_level(20), 1);
+        assert_eq!(RaidProgress::SixStars.star_level(99), 5);
+    }
+
+    #[test]
+    fn should_select_encounters_by_rate() {
+        let mut first = raid(RaidTeraType::Default, ShinyRule::Random);
+        first.stars = raid9_star_level(0x1234_5678, RaidProgress::SixStars, false);
+        first.rate = 1;
+        let mut second = first;
+        second.species = Species::Pikachu;
+        second.rate = 0xffff;
+        let mut six_star = second;
+        six_star.species = Species::Wooper;
+        six_star.stars = 6;
+
+        // The species roll comes after the star level roll.
+        let mut rng = Xoroshiro128Plus::new(0x1234_5678);
+        rng.next_int(100);
+        let roll = rng.next_int(0x10000);
+
+        let encounters = [six_star, first, second];
+        let selected =
+            select_raid9_encounter(0x1234_5678, RaidProgress::SixStars, false, &encounters);
+        let expected = if roll < 1 {
+            Species::Eevee
+        } else {
+            Species::Pikachu
+        };
+        assert_eq!(selected.map(|encounter| encounter.species), Some(expected));
+
+        let selected =
+            select_raid9_encounter(0x1234_5678, RaidProgress::SixStars, true, &encounters);
+        assert_eq!(
+            selected.map(|encounter| encounter.species),
+            Some(Species::Wooper)
+        );
+        assert_eq!(
+            select_raid9_encounter(0, RaidProgress::Start, false, &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn should_find_raid_seeds() {
+        for shiny in [ShinyRule::Random, ShinyRule::Never, ShinyRule::Always] {
+            let pk9 = raid_pk9(&raid(RaidTeraType::Random, shiny), 0xcafe_f00d);
+            assert_eq!(find_raid9_seed(&pk9), Some(0xcafe_f00d));
+        }
+
+        let mut pk9 = raid_pk9(&raid(RaidTeraType::Random, ShinyRule::Random), 0xcafe_f00d);
+        pk9.set_iv_hp(pk9.iv_hp() ^ 1);
+        pk9.set_iv_atk(pk9.iv_atk() ^ 1);
+        assert_eq!(find_raid9_seed(&pk9), None);
+    }
+}
@@ -0,0 +1,195 @@
+const N: usize = 156;
+const N32: usize = N * 4;
+const POS1: usize = 122;
+const SL1: u32 = 18;
+const SR1: u32 = 11;
+const MSK: [u32; 4] = [0xdfffffef, 0xddfecb7f, 0xbffaffff, 0xbffffff6];
+const PARITY: [u32; 4] = [0x00000001, 0x00000000, 0x00000000, 0x13c9e684];
+
+fn lanes(value: [u32; 4]) -> u128 {
+    value
+        .iter()
+        .rev()
+        .fold(0, |value, lane| (value << 32) | *lane as u128)
+}
+
+fn recursion(a: u128, b: u128, c: u128, d: u128) -> u128 {
+    // Shifting every lane in one go lets bits cross into the next lane, which
+    // the masks clear back out.
+    let b = (b >> SR1) & lanes(MSK.map(|mask| mask & (u32::MAX >> SR1)));
+    let d = (d << SL1) & lanes([u32::MAX << SL1; 4]);
+    a ^ (a << 8) ^ b ^ (c >> 8) ^ d
+}
+
+/// The SIMD-oriented Fast Mersenne Twister (SFMT19937) Gen7 games use.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SFMT {
+    state: [u128; N],
+    /// The next number to read, counted in 32-bit numbers.
+    index: usize,
+}
+
+impl SFMT {
+    /// The size of the state as bytes: the state words, then the index.
+    pub const STATE_SIZE: usize = N32 * 4 + 4;
+
+    pub fn new(seed: u32) -> Self {
+        let mut words = [0u32; N32];
+        words[0] = seed;
+        for index in 1..N32 {
+            let prev = words[index - 1];
+            words[index] = 1812433253u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(index as u32);
+        }
+
+        let inner = words
+            .iter()
+            .zip(PARITY)
+            .fold(0, |inner, (word, parity)| inner ^ (word & parity));
+        if inner.count_ones() & 1 == 0 {
+            // Flipping the lowest parity bit puts the state on the full period.
+            if let Some((word, parity)) = words
+                .iter_mut()
+                .zip(PARITY)
+                .find(|(_, parity)| *parity != 0)
+            {
+                *word ^= 1 << parity.trailing_zeros();
+            }
+        }
+
+        let mut rng = Self {
+            state: [0; N],
+            index: N32,
+        };
+        rng.set_words(&words);
+        rng
+    }
+
+    fn set_words(&mut self, words: &[u32; N32]) {
+        for (value, chunk) in self.state.iter_mut().zip(words.chunks(4)) {
+            *value = lanes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+    }
+
+    fn word(&self, index: usize) -> u32 {
+        (self.state[index / 4] >> ((index % 4) * 32)) as u32
+    }
+
+    pub fn from_bytes(data: &[u8; Self::STATE_SIZE]) -> Self {
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ])
+        };
+
+        let words = core::array::from_fn(|index| read_u32(index * 4));
+        let mut rng = Self {
+            state: [0; N],
+            index: (read_u32(N32 * 4) as usize).min(N32),
+        };
+        rng.set_words(&words);
+        rng
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::STATE_SIZE] {
+        let mut data = [0; Self::STATE_SIZE];
+        for index in 0..N32 {
+            data[index * 4..][..4].copy_from_slice(&self.word(index).to_le_bytes());
+        }
+        data[N32 * 4..].copy_from_slice(&(self.index as u32).to_le_bytes());
+        data
+    }
+
+    fn shuffle(&mut self) {
+        let mut r1 = self.state[N - 2];
+        let mut r2 = self.state[N - 1];
+        for index in 0..N {
+            let value = recursion(self.state[index], self.state[(index + POS1) % N], r1, r2);
+            self.state[index] = value;
+            r1 = r2;
+            r2 = value;
+        }
+        self.index = 0;
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        if self.index >= N32 {
+            self.shuffle();
+        }
+
+        let value = self.word(self.index);
+        self.index += 1;
+        value
+    }
+
+    /// The games read 64 bits at a time, low half first.
+    pub fn next_u64(&mut self) -> u64 {
+        let low = self.next_u32() as u64;
+        let high = self.next_u32() as u64;
+        (high << 32) | low
+    }
+
+    /// Skips `advances` 64-bit numbers one at a time.
+    pub fn advance(&mut self, advances: u32) {
+        for _ in 0..advances {
+            self.next_u64();
+        }
+    }
+
+    /// Skips `advances` 64-bit numbers without reading them, only regenerating
+    /// the state once per block of 312 numbers.
+    pub fn jump(&mut self, advances: u64) {
+        let mut index = self.index as u64 + advances * 2;
+        while index > N32 as u64 {
+            self.shuffle();
+            index -= N32 as u64;
+        }
+        self.index = index as usize;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_generate_reference_numbers() {
+        let mut rng = SFMT::new(1234);
+        assert_eq!(rng.next_u32(), 3440181298);
+        assert_eq!(rng.next_u32(), 1564997079);
+        assert_eq!(rng.next_u32(), 1510669302);
+        assert_eq!(rng.next_u32(), 2930277156);
+        assert_eq!(rng.next_u32(), 1452439940);
+    }
+
+    #[test]
+    fn should_read_64_bits_low_half_first() {
+        let mut rng = SFMT::new(1234);
+        assert_eq!(rng.next_u64(), (1564997079 << 32) | 3440181298);
+    }
+
+    #[test]
+    fn should_jump_like_it_advances() {
+        for advances in [0, 1, 311, 312, 313, 1000] {
+            let mut advanced = SFMT::new(0x1234_5678);
+            advanced.next_u64();
+            let mut jumped = advanced.clone();
+            advanced.advance(advances);
+            jumped.jump(advances as u64);
+            assert_eq!(jumped.next_u64(), advanced.next_u64());
+        }
+    }
+
+    #[test]
+    fn should_round_trip_state() {
+        let mut rng = SFMT::new(0xdead_beef);
+        rng.advance(500);
+        let mut restored = SFMT::from_bytes(&rng.to_bytes());
+        assert_eq!(restored, rng);
+        assert_eq!(restored.next_u64(), rng.next_u64());
+    }
+}
@@ -0,0 +1,176 @@
+const MAT1: u32 = 0x8f7011ee;
+const MAT2: u32 = 0xfc78ff1f;
+const TMAT: u32 = 0x3793fdff;
+const MASK: u32 = 0x7fffffff;
+
+fn next_state(state: &mut [u32; 4]) {
+    let mut x = (state[0] & MASK) ^ state[1] ^ state[2];
+    let mut y = state[3];
+    x ^= x << 1;
+    y ^= (y >> 1) ^ x;
+    state[0] = state[1];
+    state[1] = state[2];
+    state[2] = x ^ (y << 10);
+    state[3] = y;
+    if y & 1 == 1 {
+        state[1] ^= MAT1;
+        state[2] ^= MAT2;
+    }
+}
+
+fn to_u128(state: &[u32; 4]) -> u128 {
+    state
+        .iter()
+        .rev()
+        .fold(0, |value, word| (value << 32) | *word as u128)
+}
+
+fn from_u128(value: u128) -> [u32; 4] {
+    core::array::from_fn(|index| (value >> (index * 32)) as u32)
+}
+
+/// The state transition as a matrix over GF(2), one column per state bit.
+type Matrix = [u128; 128];
+
+fn apply(matrix: &Matrix, vector: u128) -> u128 {
+    (0..128)
+        .filter(|bit| (vector >> bit) & 1 == 1)
+        .fold(0, |value, bit| value ^ matrix[bit])
+}
+
+fn multiply(lhs: &Matrix, rhs: &Matrix) -> Matrix {
+    core::array::from_fn(|column| apply(lhs, rhs[column]))
+}
+
+/// The Tiny Mersenne Twister Gen6 games use, with the games' parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TinyMT {
+    state: [u32; 4],
+}
+
+impl TinyMT {
+    /// The size of the state the games keep in their saves.
+    pub const STATE_SIZE: usize = 0x10;
+
+    pub fn new(seed: u32) -> Self {
+        let mut state = [seed, MAT1, MAT2, TMAT];
+        for index in 1..8 {
+            let prev = state[(index - 1) & 3];
+            state[index & 3] ^= 1812433253u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(index as u32);
+        }
+
+        if state[0] & MASK == 0 && state[1..].iter().all(|word| *word == 0) {
+            state = [b'T', b'I', b'N', b'Y'].map(u32::from);
+        }
+
+        let mut rng = Self { state };
+        rng.advance(8);
+        rng
+    }
+
+    pub fn from_state(state: [u32; 4]) -> Self {
+        Self { state }
+    }
+
+    pub fn state(&self) -> [u32; 4] {
+        self.state
+    }
+
+    pub fn from_bytes(data: [u8; Self::STATE_SIZE]) -> Self {
+        Self::from_state(core::array::from_fn(|index| {
+            u32::from_le_bytes([
+                data[index * 4],
+                data[index * 4 + 1],
+                data[index * 4 + 2],
+                data[index * 4 + 3],
+            ])
+        }))
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::STATE_SIZE] {
+        let mut data = [0; Self::STATE_SIZE];
+        for (bytes, word) in data.chunks_mut(4).zip(self.state) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+        data
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        next_state(&mut self.state);
+
+        let t1 = self.state[0].wrapping_add(self.state[2] >> 8);
+        let mut t0 = self.state[3] ^ t1;
+        if t1 & 1 == 1 {
+            t0 ^= TMAT;
+        }
+        t0
+    }
+
+    /// Skips `advances` numbers one at a time.
+    pub fn advance(&mut self, advances: u32) {
+        for _ in 0..advances {
+            next_state(&mut self.state);
+        }
+    }
+
+    /// Skips `advances` numbers in logarithmic time.  The state transition is
+    /// linear, so it is raised to the power of `advances` as a matrix.
+    pub fn jump(&mut self, advances: u64) {
+        let mut step: Matrix = core::array::from_fn(|bit| {
+            let mut state = from_u128(1 << bit);
+            next_state(&mut state);
+            to_u128(&state)
+        });
+
+        let mut state = to_u128(&self.state);
+        let mut remaining = advances;
+        while remaining != 0 {
+            if remaining & 1 == 1 {
+                state = apply(&step, state);
+            }
+            remaining >>= 1;
+            if remaining != 0 {
+                step = multiply(&step, &step);
+            }
+        }
+
+        self.state = from_u128(state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_generate_reference_numbers() {
+        let mut rng = TinyMT::new(1);
+        assert_eq!(rng.next_u32(), 2545341989);
+        assert_eq!(rng.next_u32(), 981918433);
+        assert_eq!(rng.next_u32(), 3715302833);
+    }
+
+    #[test]
+    fn should_jump_like_it_advances() {
+        let mut advanced = TinyMT::new(0x1234_5678);
+        let mut jumped = advanced;
+        advanced.advance(12345);
+        jumped.jump(12345);
+        assert_eq!(jumped, advanced);
+        assert_eq!(jumped.next_u32(), advanced.next_u32());
+
+        jumped.jump(0);
+        assert_eq!(jumped, advanced);
+    }
+
+    #[test]
+    fn should_round_trip_state() {
+        let mut rng = TinyMT::new(0xdead_beef);
+        rng.advance(10);
+        let mut restored = TinyMT::from_bytes(rng.to_bytes());
+        assert_eq!(restored.state(), rng.state());
+        assert_eq!(restored.next_u32(), rng.next_u32());
+    }
+}
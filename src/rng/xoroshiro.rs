@@ -0,0 +1,88 @@
+/// The xoroshiro128+ generator Gen8 and later games use, seeded with the
+/// games' fixed second half of the state.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Xoroshiro128Plus {
+    state: [u64; 2],
+}
+
+impl Xoroshiro128Plus {
+    /// The second half of the state every seed starts with.
+    pub const SEED_CONSTANT: u64 = 0x82a2b175229d6a5b;
+
+    pub fn new(seed: u64) -> Self {
+        Self::from_state([seed, Self::SEED_CONSTANT])
+    }
+
+    pub fn from_state(state: [u64; 2]) -> Self {
+        Self { state }
+    }
+
+    pub fn state(&self) -> [u64; 2] {
+        self.state
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let [s0, s1] = self.state;
+        let result = s0.wrapping_add(s1);
+        let s1 = s1 ^ s0;
+        self.state = [s0.rotate_left(24) ^ s1 ^ (s1 << 16), s1.rotate_left(37)];
+        result
+    }
+
+    /// A number below `max`.  The games mask numbers to the smallest power of
+    /// two above `max` and draw again until one fits.  No number is below 0,
+    /// so a `max` of 0 gives 0 without advancing.
+    pub fn next_int(&mut self, max: u64) -> u64 {
+        if max == 0 {
+            return 0;
+        }
+
+        let mask = u64::MAX
+            .checked_shr(max.saturating_sub(1).leading_zeros())
+            .unwrap_or(0);
+        loop {
+            let value = self.next_u64() & mask;
+            if value < max {
+                return value;
+            }
+        }
+    }
+
+    pub fn advance(&mut self, advances: u32) {
+        for _ in 0..advances {
+            self.next_u64();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_generate_numbers() {
+        let mut rng = Xoroshiro128Plus::new(0x1234);
+        assert_eq!(rng.next_u64(), 0x82a2b175229d7c8f);
+        assert_eq!(rng.next_u64(), 0x8786a1eac348a713);
+        assert_eq!(rng.next_u64(), 0x58a730421270429f);
+    }
+
+    #[test]
+    fn should_draw_until_below_max() {
+        let mut rng = Xoroshiro128Plus::new(0x1234);
+        let mut expected = rng;
+        // The first number masks to 7, so the second one is used.
+        assert_eq!(rng.next_int(6), 0x13 & 7);
+        expected.advance(2);
+        assert_eq!(rng, expected);
+
+        assert_eq!(Xoroshiro128Plus::new(0).next_int(1), 0);
+    }
+
+    #[test]
+    fn should_not_loop_for_a_max_of_zero() {
+        let mut rng = Xoroshiro128Plus::new(0x1234);
+        assert_eq!(rng.next_int(0), 0);
+        assert_eq!(rng, Xoroshiro128Plus::new(0x1234));
+    }
+}
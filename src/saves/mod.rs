@@ -0,0 +1,115 @@
+mod sav3ds;
+mod sav6;
+mod sav7;
+
+pub use sav3ds::*;
+pub use sav6::*;
+pub use sav7::*;
+
+use crate::reader::Reader;
+use alloc::vec::Vec;
+
+/// A checksummed block of a 3DS save.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SaveBlock {
+    pub id: u16,
+    pub offset: usize,
+    pub length: usize,
+    /// The checksum stored in the block table.
+    pub checksum: u16,
+}
+
+/// Reads the block table at `table_offset`.
+///
+/// Each entry is the block length (u32), id (u16) and checksum (u16).  Blocks
+/// are stored back to back, each starting on a 0x200 byte boundary, and the
+/// table ends at the first entry whose id doesn't match its index.
+fn read_blocks(data: &[u8], table_offset: usize) -> Vec<SaveBlock> {
+    let mut blocks = Vec::new();
+    let mut offset = 0;
+
+    for (index, entry) in data[table_offset..].chunks_exact(8).enumerate() {
+        let length = entry.read::<u32>(0) as usize;
+        let id = entry.read::<u16>(4);
+        if id as usize != index || offset + length > table_offset {
+            break;
+        }
+
+        blocks.push(SaveBlock {
+            id,
+            offset,
+            length,
+            checksum: entry.read(6),
+        });
+        offset += length.next_multiple_of(0x200);
+    }
+
+    blocks
+}
+
+fn block_checksum_offset(table_offset: usize, block: &SaveBlock) -> usize {
+    table_offset + block.id as usize * 8 + 6
+}
+
+/// CRC-16/CCITT-FALSE, used by X/Y and Omega Ruby/Alpha Sapphire.
+pub(crate) fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc = 0xffffu16;
+
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+
+    crc
+}
+
+/// The reflected CRC-16 with an inverted seed and result, used by Gen7.
+pub(crate) fn crc16_invert(data: &[u8]) -> u16 {
+    let mut crc = 0xffffu16;
+
+    for byte in data {
+        crc ^= *byte as u16;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xa001
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::writer::Writer;
+
+    #[test]
+    fn should_calculate_crcs() {
+        assert_eq!(crc16_ccitt(b"123456789"), 0x29b1);
+        assert_eq!(crc16_invert(b"123456789"), 0xb4c8);
+    }
+
+    #[test]
+    fn should_read_block_tables() {
+        let mut data = [0u8; 0x800];
+        Writer::write(&mut data, 0x700, 0x10u32);
+        Writer::write(&mut data, 0x708, 0x210u32);
+        Writer::write(&mut data, 0x70c, 1u16);
+        Writer::write(&mut data, 0x70e, 0xbeefu16);
+
+        let blocks = read_blocks(&data, 0x700);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].offset, 0x200);
+        assert_eq!(blocks[1].length, 0x210);
+        assert_eq!(blocks[1].checksum, 0xbeef);
+    }
+}
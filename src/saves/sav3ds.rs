@@ -0,0 +1,208 @@
+use super::{block_checksum_offset, read_blocks, SaveBlock};
+use crate::pkm_storage::PkmStorage;
+use crate::pkx::PkxMut;
+use crate::poke_crypto::PokeCrypto;
+use crate::reader::Reader;
+use crate::types::{BoxSlot, PartySlot, TrainerInfo};
+use crate::writer::Writer;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// Where a 3DS game keeps its data in the main save.
+pub trait SaveLayout {
+    /// The games sharing the layout, which differ in offsets.
+    type Game: Copy;
+    type Pkm: PkxMut + Default;
+
+    const BOX_COUNT: u8;
+    /// The offset of the OT name in the trainer block.
+    const OT_NAME: usize;
+    /// The offset of the language in the trainer block.
+    const LANGUAGE: usize;
+    /// The offset of the box wallpapers in the box layout block.
+    const WALLPAPERS: usize;
+
+    /// Recognizes the game by the save size.
+    fn game(size: usize) -> Option<Self::Game>;
+    fn table_offset(size: usize) -> usize;
+    fn block_checksum(data: &[u8]) -> u16;
+    fn trainer_offset(game: Self::Game) -> usize;
+    fn party_offset(game: Self::Game) -> usize;
+    fn box_layout_offset(game: Self::Game) -> usize;
+    fn box_offset(game: Self::Game) -> usize;
+    fn get_string(data: &[u8]) -> String;
+}
+
+/// A Gen6 or Gen7 main save.
+pub struct Sav3ds<L: SaveLayout> {
+    data: Vec<u8>,
+    game: L::Game,
+    blocks: Vec<SaveBlock>,
+    layout: PhantomData<L>,
+}
+
+impl<L: SaveLayout> Reader for Sav3ds<L> {
+    fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl<L: SaveLayout> Writer for Sav3ds<L> {
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl<L: SaveLayout> Sav3ds<L> {
+    pub const BOX_COUNT: u8 = L::BOX_COUNT;
+
+    /// Opens a save, recognizing the game by the save size.
+    pub fn new(data: Vec<u8>) -> Option<Self> {
+        let game = L::game(data.len())?;
+        let blocks = read_blocks(&data, L::table_offset(data.len()));
+        Some(Self {
+            data,
+            game,
+            blocks,
+            layout: PhantomData,
+        })
+    }
+
+    pub fn game(&self) -> L::Game {
+        self.game
+    }
+
+    pub fn blocks(&self) -> &[SaveBlock] {
+        &self.blocks
+    }
+
+    /// Lists the ids of the blocks whose checksum doesn't match their data.
+    pub fn invalid_blocks(&self) -> Vec<u16> {
+        self.blocks
+            .iter()
+            .filter(|block| {
+                L::block_checksum(&self.data[block.offset..][..block.length]) != block.checksum
+            })
+            .map(|block| block.id)
+            .collect()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.blocks.is_empty() && self.invalid_blocks().is_empty()
+    }
+
+    pub fn trainer_info(&self) -> TrainerInfo {
+        let trainer = L::trainer_offset(self.game);
+        TrainerInfo {
+            ot_name: L::get_string(&self.data[trainer + L::OT_NAME..][..0x1a]),
+            tid16: self.read(trainer as u64),
+            sid16: self.read(trainer as u64 + 2),
+            version: self.read::<u8>(trainer as u64 + 4).into(),
+            gender: self.read(trainer as u64 + 5),
+            language: self.read((trainer + L::LANGUAGE) as u64),
+        }
+    }
+
+    fn party_entry_offset(&self, slot: PartySlot) -> usize {
+        L::party_offset(self.game) + slot as usize * L::Pkm::PARTY_SIZE
+    }
+
+    pub fn party_count(&self) -> u8 {
+        self.read((L::party_offset(self.game) + 6 * L::Pkm::PARTY_SIZE) as u64)
+    }
+
+    pub fn party_pkm(&self, slot: PartySlot) -> L::Pkm {
+        let offset = self.party_entry_offset(slot);
+        L::Pkm::from_bytes(&self.data[offset..][..L::Pkm::PARTY_SIZE]).unwrap_or_default()
+    }
+
+    pub fn party(&self) -> Vec<L::Pkm> {
+        let mut slot = PartySlot::Slot1;
+        let mut party = Vec::new();
+
+        for _ in 0..self.party_count().min(6) {
+            party.push(self.party_pkm(slot));
+            slot = slot.increment();
+        }
+
+        party
+    }
+
+    /// Stores a Pokemon in the party.  The party stats already in the slot
+    /// are kept, since the crate can't calculate stats yet.
+    pub fn set_party_pkm(&mut self, slot: PartySlot, pkm: &L::Pkm) {
+        let offset = self.party_entry_offset(slot);
+        let mut entry = self.data[offset..][..L::Pkm::PARTY_SIZE].to_vec();
+        L::Pkm::decrypt_raw(&mut entry);
+        entry[..L::Pkm::STORED_SIZE].copy_from_slice(&pkm.as_slice()[..L::Pkm::STORED_SIZE]);
+        L::Pkm::encrypt_raw(&mut entry);
+        self.write_array(offset as u64, &entry);
+    }
+
+    /// Recalculates every block checksum after the save has been edited.
+    pub fn refresh_checksums(&mut self) {
+        let table_offset = L::table_offset(self.data.len());
+
+        for block in self.blocks.iter_mut() {
+            block.checksum = L::block_checksum(&self.data[block.offset..][..block.length]);
+            let offset = block_checksum_offset(table_offset, block);
+            self.data[offset..][..2].copy_from_slice(&block.checksum.to_le_bytes());
+        }
+    }
+
+    /// The save data, with checksums updated for any edits.  Gen7 saves
+    /// still need a MemeCrypto re-sign, see [Sav7](super::Sav7).
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        self.refresh_checksums();
+        self.data
+    }
+
+    fn box_slot_offset(&self, slot: BoxSlot) -> usize {
+        L::box_offset(self.game) + slot.index() * L::Pkm::STORED_SIZE
+    }
+}
+
+impl<L: SaveLayout> PkmStorage for Sav3ds<L> {
+    type Pkm = L::Pkm;
+
+    fn box_count(&self) -> u8 {
+        L::BOX_COUNT
+    }
+
+    fn slot_data(&self, slot: BoxSlot) -> Option<&[u8]> {
+        if !self.contains(slot) {
+            return None;
+        }
+
+        let offset = self.box_slot_offset(slot);
+        self.data.get(offset..offset + L::Pkm::STORED_SIZE)
+    }
+
+    fn slot_data_mut(&mut self, slot: BoxSlot) -> Option<&mut [u8]> {
+        if !self.contains(slot) {
+            return None;
+        }
+
+        let offset = self.box_slot_offset(slot);
+        self.data.get_mut(offset..offset + L::Pkm::STORED_SIZE)
+    }
+
+    fn box_name(&self, box_index: u8) -> Option<String> {
+        if box_index >= L::BOX_COUNT {
+            return None;
+        }
+
+        let offset = L::box_layout_offset(self.game) + box_index as usize * 0x22;
+        Some(L::get_string(&self.data[offset..][..0x22]))
+    }
+
+    fn box_wallpaper(&self, box_index: u8) -> Option<u8> {
+        if box_index >= L::BOX_COUNT {
+            return None;
+        }
+
+        let offset = L::box_layout_offset(self.game) + L::WALLPAPERS + box_index as usize;
+        Some(self.read(offset as u64))
+    }
+}
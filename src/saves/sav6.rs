@@ -0,0 +1,172 @@
+use super::{crc16_ccitt, Sav3ds, SaveLayout};
+use crate::pk6::Pk6;
+use crate::strings::string_converter6;
+use alloc::string::String;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sav6Game {
+    XY,
+    ORAS,
+}
+
+/// The X/Y and Omega Ruby/Alpha Sapphire main save layout.
+pub struct Layout6;
+
+/// A Gen6 main save.
+pub type Sav6 = Sav3ds<Layout6>;
+
+impl Layout6 {
+    pub const SIZE_XY: usize = 0x65600;
+    pub const SIZE_ORAS: usize = 0x76000;
+
+    const TRAINER: usize = 0x14000;
+    const PARTY: usize = 0x14200;
+}
+
+impl SaveLayout for Layout6 {
+    type Game = Sav6Game;
+    type Pkm = Pk6;
+
+    const BOX_COUNT: u8 = 31;
+    const OT_NAME: usize = 0x48;
+    const LANGUAGE: usize = 0x2d;
+    const WALLPAPERS: usize = 0x41e;
+
+    fn game(size: usize) -> Option<Sav6Game> {
+        match size {
+            Self::SIZE_XY => Some(Sav6Game::XY),
+            Self::SIZE_ORAS => Some(Sav6Game::ORAS),
+            _ => None,
+        }
+    }
+
+    fn table_offset(size: usize) -> usize {
+        size - 0x200 + 0x10
+    }
+
+    fn block_checksum(data: &[u8]) -> u16 {
+        crc16_ccitt(data)
+    }
+
+    fn trainer_offset(_game: Sav6Game) -> usize {
+        Self::TRAINER
+    }
+
+    fn party_offset(_game: Sav6Game) -> usize {
+        Self::PARTY
+    }
+
+    fn box_layout_offset(_game: Sav6Game) -> usize {
+        0x4400
+    }
+
+    fn box_offset(game: Sav6Game) -> usize {
+        match game {
+            Sav6Game::XY => 0x22600,
+            Sav6Game::ORAS => 0x33000,
+        }
+    }
+
+    fn get_string(data: &[u8]) -> String {
+        string_converter6::get_string(data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm_storage::PkmStorage;
+    use crate::pkx::{Pkx, PkxMut};
+    use crate::poke_crypto::PokeCrypto;
+    use crate::types::{BoxSlot, GameVersion, PartySlot, Species};
+    use crate::writer::Writer;
+    use alloc::vec::Vec;
+
+    fn save() -> Vec<u8> {
+        let mut data = alloc::vec![0u8; Layout6::SIZE_XY];
+        let table = Layout6::table_offset(data.len());
+        // One block covering everything up to the boxes.
+        Writer::write(&mut data, table as u64, 0x22600u32);
+
+        let trainer = Layout6::TRAINER as u64;
+        Writer::write(&mut data, trainer, 12345u16);
+        Writer::write(&mut data, trainer + 2, 54321u16);
+        Writer::write(&mut data, trainer + 4, GameVersion::Y as u8);
+        Writer::write(&mut data, trainer + 5, 1u8);
+        Writer::write(&mut data, trainer + 0x2d, 2u8);
+        string_converter6::set_string(&mut data[Layout6::TRAINER + 0x48..][..0x1a], "Serena");
+        data
+    }
+
+    fn pikachu() -> Pk6 {
+        let mut pkm = Pk6::default();
+        pkm.set_encryption_constant(0x1234_5678);
+        pkm.set_species(Species::Pikachu as u16);
+        pkm.refresh_checksum();
+        pkm
+    }
+
+    #[test]
+    fn should_detect_games_by_size() {
+        assert_eq!(Sav6::new(save()).unwrap().game(), Sav6Game::XY);
+        let oras = Sav6::new(alloc::vec![0u8; Layout6::SIZE_ORAS]).unwrap();
+        assert_eq!(oras.game(), Sav6Game::ORAS);
+        assert!(Sav6::new(alloc::vec![0u8; 0x100]).is_none());
+    }
+
+    #[test]
+    fn should_read_trainer_info() {
+        let trainer = Sav6::new(save()).unwrap().trainer_info();
+        assert_eq!(trainer.ot_name, "Serena");
+        assert_eq!(trainer.tid16, 12345);
+        assert_eq!(trainer.sid16, 54321);
+        assert_eq!(trainer.version, GameVersion::Y);
+        assert_eq!(trainer.gender, 1);
+    }
+
+    #[test]
+    fn should_read_box_layout() {
+        let mut data = save();
+        string_converter6::set_string(&mut data[0x4400 + 0x22..][..0x22], "Favorites");
+        Writer::write(&mut data, 0x4400 + 0x41e + 1, 5u8);
+
+        let sav = Sav6::new(data).unwrap();
+        assert_eq!(sav.box_name(1).unwrap(), "Favorites");
+        assert_eq!(sav.box_wallpaper(1), Some(5));
+        assert!(sav.box_name(31).is_none());
+    }
+
+    #[test]
+    fn should_validate_block_checksums() {
+        let sav = Sav6::new(save()).unwrap();
+        assert_eq!(sav.blocks().len(), 1);
+        assert_eq!(sav.invalid_blocks(), [0]);
+
+        let sav = Sav6::new(sav.into_bytes()).unwrap();
+        assert!(sav.is_valid());
+    }
+
+    #[test]
+    fn should_write_party_and_boxes() {
+        let mut data = save();
+        Writer::write(
+            &mut data,
+            (Layout6::PARTY + 6 * Pk6::PARTY_SIZE) as u64,
+            1u8,
+        );
+        let mut sav = Sav6::new(data).unwrap();
+        sav.set_party_pkm(PartySlot::Slot1, &pikachu());
+        sav.set_pkm(BoxSlot::new(30, 29), &pikachu());
+
+        let sav = Sav6::new(sav.into_bytes()).unwrap();
+        assert!(sav.is_valid());
+        assert_eq!(sav.party().len(), 1);
+        assert_eq!(sav.party()[0].species_t(), Species::Pikachu);
+        assert!(sav.party()[0].is_valid());
+        assert_eq!(
+            sav.pkm(BoxSlot::new(30, 29)).unwrap().species_t(),
+            Species::Pikachu
+        );
+        assert!(sav.pkm(BoxSlot::new(31, 0)).is_none());
+    }
+}
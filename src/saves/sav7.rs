@@ -0,0 +1,165 @@
+use super::{crc16_invert, Sav3ds, SaveLayout};
+use crate::pk7::Pk7;
+use crate::strings::string_converter7;
+use alloc::string::String;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sav7Game {
+    SM,
+    USUM,
+}
+
+/// The Sun/Moon and Ultra Sun/Ultra Moon main save layout.
+pub struct Layout7;
+
+/// A Gen7 main save.
+///
+/// Sun/Moon and Ultra Sun/Ultra Moon also sign the block checksum table with
+/// MemeCrypto, which this crate doesn't implement.  The bytes from
+/// [into_bytes](Sav3ds::into_bytes) keep the old signature, so an edited save
+/// has to be re-signed by an external tool before the game will load it.
+/// Gen6 saves have no signature.
+pub type Sav7 = Sav3ds<Layout7>;
+
+impl Layout7 {
+    pub const SIZE_SM: usize = 0x6be00;
+    pub const SIZE_USUM: usize = 0x6cc00;
+}
+
+impl SaveLayout for Layout7 {
+    type Game = Sav7Game;
+    type Pkm = Pk7;
+
+    const BOX_COUNT: u8 = 32;
+    const OT_NAME: usize = 0x38;
+    const LANGUAGE: usize = 0x35;
+    const WALLPAPERS: usize = 0x5c0;
+
+    fn game(size: usize) -> Option<Sav7Game> {
+        match size {
+            Self::SIZE_SM => Some(Sav7Game::SM),
+            Self::SIZE_USUM => Some(Sav7Game::USUM),
+            _ => None,
+        }
+    }
+
+    fn table_offset(size: usize) -> usize {
+        size - 0x200
+    }
+
+    fn block_checksum(data: &[u8]) -> u16 {
+        crc16_invert(data)
+    }
+
+    fn trainer_offset(game: Sav7Game) -> usize {
+        match game {
+            Sav7Game::SM => 0x1200,
+            Sav7Game::USUM => 0x1400,
+        }
+    }
+
+    fn party_offset(game: Sav7Game) -> usize {
+        Self::trainer_offset(game) + 0x200
+    }
+
+    fn box_layout_offset(game: Sav7Game) -> usize {
+        match game {
+            Sav7Game::SM => 0x4800,
+            Sav7Game::USUM => 0x4c00,
+        }
+    }
+
+    fn box_offset(game: Sav7Game) -> usize {
+        match game {
+            Sav7Game::SM => 0x4e00,
+            Sav7Game::USUM => 0x5200,
+        }
+    }
+
+    fn get_string(data: &[u8]) -> String {
+        string_converter7::get_string(data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm_storage::PkmStorage;
+    use crate::pkx::{Pkx, PkxMut};
+    use crate::poke_crypto::PokeCrypto;
+    use crate::types::{BoxSlot, GameVersion, PartySlot, Species};
+    use crate::writer::Writer;
+    use alloc::vec::Vec;
+
+    fn save() -> Vec<u8> {
+        let mut data = alloc::vec![0u8; Layout7::SIZE_USUM];
+        let table = Layout7::table_offset(data.len());
+        // One block covering everything up to the boxes.
+        Writer::write(&mut data, table as u64, 0x5200u32);
+
+        let trainer = 0x1400u64;
+        Writer::write(&mut data, trainer, 12345u16);
+        Writer::write(&mut data, trainer + 2, 54321u16);
+        Writer::write(&mut data, trainer + 4, GameVersion::UltraMoon as u8);
+        Writer::write(&mut data, trainer + 5, 1u8);
+        Writer::write(&mut data, trainer + 0x35, 2u8);
+        string_converter7::set_string(&mut data[0x1400 + 0x38..][..0x1a], "Selene");
+        data
+    }
+
+    fn pikachu() -> Pk7 {
+        let mut pkm = Pk7::default();
+        pkm.set_encryption_constant(0x1234_5678);
+        pkm.set_species(Species::Pikachu as u16);
+        pkm.refresh_checksum();
+        pkm
+    }
+
+    #[test]
+    fn should_detect_games_by_size() {
+        assert_eq!(Sav7::new(save()).unwrap().game(), Sav7Game::USUM);
+        let sm = Sav7::new(alloc::vec![0u8; Layout7::SIZE_SM]).unwrap();
+        assert_eq!(sm.game(), Sav7Game::SM);
+        assert!(Sav7::new(alloc::vec![0u8; 0x100]).is_none());
+    }
+
+    #[test]
+    fn should_read_trainer_info() {
+        let trainer = Sav7::new(save()).unwrap().trainer_info();
+        assert_eq!(trainer.ot_name, "Selene");
+        assert_eq!(trainer.tid16, 12345);
+        assert_eq!(trainer.sid16, 54321);
+        assert_eq!(trainer.version, GameVersion::UltraMoon);
+        assert_eq!(trainer.gender, 1);
+    }
+
+    #[test]
+    fn should_validate_block_checksums() {
+        let sav = Sav7::new(save()).unwrap();
+        assert_eq!(sav.blocks().len(), 1);
+        assert_eq!(sav.invalid_blocks(), [0]);
+
+        let sav = Sav7::new(sav.into_bytes()).unwrap();
+        assert!(sav.is_valid());
+    }
+
+    #[test]
+    fn should_write_party_and_boxes() {
+        let mut data = save();
+        Writer::write(&mut data, (0x1600 + 6 * Pk7::PARTY_SIZE) as u64, 1u8);
+        let mut sav = Sav7::new(data).unwrap();
+        sav.set_party_pkm(PartySlot::Slot1, &pikachu());
+        sav.set_pkm(BoxSlot::new(31, 29), &pikachu());
+
+        let sav = Sav7::new(sav.into_bytes()).unwrap();
+        assert!(sav.is_valid());
+        assert_eq!(sav.party().len(), 1);
+        assert_eq!(sav.party()[0].species_t(), Species::Pikachu);
+        assert!(sav.party()[0].is_valid());
+        assert_eq!(
+            sav.pkm(BoxSlot::new(31, 29)).unwrap().species_t(),
+            Species::Pikachu
+        );
+        assert!(sav.pkm(BoxSlot::new(32, 0)).is_none());
+    }
+}
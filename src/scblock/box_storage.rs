@@ -0,0 +1,86 @@
+use super::SCBlock;
+use crate::pkm_storage::PkmStorage;
+use crate::pkx::PkxMut;
+use crate::types::BoxSlot;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// The boxes stored in an SCBlock.  The Switch games keep party sized entries
+/// in their boxes, so each slot is `T::PARTY_SIZE` bytes.
+pub struct SCBoxStorage<'a, T> {
+    block: &'a mut SCBlock,
+    pkm: PhantomData<T>,
+}
+
+impl<'a, T: PkxMut + Default> SCBoxStorage<'a, T> {
+    pub fn new(block: &'a mut SCBlock) -> Self {
+        Self {
+            block,
+            pkm: PhantomData,
+        }
+    }
+
+    fn slot_range(&self, slot: BoxSlot) -> Option<Range<usize>> {
+        let start = slot.index() * T::PARTY_SIZE;
+        let end = start + T::PARTY_SIZE;
+        (self.contains(slot) && end <= self.block.data.len()).then_some(start..end)
+    }
+}
+
+impl<T: PkxMut + Default> PkmStorage for SCBoxStorage<'_, T> {
+    type Pkm = T;
+
+    fn box_count(&self) -> u8 {
+        BoxSlot::BOX_COUNT
+    }
+
+    fn slot_data(&self, slot: BoxSlot) -> Option<&[u8]> {
+        let range = self.slot_range(slot)?;
+        Some(&self.block.data[range])
+    }
+
+    fn slot_data_mut(&mut self, slot: BoxSlot) -> Option<&mut [u8]> {
+        let range = self.slot_range(slot)?;
+        Some(&mut self.block.data[range])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pk9::Pk9;
+    use crate::pkx::Pkx;
+    use crate::poke_crypto::PokeCrypto;
+    use crate::scblock::{SCTypeCode, SC_KEY_BOX};
+    use crate::types::Species;
+    use alloc::vec;
+
+    fn block() -> SCBlock {
+        SCBlock {
+            key: SC_KEY_BOX,
+            type_code: SCTypeCode::Object,
+            sub_type: None,
+            data: vec![0; 32 * 30 * Pk9::PARTY_SIZE],
+        }
+    }
+
+    #[test]
+    fn should_read_and_write_slots() {
+        let mut block = block();
+        let mut storage = SCBoxStorage::<Pk9>::new(&mut block);
+
+        let mut pkm = Pk9::default();
+        pkm.set_encryption_constant(0xdead_beef);
+        pkm.set_species(Species::Sprigatito as u16);
+        pkm.refresh_checksum();
+        storage.set_pkm(BoxSlot::new(31, 29), &pkm);
+
+        let read = storage.pkm(BoxSlot::new(31, 29)).unwrap();
+        assert_eq!(read.species_t(), Species::Sprigatito);
+        assert!(read.is_valid());
+        assert!(storage.pkm(BoxSlot::new(32, 0)).is_none());
+
+        let offset = (31 * 30 + 29) * Pk9::PARTY_SIZE;
+        assert_ne!(block.data[offset + 8..][..2], [0, 0]);
+    }
+}
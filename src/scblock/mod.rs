@@ -0,0 +1,329 @@
+mod box_storage;
+mod xorshift;
+
+pub use box_storage::*;
+pub use xorshift::*;
+
+use alloc::vec::Vec;
+use num_enum::TryFromPrimitive;
+use sha2::{Digest, Sha256};
+
+/// The key of the box storage block in Sword/Shield and Scarlet/Violet.
+pub const SC_KEY_BOX: u32 = 0x0d66012c;
+/// The key of the box storage block in Legends Arceus.
+pub const SC_KEY_BOX_LA: u32 = 0x47e1ceab;
+
+const HASH_SIZE: usize = 0x20;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum SCTypeCode {
+    None = 0,
+    /// A bool block holding `false`.
+    Bool1 = 1,
+    /// A bool block holding `true`.
+    Bool2 = 2,
+    /// A bool block only used by a few special flags.
+    Bool3 = 3,
+    Object = 4,
+    Array = 5,
+    Byte = 8,
+    UInt16 = 9,
+    UInt32 = 10,
+    UInt64 = 11,
+    SByte = 12,
+    Int16 = 13,
+    Int32 = 14,
+    Int64 = 15,
+    Single = 16,
+    Double = 17,
+}
+
+impl SCTypeCode {
+    /// The size of a single value, or 0 for types without a fixed size.
+    pub fn byte_size(&self) -> usize {
+        match self {
+            Self::Bool1 | Self::Bool2 | Self::Bool3 | Self::Byte | Self::SByte => 1,
+            Self::UInt16 | Self::Int16 => 2,
+            Self::UInt32 | Self::Int32 | Self::Single => 4,
+            Self::UInt64 | Self::Int64 | Self::Double => 8,
+            Self::None | Self::Object | Self::Array => 0,
+        }
+    }
+
+    pub fn is_bool(&self) -> bool {
+        matches!(self, Self::Bool1 | Self::Bool2 | Self::Bool3)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SCBlockError {
+    /// The SHA256 hash at the end of the file doesn't match its contents.
+    InvalidHash,
+    /// A block runs past the end of the file.
+    UnexpectedEnd,
+    /// The block with the given key has an unknown type.
+    UnknownType(u32),
+}
+
+/// A decrypted key-value block of a Switch save.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SCBlock {
+    pub key: u32,
+    pub type_code: SCTypeCode,
+    /// The element type of array blocks.
+    pub sub_type: Option<SCTypeCode>,
+    pub data: Vec<u8>,
+}
+
+impl SCBlock {
+    /// The value of a bool block.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.type_code {
+            SCTypeCode::Bool1 => Some(false),
+            SCTypeCode::Bool2 => Some(true),
+            _ => None,
+        }
+    }
+
+    /// The number of elements in an array block.
+    pub fn array_len(&self) -> Option<usize> {
+        let size = self.sub_type?.byte_size().max(1);
+        Some(self.data.len() / size)
+    }
+
+    fn read(data: &[u8], offset: &mut usize) -> Result<Self, SCBlockError> {
+        let mut take = |len: usize| -> Result<&[u8], SCBlockError> {
+            let bytes = data
+                .get(*offset..*offset + len)
+                .ok_or(SCBlockError::UnexpectedEnd)?;
+            *offset += len;
+            Ok(bytes)
+        };
+
+        let key = u32::from_le_bytes(take(4)?.try_into().unwrap());
+        let mut xk = SCXorShift32::new(key);
+        let type_code = SCTypeCode::try_from(take(1)?[0] ^ xk.next_u8())
+            .map_err(|_| SCBlockError::UnknownType(key))?;
+
+        let mut read_u32 = |xk: &mut SCXorShift32| -> Result<u32, SCBlockError> {
+            let bytes: [u8; 4] = take(4)?.try_into().unwrap();
+            Ok(u32::from_le_bytes(bytes) ^ xk.next_u32())
+        };
+
+        let (sub_type, len) = match type_code {
+            SCTypeCode::None => return Err(SCBlockError::UnknownType(key)),
+            type_code if type_code.is_bool() => (None, 0),
+            SCTypeCode::Object => (None, read_u32(&mut xk)? as usize),
+            SCTypeCode::Array => {
+                let count = read_u32(&mut xk)? as usize;
+                let sub_type = SCTypeCode::try_from(take(1)?[0] ^ xk.next_u8())
+                    .map_err(|_| SCBlockError::UnknownType(key))?;
+                (Some(sub_type), count * sub_type.byte_size())
+            }
+            type_code => (None, type_code.byte_size()),
+        };
+
+        let data = take(len)?.iter().map(|byte| byte ^ xk.next_u8()).collect();
+
+        Ok(Self {
+            key,
+            type_code,
+            sub_type,
+            data,
+        })
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        let mut xk = SCXorShift32::new(self.key);
+        out.extend_from_slice(&self.key.to_le_bytes());
+        out.push(self.type_code as u8 ^ xk.next_u8());
+
+        match self.type_code {
+            SCTypeCode::Object => {
+                let len = self.data.len() as u32 ^ xk.next_u32();
+                out.extend_from_slice(&len.to_le_bytes());
+            }
+            SCTypeCode::Array => {
+                let sub_type = self.sub_type.unwrap_or(SCTypeCode::Byte);
+                let count = (self.data.len() / sub_type.byte_size().max(1)) as u32 ^ xk.next_u32();
+                out.extend_from_slice(&count.to_le_bytes());
+                out.push(sub_type as u8 ^ xk.next_u8());
+            }
+            _ => {}
+        }
+
+        out.extend(self.data.iter().map(|byte| byte ^ xk.next_u8()));
+    }
+}
+
+/// The constants used to encrypt and hash a Switch save.
+///
+/// They come from the games and aren't distributed with the crate, so reading
+/// a real `main` file needs the caller to supply them, e.g. from PKHeX's
+/// `SwishCrypto`.  Saves decrypted elsewhere can go through [read_sc_blocks]
+/// and [write_sc_blocks] instead.
+#[derive(Clone, Copy, Debug)]
+pub struct SwishKeys<'a> {
+    /// The pad the whole file (minus the hash) is XORed with, repeated.
+    pub xorpad: &'a [u8],
+    /// Hashed before the encrypted file contents.
+    pub hash_intro: &'a [u8],
+    /// Hashed after the encrypted file contents.
+    pub hash_outro: &'a [u8],
+}
+
+impl SwishKeys<'_> {
+    fn hash(&self, data: &[u8]) -> [u8; HASH_SIZE] {
+        let mut sha = Sha256::new();
+        sha.update(self.hash_intro);
+        sha.update(data);
+        sha.update(self.hash_outro);
+        sha.finalize().into()
+    }
+
+    fn crypt(&self, data: &mut [u8]) {
+        if self.xorpad.is_empty() {
+            return;
+        }
+
+        for (byte, pad) in data.iter_mut().zip(self.xorpad.iter().cycle()) {
+            *byte ^= pad;
+        }
+    }
+}
+
+/// Whether the hash at the end of an encrypted save matches its contents.
+pub fn is_sc_hash_valid(data: &[u8], keys: &SwishKeys) -> bool {
+    data.len() >= HASH_SIZE && {
+        let (payload, hash) = data.split_at(data.len() - HASH_SIZE);
+        keys.hash(payload) == hash
+    }
+}
+
+/// Decrypts a `main` save into its blocks.
+pub fn decrypt_sc_blocks(data: &[u8], keys: &SwishKeys) -> Result<Vec<SCBlock>, SCBlockError> {
+    if !is_sc_hash_valid(data, keys) {
+        return Err(SCBlockError::InvalidHash);
+    }
+
+    let mut payload = data[..data.len() - HASH_SIZE].to_vec();
+    keys.crypt(&mut payload);
+    read_sc_blocks(&payload)
+}
+
+/// Reads the blocks of a `main` save that has already been decrypted and had
+/// its hash removed.
+pub fn read_sc_blocks(payload: &[u8]) -> Result<Vec<SCBlock>, SCBlockError> {
+    let mut blocks = Vec::new();
+    let mut offset = 0;
+    while offset < payload.len() {
+        blocks.push(SCBlock::read(payload, &mut offset)?);
+    }
+
+    Ok(blocks)
+}
+
+/// Writes blocks into a decrypted `main` save, without the hash.
+pub fn write_sc_blocks(blocks: &[SCBlock]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for block in blocks {
+        block.write(&mut data);
+    }
+
+    data
+}
+
+/// Encrypts blocks into a `main` save, including the hash.
+pub fn encrypt_sc_blocks(blocks: &[SCBlock], keys: &SwishKeys) -> Vec<u8> {
+    let mut data = write_sc_blocks(blocks);
+    keys.crypt(&mut data);
+    let hash = keys.hash(&data);
+    data.extend_from_slice(&hash);
+    data
+}
+
+pub fn find_sc_block(blocks: &[SCBlock], key: u32) -> Option<&SCBlock> {
+    blocks.iter().find(|block| block.key == key)
+}
+
+pub fn find_sc_block_mut(blocks: &mut [SCBlock], key: u32) -> Option<&mut SCBlock> {
+    blocks.iter_mut().find(|block| block.key == key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    const KEYS: SwishKeys = SwishKeys {
+        xorpad: &[0x5a, 0xa5, 0x3c],
+        hash_intro: b"intro",
+        hash_outro: b"outro",
+    };
+
+    fn blocks() -> Vec<SCBlock> {
+        vec![
+            SCBlock {
+                key: 0x1234,
+                type_code: SCTypeCode::Bool2,
+                sub_type: None,
+                data: Vec::new(),
+            },
+            SCBlock {
+                key: 0xabcd_ef01,
+                type_code: SCTypeCode::Object,
+                sub_type: None,
+                data: vec![1, 2, 3, 4, 5],
+            },
+            SCBlock {
+                key: 0x0f0f_0f0f,
+                type_code: SCTypeCode::Array,
+                sub_type: Some(SCTypeCode::UInt16),
+                data: vec![1, 0, 2, 0, 3, 0],
+            },
+            SCBlock {
+                key: 0x7777,
+                type_code: SCTypeCode::UInt32,
+                sub_type: None,
+                data: vec![0x78, 0x56, 0x34, 0x12],
+            },
+        ]
+    }
+
+    #[test]
+    fn should_round_trip_blocks() {
+        let data = encrypt_sc_blocks(&blocks(), &KEYS);
+        assert!(is_sc_hash_valid(&data, &KEYS));
+
+        let decrypted = decrypt_sc_blocks(&data, &KEYS).unwrap();
+        assert_eq!(decrypted, blocks());
+        assert_eq!(decrypted[0].as_bool(), Some(true));
+        assert_eq!(decrypted[2].array_len(), Some(3));
+    }
+
+    #[test]
+    fn should_round_trip_decrypted_payloads() {
+        let payload = write_sc_blocks(&blocks());
+        assert_eq!(read_sc_blocks(&payload).unwrap(), blocks());
+    }
+
+    #[test]
+    fn should_reject_invalid_hashes() {
+        let mut data = encrypt_sc_blocks(&blocks(), &KEYS);
+        data[0] ^= 1;
+        assert_eq!(
+            decrypt_sc_blocks(&data, &KEYS),
+            Err(SCBlockError::InvalidHash)
+        );
+    }
+
+    #[test]
+    fn should_find_blocks_by_key() {
+        let mut blocks = blocks();
+        assert_eq!(find_sc_block(&blocks, 0x7777).unwrap().data.len(), 4);
+        find_sc_block_mut(&mut blocks, 0x7777).unwrap().data[0] = 0;
+        assert_eq!(blocks[3].data[0], 0);
+        assert!(find_sc_block(&blocks, 1).is_none());
+    }
+}
@@ -0,0 +1,62 @@
+/// The key stream each SCBlock is XORed with, seeded by the block key.
+pub struct SCXorShift32 {
+    state: u32,
+    counter: u32,
+}
+
+fn advance(mut state: u32) -> u32 {
+    state ^= state << 2;
+    state ^= state >> 15;
+    state ^= state << 13;
+    state
+}
+
+impl SCXorShift32 {
+    pub fn new(seed: u32) -> Self {
+        let mut state = seed;
+        for _ in 0..seed.count_ones() {
+            state = advance(state);
+        }
+
+        Self { state, counter: 0 }
+    }
+
+    pub fn next_u8(&mut self) -> u8 {
+        let value = (self.state >> (self.counter << 3)) as u8;
+        if self.counter == 3 {
+            self.state = advance(self.state);
+            self.counter = 0;
+        } else {
+            self.counter += 1;
+        }
+
+        value
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        u32::from_le_bytes([
+            self.next_u8(),
+            self.next_u8(),
+            self.next_u8(),
+            self.next_u8(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_advance_once_per_set_bit() {
+        let mut rng = SCXorShift32::new(1);
+        assert_eq!(rng.next_u32(), advance(1));
+        assert_eq!(rng.next_u32(), advance(advance(1)));
+    }
+
+    #[test]
+    fn should_stay_zero_for_zero_seeds() {
+        let mut rng = SCXorShift32::new(0);
+        assert_eq!(rng.next_u32(), 0);
+    }
+}
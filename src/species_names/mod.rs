@@ -0,0 +1,101 @@
+mod de;
+mod en;
+mod fr;
+mod ja;
+
+use crate::types::{Language, Species};
+
+fn language_names(language: Language) -> Option<&'static [&'static str]> {
+    match language {
+        Language::Japanese => Some(&ja::NAMES),
+        Language::English | Language::Italian | Language::Spanish => Some(&en::NAMES),
+        Language::French => Some(&fr::NAMES),
+        Language::German => Some(&de::NAMES),
+        _ => None,
+    }
+}
+
+/// The name a species goes by in a language's games, which is what the games
+/// set as the nickname of a Pokemon that isn't nicknamed.
+///
+/// Korean and Chinese names aren't known yet, so those languages give `None`.
+pub fn species_name(species: Species, language: Language) -> Option<&'static str> {
+    if species == Species::None {
+        return None;
+    }
+
+    language_names(language)?
+        .get(species as usize)
+        .copied()
+        .filter(|name| !name.is_empty())
+}
+
+/// The name eggs go by in a language's games.
+pub fn egg_name(language: Language) -> Option<&'static str> {
+    language_names(language).map(|names| names[0])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_name_species() {
+        assert_eq!(
+            species_name(Species::Bulbasaur, Language::Japanese),
+            Some("フシギダネ")
+        );
+        assert_eq!(
+            species_name(Species::Eevee, Language::French),
+            Some("Évoli")
+        );
+        assert_eq!(
+            species_name(Species::Charizard, Language::German),
+            Some("Glurak")
+        );
+        assert_eq!(
+            species_name(Species::Pikachu, Language::Spanish),
+            Some("Pikachu")
+        );
+    }
+
+    #[test]
+    fn should_keep_punctuation() {
+        assert_eq!(
+            species_name(Species::FarfetchD, Language::English),
+            Some("Farfetch\u{2019}d")
+        );
+        assert_eq!(
+            species_name(Species::HoOh, Language::English),
+            Some("Ho-Oh")
+        );
+        assert_eq!(
+            species_name(Species::PorygonZ, Language::Italian),
+            Some("Porygon-Z")
+        );
+        assert_eq!(
+            species_name(Species::NidoranF, Language::English),
+            Some("Nidoran♀")
+        );
+    }
+
+    #[test]
+    fn should_use_sv_species_numbers() {
+        assert_eq!(
+            species_name(Species::Annihilape, Language::English),
+            Some("Annihilape")
+        );
+        assert_eq!(
+            species_name(Species::Gimmighoul, Language::Japanese),
+            Some("コレクレー")
+        );
+        assert_eq!(species_name(Species::Aketuban, Language::English), None);
+    }
+
+    #[test]
+    fn should_skip_unknown_languages() {
+        assert_eq!(species_name(Species::Pikachu, Language::Korean), None);
+        assert_eq!(species_name(Species::None, Language::English), None);
+        assert_eq!(egg_name(Language::German), Some("Ei"));
+    }
+}
@@ -1,4 +1,5 @@
 mod string_converter;
+pub mod string_converter5;
 pub mod string_converter6;
 pub mod string_converter7;
 pub mod string_converter8;
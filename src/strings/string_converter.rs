@@ -7,3 +7,28 @@ pub fn sanitize_char(chr: char) -> char {
         _ => chr,
     }
 }
+
+pub fn unsanitize_char(chr: char) -> char {
+    match chr {
+        '♀' => '\u{E08F}',
+        '♂' => '\u{E08E}',
+        _ => chr,
+    }
+}
+
+/// Writes `value` as null terminated UTF-16, truncating it to fit `data`.
+pub fn set_utf16_string(data: &mut [u8], value: impl Iterator<Item = char>) {
+    data.fill(0);
+
+    let max_len = (data.len() / 2).saturating_sub(1);
+    let mut buf = [0u16; 2];
+    let units = value.flat_map(|chr| {
+        let len = chr.encode_utf16(&mut buf).len();
+        let units = buf;
+        units.into_iter().take(len)
+    });
+
+    for (chunk, unit) in data.chunks_exact_mut(2).take(max_len).zip(units) {
+        chunk.copy_from_slice(&unit.to_le_bytes());
+    }
+}
@@ -0,0 +1,58 @@
+use super::string_converter::set_utf16_string;
+use alloc::string::String;
+
+const TERMINATOR: u16 = 0xffff;
+
+fn sanitize_char(chr: char) -> char {
+    match chr {
+        '\u{246D}' => '♂',
+        '\u{246E}' => '♀',
+        _ => chr,
+    }
+}
+
+fn unsanitize_char(chr: char) -> char {
+    match chr {
+        '♂' => '\u{246D}',
+        '♀' => '\u{246E}',
+        _ => chr,
+    }
+}
+
+/// Gen5 strings end with `0xFFFF` instead of a null.
+pub fn get_string(data: &[u8]) -> String {
+    let utf16_iter = data
+        .chunks_exact(2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .take_while(|unit| *unit != TERMINATOR && *unit != 0);
+
+    char::decode_utf16(utf16_iter)
+        .filter_map(|char| char.ok().map(sanitize_char))
+        .collect()
+}
+
+pub fn set_string(data: &mut [u8], value: &str) {
+    set_utf16_string(data, value.chars().map(unsanitize_char));
+
+    let end = data
+        .chunks_exact(2)
+        .position(|chunk| chunk == [0, 0])
+        .unwrap_or(data.len() / 2);
+    if let Some(terminator) = data.get_mut(end * 2..end * 2 + 2) {
+        terminator.copy_from_slice(&TERMINATOR.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_round_trip() {
+        let mut data = [0; 22];
+        set_string(&mut data, "NIDORAN♀");
+        assert_eq!(data[16..18], [0xff, 0xff]);
+        assert_eq!(data[14..16], [0x6e, 0x24]);
+        assert_eq!(get_string(&data), "NIDORAN♀");
+    }
+}
@@ -1,4 +1,4 @@
-use super::string_converter::sanitize_char;
+use super::string_converter::{sanitize_char, set_utf16_string, unsanitize_char};
 use alloc::string::String;
 
 pub fn get_string(data: &[u8]) -> String {
@@ -11,3 +11,7 @@ pub fn get_string(data: &[u8]) -> String {
         .filter_map(|char| char.ok().map(sanitize_char))
         .collect()
 }
+
+pub fn set_string(data: &mut [u8], value: &str) {
+    set_utf16_string(data, value.chars().map(unsanitize_char));
+}
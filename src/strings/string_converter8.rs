@@ -1,3 +1,4 @@
+use super::string_converter::set_utf16_string;
 use alloc::string::String;
 
 pub fn get_string(data: &[u8]) -> String {
@@ -10,3 +11,7 @@ pub fn get_string(data: &[u8]) -> String {
         .filter_map(Result::ok)
         .collect()
 }
+
+pub fn set_string(data: &mut [u8], value: &str) {
+    set_utf16_string(data, value.chars());
+}
@@ -105,7 +105,7 @@ const TABLE: [char; 1575] = [
 ];
 
 fn is_private_char(glyph: u16) -> bool {
-    glyph >= START && glyph <= END
+    (START..=END).contains(&glyph)
 }
 
 pub fn get_unicode_char(glyph: u16) -> u16 {
@@ -0,0 +1,87 @@
+use super::GameVersion;
+use core::fmt;
+use num_enum::FromPrimitive;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromPrimitive)]
+#[repr(u8)]
+pub enum Ball {
+    #[num_enum(default)]
+    None = 0,
+    Master = 1,
+    Ultra = 2,
+    Great = 3,
+    Poke = 4,
+    Safari = 5,
+    Net = 6,
+    Dive = 7,
+    Nest = 8,
+    Repeat = 9,
+    Timer = 10,
+    Luxury = 11,
+    Premier = 12,
+    Dusk = 13,
+    Heal = 14,
+    Quick = 15,
+    Cherish = 16,
+    Fast = 17,
+    Level = 18,
+    Lure = 19,
+    Heavy = 20,
+    Love = 21,
+    Friend = 22,
+    Moon = 23,
+    Sport = 24,
+    Dream = 25,
+    Beast = 26,
+    Strange = 27,
+    HisuiPoke = 28,
+    HisuiGreat = 29,
+    HisuiUltra = 30,
+    HisuiFeather = 31,
+    HisuiWing = 32,
+    HisuiJet = 33,
+    HisuiHeavy = 34,
+    HisuiLeaden = 35,
+    HisuiGigaton = 36,
+    HisuiOrigin = 37,
+}
+
+impl Ball {
+    /// Balls crafted and thrown in Legends: Arceus.
+    pub fn is_hisui(&self) -> bool {
+        (Self::HisuiPoke as u8..=Self::HisuiOrigin as u8).contains(&(*self as u8))
+    }
+
+    /// Whether a Pokemon from `version` can be in this ball.  Legends: Arceus
+    /// only has the Strange Ball and the Hisuian balls, which no other game
+    /// uses.
+    pub fn is_valid_for(&self, version: GameVersion) -> bool {
+        let from_hisui = version == GameVersion::LegendsArceus;
+        match self {
+            Self::None => false,
+            Self::Strange => from_hisui,
+            _ if self.is_hisui() => from_hisui,
+            _ => !from_hisui,
+        }
+    }
+}
+
+impl fmt::Display for Ball {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Self::HisuiPoke => "Poke (Hisui)",
+            Self::HisuiGreat => "Great (Hisui)",
+            Self::HisuiUltra => "Ultra (Hisui)",
+            Self::HisuiFeather => "Feather",
+            Self::HisuiWing => "Wing",
+            Self::HisuiJet => "Jet",
+            Self::HisuiHeavy => "Heavy (Hisui)",
+            Self::HisuiLeaden => "Leaden",
+            Self::HisuiGigaton => "Gigaton",
+            Self::HisuiOrigin => "Origin",
+            _ => return write!(f, "{:?}", self),
+        };
+
+        write!(f, "{}", display)
+    }
+}
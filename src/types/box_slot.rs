@@ -0,0 +1,108 @@
+use core::fmt;
+
+/// A slot in the PC.  `box` is a keyword, so the box is `box_index`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BoxSlot {
+    pub box_index: u8,
+    pub slot: u8,
+}
+
+impl BoxSlot {
+    /// The most boxes any supported game has.
+    pub const BOX_COUNT: u8 = 32;
+    pub const SLOT_COUNT: u8 = 30;
+
+    pub fn new(box_index: u8, slot: u8) -> Self {
+        Self { box_index, slot }
+    }
+
+    /// The slot at a position counted from the first slot of the first box.
+    pub fn from_index(index: usize) -> Self {
+        let slot_count = Self::SLOT_COUNT as usize;
+        Self {
+            box_index: (index / slot_count) as u8,
+            slot: (index % slot_count) as u8,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.box_index as usize * Self::SLOT_COUNT as usize + self.slot as usize
+    }
+
+    /// The next slot, moving to the next box after the last slot and back to
+    /// the first box after the last one.
+    #[must_use]
+    pub fn increment(&self) -> Self {
+        self.increment_within(Self::BOX_COUNT)
+    }
+
+    #[must_use]
+    pub fn decrement(&self) -> Self {
+        self.decrement_within(Self::BOX_COUNT)
+    }
+
+    /// Like [increment](Self::increment), for games with fewer boxes.  With
+    /// no boxes there's nowhere to move, so the slot stays where it is.
+    #[must_use]
+    pub fn increment_within(&self, box_count: u8) -> Self {
+        let total = box_count as usize * Self::SLOT_COUNT as usize;
+        if total == 0 {
+            return *self;
+        }
+        Self::from_index((self.index() + 1) % total)
+    }
+
+    #[must_use]
+    pub fn decrement_within(&self, box_count: u8) -> Self {
+        let total = box_count as usize * Self::SLOT_COUNT as usize;
+        if total == 0 {
+            return *self;
+        }
+        Self::from_index((self.index() + total - 1) % total)
+    }
+}
+
+impl fmt::Display for BoxSlot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Box {} Slot {}", self.box_index + 1, self.slot + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn should_wrap_around() {
+        assert_eq!(BoxSlot::new(0, 29).increment(), BoxSlot::new(1, 0));
+        assert_eq!(BoxSlot::new(31, 29).increment(), BoxSlot::new(0, 0));
+        assert_eq!(BoxSlot::new(0, 0).decrement(), BoxSlot::new(31, 29));
+        assert_eq!(BoxSlot::new(2, 0).decrement(), BoxSlot::new(1, 29));
+    }
+
+    #[test]
+    fn should_wrap_around_fewer_boxes() {
+        assert_eq!(
+            BoxSlot::new(30, 29).increment_within(31),
+            BoxSlot::new(0, 0)
+        );
+        assert_eq!(
+            BoxSlot::new(0, 0).decrement_within(31),
+            BoxSlot::new(30, 29)
+        );
+    }
+
+    #[test]
+    fn should_stay_put_without_boxes() {
+        assert_eq!(BoxSlot::new(1, 2).increment_within(0), BoxSlot::new(1, 2));
+        assert_eq!(BoxSlot::new(1, 2).decrement_within(0), BoxSlot::new(1, 2));
+    }
+
+    #[test]
+    fn should_convert_indexes() {
+        assert_eq!(BoxSlot::from_index(31), BoxSlot::new(1, 1));
+        assert_eq!(BoxSlot::new(1, 1).index(), 31);
+        assert_eq!(BoxSlot::new(0, 4).to_string(), "Box 1 Slot 5");
+    }
+}
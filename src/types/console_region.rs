@@ -0,0 +1,21 @@
+use core::fmt;
+use num_enum::FromPrimitive;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromPrimitive)]
+#[repr(u8)]
+pub enum ConsoleRegion {
+    Japan = 0,
+    Americas = 1,
+    Europe = 2,
+    China = 4,
+    Korea = 5,
+    Taiwan = 6,
+    #[num_enum(default)]
+    Invalid = 255,
+}
+
+impl fmt::Display for ConsoleRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
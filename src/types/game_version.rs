@@ -0,0 +1,88 @@
+use core::fmt;
+use num_enum::FromPrimitive;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromPrimitive)]
+#[repr(u8)]
+pub enum GameVersion {
+    #[num_enum(default)]
+    Invalid = 0,
+    Sapphire = 1,
+    Ruby = 2,
+    Emerald = 3,
+    FireRed = 4,
+    LeafGreen = 5,
+    HeartGold = 7,
+    SoulSilver = 8,
+    Diamond = 10,
+    Pearl = 11,
+    Platinum = 12,
+    ColosseumXd = 15,
+    White = 20,
+    Black = 21,
+    White2 = 22,
+    Black2 = 23,
+    X = 24,
+    Y = 25,
+    AlphaSapphire = 26,
+    OmegaRuby = 27,
+    Sun = 30,
+    Moon = 31,
+    UltraSun = 32,
+    UltraMoon = 33,
+    Go = 34,
+    Red = 35,
+    Green = 36,
+    Blue = 37,
+    Yellow = 38,
+    Gold = 39,
+    Silver = 40,
+    Crystal = 41,
+    LetsGoPikachu = 42,
+    LetsGoEevee = 43,
+    Sword = 44,
+    Shield = 45,
+    LegendsArceus = 47,
+    BrilliantDiamond = 48,
+    ShiningPearl = 49,
+    Scarlet = 50,
+    Violet = 51,
+}
+
+impl GameVersion {
+    /// The generation a game belongs to, or 0 for unknown versions.
+    pub fn generation(&self) -> u8 {
+        match self {
+            Self::Red | Self::Green | Self::Blue | Self::Yellow => 1,
+            Self::Gold | Self::Silver | Self::Crystal => 2,
+            Self::Sapphire
+            | Self::Ruby
+            | Self::Emerald
+            | Self::FireRed
+            | Self::LeafGreen
+            | Self::ColosseumXd => 3,
+            Self::HeartGold | Self::SoulSilver | Self::Diamond | Self::Pearl | Self::Platinum => 4,
+            Self::White | Self::Black | Self::White2 | Self::Black2 => 5,
+            Self::X | Self::Y | Self::AlphaSapphire | Self::OmegaRuby => 6,
+            Self::Sun
+            | Self::Moon
+            | Self::UltraSun
+            | Self::UltraMoon
+            | Self::Go
+            | Self::LetsGoPikachu
+            | Self::LetsGoEevee => 7,
+            Self::Sword
+            | Self::Shield
+            | Self::LegendsArceus
+            | Self::BrilliantDiamond
+            | Self::ShiningPearl => 8,
+            Self::Scarlet | Self::Violet => 9,
+            Self::Invalid => 0,
+        }
+    }
+}
+
+impl fmt::Display for GameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
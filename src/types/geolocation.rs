@@ -0,0 +1,12 @@
+/// A country and region pair from a 3DS-era Pokemon's geolocation history.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Geolocation {
+    pub country: u8,
+    pub region: u8,
+}
+
+impl Geolocation {
+    pub fn is_empty(&self) -> bool {
+        self.country == 0
+    }
+}
@@ -0,0 +1,59 @@
+use core::fmt;
+use num_enum::FromPrimitive;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromPrimitive)]
+#[repr(u8)]
+pub enum GrowthRate {
+    #[num_enum(default)]
+    MediumFast = 0,
+    Erratic = 1,
+    Fluctuating = 2,
+    MediumSlow = 3,
+    Fast = 4,
+    Slow = 5,
+}
+
+impl GrowthRate {
+    /// The experience needed to reach `level`.
+    pub fn exp_for_level(&self, level: u8) -> u32 {
+        let n = u64::from(level.clamp(1, 100));
+        if n == 1 {
+            return 0;
+        }
+
+        let cube = n * n * n;
+        let exp = match self {
+            Self::MediumFast => cube,
+            Self::Erratic => match n {
+                0..=49 => cube * (100 - n) / 50,
+                50..=67 => cube * (150 - n) / 100,
+                68..=97 => cube * ((1911 - 10 * n) / 3) / 500,
+                _ => cube * (160 - n) / 100,
+            },
+            Self::Fluctuating => match n {
+                0..=14 => cube * ((n + 1) / 3 + 24) / 50,
+                15..=35 => cube * (n + 14) / 50,
+                _ => cube * (n / 2 + 32) / 50,
+            },
+            Self::MediumSlow => (6 * cube / 5 + 100 * n) - (15 * n * n + 140),
+            Self::Fast => 4 * cube / 5,
+            Self::Slow => 5 * cube / 4,
+        };
+
+        exp as u32
+    }
+
+    /// The level a Pokemon with `exp` experience is at.
+    pub fn level_for_exp(&self, exp: u32) -> u8 {
+        (2..=100)
+            .take_while(|level| self.exp_for_level(*level) <= exp)
+            .last()
+            .unwrap_or(1)
+    }
+}
+
+impl fmt::Display for GrowthRate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
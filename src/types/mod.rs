@@ -17,6 +17,9 @@ pub use species::*;
 mod party_slot;
 pub use party_slot::*;
 
+mod box_slot;
+pub use box_slot::*;
+
 mod hidden_power;
 pub use hidden_power::*;
 
@@ -28,3 +31,27 @@ pub use gender_ratio::*;
 
 mod shiny;
 pub use shiny::*;
+
+mod game_version;
+pub use game_version::*;
+
+mod ribbon;
+pub use ribbon::*;
+
+mod tera_type;
+pub use tera_type::*;
+
+mod ball;
+pub use ball::*;
+
+mod console_region;
+pub use console_region::*;
+
+mod geolocation;
+pub use geolocation::*;
+
+mod growth_rate;
+pub use growth_rate::*;
+
+mod trainer_info;
+pub use trainer_info::*;
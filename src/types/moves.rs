@@ -909,6 +909,115 @@ pub enum Move {
     MagicalTorque = 900,
 }
 
+#[rustfmt::skip]
+const MOVE_PP: [u8; 901] = [
+    0, 35, 25, 10, 15, 20, 20, 15, 15, 15,
+    35, 30, 5, 10, 20, 30, 35, 35, 20, 15,
+    20, 20, 25, 20, 30, 5, 10, 15, 15, 15,
+    25, 20, 5, 35, 15, 20, 20, 10, 15, 30,
+    35, 20, 20, 30, 25, 40, 20, 15, 20, 20,
+    20, 30, 25, 15, 30, 25, 5, 15, 10, 5,
+    20, 20, 20, 5, 35, 20, 20, 20, 20, 20,
+    15, 25, 15, 10, 20, 25, 10, 35, 30, 15,
+    10, 40, 10, 15, 30, 15, 20, 10, 15, 10,
+    5, 10, 10, 25, 10, 20, 40, 30, 30, 20,
+    20, 15, 10, 40, 15, 10, 30, 10, 20, 10,
+    40, 40, 20, 30, 30, 20, 30, 10, 10, 20,
+    5, 10, 30, 20, 20, 20, 5, 15, 15, 20,
+    10, 15, 35, 20, 15, 10, 10, 30, 15, 40,
+    20, 10, 10, 5, 10, 30, 10, 15, 20, 15,
+    40, 20, 10, 5, 15, 10, 5, 10, 15, 30,
+    30, 10, 10, 20, 10, 1, 1, 10, 25, 10,
+    5, 15, 25, 15, 10, 15, 30, 5, 40, 15,
+    10, 25, 10, 30, 10, 20, 10, 10, 10, 10,
+    10, 20, 5, 40, 5, 5, 15, 5, 10, 5,
+    10, 10, 10, 10, 20, 20, 40, 15, 10, 20,
+    20, 25, 5, 15, 10, 5, 20, 15, 20, 25,
+    20, 5, 30, 5, 10, 20, 40, 5, 20, 40,
+    20, 15, 35, 10, 5, 5, 5, 15, 5, 20,
+    5, 5, 15, 20, 10, 5, 5, 15, 10, 15,
+    15, 10, 10, 10, 20, 10, 10, 10, 10, 15,
+    15, 15, 10, 20, 20, 10, 20, 20, 20, 20,
+    20, 10, 10, 10, 20, 20, 5, 15, 10, 10,
+    15, 10, 20, 5, 5, 10, 10, 20, 5, 10,
+    20, 10, 20, 20, 20, 5, 5, 15, 20, 10,
+    15, 20, 15, 10, 10, 15, 10, 5, 5, 10,
+    15, 10, 5, 20, 25, 5, 40, 15, 5, 40,
+    15, 20, 20, 5, 15, 20, 20, 15, 15, 5,
+    10, 30, 20, 30, 15, 5, 40, 15, 5, 20,
+    5, 15, 25, 25, 15, 20, 15, 20, 15, 20,
+    10, 20, 20, 5, 5, 10, 5, 40, 10, 10,
+    5, 10, 10, 15, 10, 20, 15, 30, 10, 20,
+    5, 10, 10, 15, 10, 10, 5, 15, 5, 10,
+    10, 30, 20, 20, 10, 10, 5, 5, 10, 5,
+    20, 10, 20, 10, 15, 10, 20, 20, 20, 15,
+    15, 10, 15, 15, 15, 10, 10, 10, 20, 10,
+    30, 5, 10, 15, 10, 10, 5, 20, 30, 10,
+    30, 15, 15, 15, 15, 30, 10, 20, 15, 10,
+    10, 20, 15, 5, 5, 15, 15, 5, 10, 5,
+    20, 5, 15, 20, 5, 20, 20, 20, 20, 10,
+    20, 10, 15, 20, 15, 10, 10, 5, 10, 5,
+    5, 10, 5, 5, 10, 5, 5, 5, 15, 10,
+    10, 10, 10, 10, 10, 15, 20, 15, 10, 15,
+    10, 15, 10, 20, 10, 10, 10, 20, 20, 20,
+    20, 20, 15, 15, 15, 15, 15, 15, 20, 15,
+    10, 15, 15, 15, 15, 10, 10, 10, 10, 10,
+    15, 15, 15, 15, 5, 5, 15, 5, 10, 10,
+    10, 20, 20, 20, 10, 10, 30, 15, 15, 10,
+    15, 25, 10, 15, 10, 10, 10, 20, 10, 10,
+    10, 10, 10, 15, 15, 5, 5, 10, 10, 10,
+    5, 5, 10, 5, 5, 15, 10, 5, 5, 5,
+    10, 10, 10, 10, 20, 25, 10, 20, 30, 25,
+    20, 20, 15, 20, 15, 20, 20, 10, 10, 10,
+    10, 10, 20, 10, 30, 15, 10, 10, 10, 20,
+    20, 5, 5, 5, 20, 10, 10, 20, 15, 20,
+    20, 10, 20, 30, 10, 10, 40, 40, 30, 20,
+    40, 20, 20, 10, 10, 10, 10, 5, 10, 10,
+    5, 5, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    40, 15, 20, 30, 20, 15, 15, 20, 10, 15,
+    15, 10, 5, 10, 10, 20, 15, 10, 15, 15,
+    15, 5, 15, 20, 20, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 5, 5, 10, 10, 10, 20,
+    10, 10, 10, 5, 5, 20, 10, 10, 10, 1,
+    5, 15, 5, 1, 1, 1, 1, 1, 1, 10,
+    15, 15, 20, 20, 20, 20, 15, 15, 15, 15,
+    15, 20, 5, 10, 5, 15, 10, 10, 5, 15,
+    20, 10, 10, 15, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 5, 10, 15, 10, 15,
+    5, 5, 5, 10, 15, 40, 10, 10, 10, 15,
+    10, 10, 10, 10, 5, 5, 5, 10, 5, 20,
+    10, 10, 5, 20, 20, 10, 10, 5, 5, 5,
+    40, 10, 20, 10, 10, 10, 10, 5, 5, 15,
+    5, 10, 10, 10, 5, 5, 5, 15, 10, 10,
+    15, 5, 10, 10, 10, 5, 10, 10, 5, 10,
+    10, 10, 10, 10, 15, 15, 10, 10, 10, 5,
+    10, 10, 10, 10, 10, 10, 10, 15, 15, 5,
+    10, 15, 5, 1, 15, 10, 15, 10, 10, 10,
+    10, 10, 10, 10, 5, 15, 15, 10, 5, 5,
+    10, 10, 10, 10, 20, 20, 20, 5, 10, 10,
+    5, 10, 5, 5, 10, 20, 10, 10, 10, 10,
+    10,
+];
+
+impl Move {
+    /// The move's PP before any PP Ups, as of Sword and Shield.
+    pub fn base_pp(&self) -> u8 {
+        MOVE_PP[*self as usize]
+    }
+
+    /// The move's PP with `pp_ups` PP Ups applied, each adding a fifth of the
+    /// base PP.
+    pub fn max_pp(&self, pp_ups: u8) -> u8 {
+        let base_pp = self.base_pp();
+        base_pp + base_pp * pp_ups.min(3) / 5
+    }
+}
+
 impl fmt::Display for Move {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let display = match self {
@@ -1581,3 +1690,17 @@ impl fmt::Display for Move {
         write!(f, "{}", display)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_apply_pp_ups() {
+        assert_eq!(Move::Tackle.base_pp(), 35);
+        assert_eq!(Move::Tackle.max_pp(3), 56);
+        assert_eq!(Move::HydroPump.max_pp(1), 6);
+        assert_eq!(Move::Sketch.max_pp(3), 1);
+        assert_eq!(Move::None.max_pp(3), 0);
+    }
+}
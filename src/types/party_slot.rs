@@ -1,8 +1,9 @@
 use core::fmt;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[repr(usize)]
 pub enum PartySlot {
+    #[default]
     Slot1 = 0,
     Slot2 = 1,
     Slot3 = 2,
@@ -51,9 +52,3 @@ impl fmt::Display for PartySlot {
         write!(f, "{}", display)
     }
 }
-
-impl Default for PartySlot {
-    fn default() -> Self {
-        Self::Slot1
-    }
-}
@@ -0,0 +1,267 @@
+use core::fmt;
+use num_enum::TryFromPrimitive;
+
+/// Every ribbon and mark, numbered by its flag index.
+///
+/// Gen6+ formats store ribbons as a bitfield in this order, so a ribbon's
+/// value is also the bit it occupies in the ribbon flags.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum Ribbon {
+    ChampionKalos = 0,
+    ChampionG3 = 1,
+    ChampionSinnoh = 2,
+    BestFriends = 3,
+    Training = 4,
+    BattlerSkillful = 5,
+    BattlerExpert = 6,
+    Effort = 7,
+    Alert = 8,
+    Shock = 9,
+    Downcast = 10,
+    Careless = 11,
+    Relax = 12,
+    Snooze = 13,
+    Smile = 14,
+    Gorgeous = 15,
+    Royal = 16,
+    GorgeousRoyal = 17,
+    Artist = 18,
+    Footprint = 19,
+    Record = 20,
+    Legend = 21,
+    Country = 22,
+    National = 23,
+    Earth = 24,
+    World = 25,
+    Classic = 26,
+    Premier = 27,
+    Event = 28,
+    Birthday = 29,
+    Special = 30,
+    Souvenir = 31,
+    Wishing = 32,
+    ChampionBattle = 33,
+    ChampionRegional = 34,
+    ChampionNational = 35,
+    ChampionWorld = 36,
+    CountMemoryContest = 37,
+    CountMemoryBattle = 38,
+    ChampionG6Hoenn = 39,
+    ContestStar = 40,
+    MasterCoolness = 41,
+    MasterBeauty = 42,
+    MasterCuteness = 43,
+    MasterCleverness = 44,
+    MasterToughness = 45,
+    ChampionAlola = 46,
+    BattleRoyale = 47,
+    BattleTreeGreat = 48,
+    BattleTreeMaster = 49,
+    ChampionGalar = 50,
+    TowerMaster = 51,
+    MasterRank = 52,
+    MarkLunchtime = 53,
+    MarkSleepyTime = 54,
+    MarkDusk = 55,
+    MarkDawn = 56,
+    MarkCloudy = 57,
+    MarkRainy = 58,
+    MarkStormy = 59,
+    MarkSnowy = 60,
+    MarkBlizzard = 61,
+    MarkDry = 62,
+    MarkSandstorm = 63,
+    MarkMisty = 64,
+    MarkDestiny = 65,
+    MarkFishing = 66,
+    MarkCurry = 67,
+    MarkUncommon = 68,
+    MarkRare = 69,
+    MarkRowdy = 70,
+    MarkAbsentMinded = 71,
+    MarkJittery = 72,
+    MarkExcited = 73,
+    MarkCharismatic = 74,
+    MarkCalmness = 75,
+    MarkIntense = 76,
+    MarkZonedOut = 77,
+    MarkJoyful = 78,
+    MarkAngry = 79,
+    MarkSmiley = 80,
+    MarkTeary = 81,
+    MarkUpbeat = 82,
+    MarkPeeved = 83,
+    MarkIntellectual = 84,
+    MarkFerocious = 85,
+    MarkCrafty = 86,
+    MarkScowling = 87,
+    MarkKindly = 88,
+    MarkFlustered = 89,
+    MarkPumpedUp = 90,
+    MarkZeroEnergy = 91,
+    MarkPrideful = 92,
+    MarkUnsure = 93,
+    MarkHumble = 94,
+    MarkThorny = 95,
+    MarkVigor = 96,
+    MarkSlump = 97,
+    Hisui = 98,
+    TwinklingStar = 99,
+    ChampionPaldea = 100,
+    MarkJumbo = 101,
+    MarkMini = 102,
+    MarkItemfinder = 103,
+    MarkPartner = 104,
+    MarkGourmand = 105,
+    OnceInALifetime = 106,
+    MarkAlpha = 107,
+    MarkMightiest = 108,
+    MarkTitan = 109,
+    Partner = 110,
+}
+
+impl Ribbon {
+    pub const COUNT: u8 = 111;
+
+    /// Iterates over every ribbon in flag order.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        (0..Self::COUNT).filter_map(|index| Self::try_from(index).ok())
+    }
+
+    pub fn is_mark(&self) -> bool {
+        let index = *self as u8;
+        (Self::MarkLunchtime as u8..=Self::MarkSlump as u8).contains(&index)
+            || matches!(
+                self,
+                Self::MarkJumbo
+                    | Self::MarkMini
+                    | Self::MarkItemfinder
+                    | Self::MarkPartner
+                    | Self::MarkGourmand
+                    | Self::MarkAlpha
+                    | Self::MarkMightiest
+                    | Self::MarkTitan
+            )
+    }
+}
+
+impl fmt::Display for Ribbon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Self::ChampionKalos => "Kalos Champion Ribbon",
+            Self::ChampionG3 => "Champion Ribbon",
+            Self::ChampionSinnoh => "Sinnoh Champion Ribbon",
+            Self::BestFriends => "Best Friends Ribbon",
+            Self::Training => "Training Ribbon",
+            Self::BattlerSkillful => "Skillful Battler Ribbon",
+            Self::BattlerExpert => "Expert Battler Ribbon",
+            Self::Effort => "Effort Ribbon",
+            Self::Alert => "Alert Ribbon",
+            Self::Shock => "Shock Ribbon",
+            Self::Downcast => "Downcast Ribbon",
+            Self::Careless => "Careless Ribbon",
+            Self::Relax => "Relax Ribbon",
+            Self::Snooze => "Snooze Ribbon",
+            Self::Smile => "Smile Ribbon",
+            Self::Gorgeous => "Gorgeous Ribbon",
+            Self::Royal => "Royal Ribbon",
+            Self::GorgeousRoyal => "Gorgeous Royal Ribbon",
+            Self::Artist => "Artist Ribbon",
+            Self::Footprint => "Footprint Ribbon",
+            Self::Record => "Record Ribbon",
+            Self::Legend => "Legend Ribbon",
+            Self::Country => "Country Ribbon",
+            Self::National => "National Ribbon",
+            Self::Earth => "Earth Ribbon",
+            Self::World => "World Ribbon",
+            Self::Classic => "Classic Ribbon",
+            Self::Premier => "Premier Ribbon",
+            Self::Event => "Event Ribbon",
+            Self::Birthday => "Birthday Ribbon",
+            Self::Special => "Special Ribbon",
+            Self::Souvenir => "Souvenir Ribbon",
+            Self::Wishing => "Wishing Ribbon",
+            Self::ChampionBattle => "Battle Champion Ribbon",
+            Self::ChampionRegional => "Regional Champion Ribbon",
+            Self::ChampionNational => "National Champion Ribbon",
+            Self::ChampionWorld => "World Champion Ribbon",
+            Self::CountMemoryContest => "Contest Memory Ribbon",
+            Self::CountMemoryBattle => "Battle Memory Ribbon",
+            Self::ChampionG6Hoenn => "Hoenn Champion Ribbon",
+            Self::ContestStar => "Contest Star Ribbon",
+            Self::MasterCoolness => "Coolness Master Ribbon",
+            Self::MasterBeauty => "Beauty Master Ribbon",
+            Self::MasterCuteness => "Cuteness Master Ribbon",
+            Self::MasterCleverness => "Cleverness Master Ribbon",
+            Self::MasterToughness => "Toughness Master Ribbon",
+            Self::ChampionAlola => "Alola Champion Ribbon",
+            Self::BattleRoyale => "Battle Royal Master Ribbon",
+            Self::BattleTreeGreat => "Battle Tree Great Ribbon",
+            Self::BattleTreeMaster => "Battle Tree Master Ribbon",
+            Self::ChampionGalar => "Galar Champion Ribbon",
+            Self::TowerMaster => "Tower Master Ribbon",
+            Self::MasterRank => "Master Rank Ribbon",
+            Self::MarkLunchtime => "Lunchtime Mark",
+            Self::MarkSleepyTime => "Sleepy-Time Mark",
+            Self::MarkDusk => "Dusk Mark",
+            Self::MarkDawn => "Dawn Mark",
+            Self::MarkCloudy => "Cloudy Mark",
+            Self::MarkRainy => "Rainy Mark",
+            Self::MarkStormy => "Stormy Mark",
+            Self::MarkSnowy => "Snowy Mark",
+            Self::MarkBlizzard => "Blizzard Mark",
+            Self::MarkDry => "Dry Mark",
+            Self::MarkSandstorm => "Sandstorm Mark",
+            Self::MarkMisty => "Misty Mark",
+            Self::MarkDestiny => "Destiny Mark",
+            Self::MarkFishing => "Fishing Mark",
+            Self::MarkCurry => "Curry Mark",
+            Self::MarkUncommon => "Uncommon Mark",
+            Self::MarkRare => "Rare Mark",
+            Self::MarkRowdy => "Rowdy Mark",
+            Self::MarkAbsentMinded => "Absent-Minded Mark",
+            Self::MarkJittery => "Jittery Mark",
+            Self::MarkExcited => "Excited Mark",
+            Self::MarkCharismatic => "Charismatic Mark",
+            Self::MarkCalmness => "Calmness Mark",
+            Self::MarkIntense => "Intense Mark",
+            Self::MarkZonedOut => "Zoned-Out Mark",
+            Self::MarkJoyful => "Joyful Mark",
+            Self::MarkAngry => "Angry Mark",
+            Self::MarkSmiley => "Smiley Mark",
+            Self::MarkTeary => "Teary Mark",
+            Self::MarkUpbeat => "Upbeat Mark",
+            Self::MarkPeeved => "Peeved Mark",
+            Self::MarkIntellectual => "Intellectual Mark",
+            Self::MarkFerocious => "Ferocious Mark",
+            Self::MarkCrafty => "Crafty Mark",
+            Self::MarkScowling => "Scowling Mark",
+            Self::MarkKindly => "Kindly Mark",
+            Self::MarkFlustered => "Flustered Mark",
+            Self::MarkPumpedUp => "Pumped-Up Mark",
+            Self::MarkZeroEnergy => "Zero Energy Mark",
+            Self::MarkPrideful => "Prideful Mark",
+            Self::MarkUnsure => "Unsure Mark",
+            Self::MarkHumble => "Humble Mark",
+            Self::MarkThorny => "Thorny Mark",
+            Self::MarkVigor => "Vigor Mark",
+            Self::MarkSlump => "Slump Mark",
+            Self::Hisui => "Hisui Ribbon",
+            Self::TwinklingStar => "Twinkling Star Ribbon",
+            Self::ChampionPaldea => "Paldea Champion Ribbon",
+            Self::MarkJumbo => "Jumbo Mark",
+            Self::MarkMini => "Mini Mark",
+            Self::MarkItemfinder => "Itemfinder Mark",
+            Self::MarkPartner => "Partner Mark",
+            Self::MarkGourmand => "Gourmand Mark",
+            Self::OnceInALifetime => "Once-in-a-Lifetime Ribbon",
+            Self::MarkAlpha => "Alpha Mark",
+            Self::MarkMightiest => "Mightiest Mark",
+            Self::MarkTitan => "Titan Mark",
+            Self::Partner => "Partner Ribbon",
+        };
+
+        write!(f, "{}", display)
+    }
+}
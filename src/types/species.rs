@@ -1029,7 +1029,7 @@ impl Species {
 impl fmt::Display for Species {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let display = match self {
-            Self::FarfetchD => "Farfetch'd".to_string(),
+            Self::FarfetchD => "Farfetch’d".to_string(),
             Self::MrMime => "Mr. Mime".to_string(),
             Self::HoOh => "Ho-Oh".to_string(),
             Self::MimeJr => "Mime Jr.".to_string(),
@@ -1043,7 +1043,7 @@ impl fmt::Display for Species {
             Self::TapuLele => "Tapu Lele".to_string(),
             Self::TapuBulu => "Tapu Bulu".to_string(),
             Self::TapuFini => "Tapu Fini".to_string(),
-            Self::SirfetchD => "Sirfetch'd".to_string(),
+            Self::SirfetchD => "Sirfetch’d".to_string(),
             Self::MrRime => "Mr. Rime".to_string(),
             Self::GreatTusk => "Great Tusk".to_string(),
             Self::BruteBonnet => "Brute Bonnet".to_string(),
@@ -0,0 +1,39 @@
+use core::fmt;
+use num_enum::FromPrimitive;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromPrimitive)]
+#[repr(u8)]
+pub enum TeraType {
+    Normal = 0,
+    Fighting = 1,
+    Flying = 2,
+    Poison = 3,
+    Ground = 4,
+    Rock = 5,
+    Bug = 6,
+    Ghost = 7,
+    Steel = 8,
+    Fire = 9,
+    Water = 10,
+    Grass = 11,
+    Electric = 12,
+    Psychic = 13,
+    Ice = 14,
+    Dragon = 15,
+    Dark = 16,
+    Fairy = 17,
+    Stellar = 99,
+    #[num_enum(default)]
+    Invalid = 255,
+}
+
+impl TeraType {
+    /// Stored in the override slot when no override is applied.
+    pub const OVERRIDE_NONE: u8 = 19;
+}
+
+impl fmt::Display for TeraType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,19 @@
+use super::GameVersion;
+use alloc::string::String;
+
+/// The trainer receiving, transferring or trading a Pokemon.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrainerInfo {
+    pub ot_name: String,
+    pub tid16: u16,
+    pub sid16: u16,
+    pub gender: u8,
+    pub language: u8,
+    pub version: GameVersion,
+}
+
+impl TrainerInfo {
+    pub fn tsv(&self) -> u16 {
+        (self.tid16 ^ self.sid16) >> 4
+    }
+}
@@ -0,0 +1,455 @@
+mod wa8;
+mod wc6;
+mod wc7;
+mod wc8;
+mod wc9;
+
+pub use wa8::*;
+pub use wc6::*;
+pub use wc7::*;
+pub use wc8::*;
+pub use wc9::*;
+
+use crate::locations;
+use crate::personal::personal_info;
+use crate::pkx::PkxMut;
+use crate::reader::Reader;
+use crate::species_names::{egg_name, species_name};
+use crate::types::{Ball, GameVersion, Gender, GenderRatio, Language, Move, Species, TrainerInfo};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// What a wondercard gives the player.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GiftType {
+    Pokemon,
+    Item,
+    BattlePoints,
+    /// Poke Beans for Poke Pelago.
+    Bean,
+    Clothing,
+    /// An O-Power.
+    Power,
+    Unknown,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GiftItem {
+    pub item: u16,
+    pub quantity: u16,
+}
+
+/// How the PID of a gifted Pokemon is decided.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GiftShiny {
+    /// The PID stored in the card.
+    Fixed,
+    Random,
+    Never,
+    /// Shiny, with a star shine on games that tell the two apart.
+    Always,
+    AlwaysStar,
+    AlwaysSquare,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WondercardError {
+    /// The card gives an item or something else that isn't a Pokemon.
+    NotPokemon,
+    /// The species has no personal data, such as an out of range species.
+    UnknownSpecies(Species),
+}
+
+/// Reads the contents of a wondercard (`WC6`, `WC7`, `WC8`, `WA8` or `WC9`).
+pub trait Wondercard: Reader {
+    type Pkm: PkxMut + Default;
+
+    fn card_id(&self) -> u16;
+    /// The card title.  Gen8 and later cards only store an index into the
+    /// game's text, so this is `None` for them.
+    fn title(&self) -> Option<String>;
+    fn gift_type(&self) -> GiftType;
+    fn items(&self) -> Vec<GiftItem>;
+
+    fn battle_points(&self) -> u16 {
+        0
+    }
+
+    fn tid16(&self) -> u16;
+    fn sid16(&self) -> u16;
+    /// The game the Pokemon comes from, or 0 for the receiving game.
+    fn origin_game(&self) -> u8;
+    fn encryption_constant(&self) -> u32;
+    fn pid(&self) -> u32;
+    fn gift_shiny(&self) -> GiftShiny;
+    fn ball(&self) -> u8;
+    fn held_item(&self) -> u16;
+    fn move1(&self) -> u16;
+    fn move2(&self) -> u16;
+    fn move3(&self) -> u16;
+    fn move4(&self) -> u16;
+    fn relearn_move1(&self) -> u16;
+    fn relearn_move2(&self) -> u16;
+    fn relearn_move3(&self) -> u16;
+    fn relearn_move4(&self) -> u16;
+    fn species(&self) -> u16;
+    fn form(&self) -> u8;
+    fn level(&self) -> u8;
+    fn met_level(&self) -> u8;
+    fn is_egg(&self) -> bool;
+    /// The nature, or 0xff for a random one.
+    fn nature(&self) -> u8;
+    /// The gender, or 3 for one decided by the species' gender ratio.
+    fn gender(&self) -> u8;
+    /// 0-2 for a fixed ability, 3 for a random first or second ability and 4
+    /// for any ability, including the hidden one.
+    fn ability_type(&self) -> u8;
+    fn met_location(&self) -> u16;
+    fn egg_location(&self) -> u16;
+    /// IVs in HP, Atk, Def, Spe, SpA, SpD order.  Values above 31 are random,
+    /// and 0xfc-0xfe in the first slot guarantee 1-3 perfect IVs.
+    fn ivs(&self) -> [u8; 6];
+    /// EVs in HP, Atk, Def, Spe, SpA, SpD order.
+    fn evs(&self) -> [u8; 6];
+    fn nickname(&self, language: u8) -> String;
+    fn ot_name(&self, language: u8) -> String;
+    fn ot_gender(&self) -> u8;
+    fn ot_intensity(&self) -> u8;
+    fn ot_memory(&self) -> u8;
+    fn ot_text_var(&self) -> u16;
+    fn ot_feeling(&self) -> u8;
+
+    /// The language of the Pokemon, or 0 for the receiving game's language.
+    fn language(&self) -> u8 {
+        0
+    }
+
+    fn species_t(&self) -> Species {
+        self.species().into()
+    }
+
+    fn moves(&self) -> [Move; 4] {
+        [self.move1(), self.move2(), self.move3(), self.move4()].map(Move::from)
+    }
+
+    fn relearn_moves(&self) -> [Move; 4] {
+        [
+            self.relearn_move1(),
+            self.relearn_move2(),
+            self.relearn_move3(),
+            self.relearn_move4(),
+        ]
+        .map(Move::from)
+    }
+
+    fn is_pokemon(&self) -> bool {
+        self.gift_type() == GiftType::Pokemon
+    }
+
+    /// Sets the fields only one format has, e.g. contest stats or Tera Types.
+    fn apply_format(&self, _pkm: &mut Self::Pkm, _rand: &mut dyn FnMut() -> u32) {}
+
+    /// Generates the Pokemon the way the game does when the gift is received.
+    ///
+    /// `rand` provides the random values for everything the card leaves
+    /// open.
+    fn convert_to_pkm(
+        &self,
+        trainer: &TrainerInfo,
+        rand: &mut dyn FnMut() -> u32,
+    ) -> Result<Self::Pkm, WondercardError> {
+        if !self.is_pokemon() {
+            return Err(WondercardError::NotPokemon);
+        }
+
+        let species = self.species_t();
+        let personal =
+            personal_info(species, self.form()).ok_or(WondercardError::UnknownSpecies(species))?;
+
+        let mut pkm = Self::Pkm::default();
+        let language = match self.language() {
+            0 => trainer.language,
+            language => language,
+        };
+
+        let is_egg = self.is_egg();
+        let level = if is_egg { 1 } else { self.level() };
+
+        pkm.set_species(self.species());
+        pkm.set_form(self.form());
+        pkm.set_held_item(self.held_item());
+        pkm.set_exp(personal.growth_rate.exp_for_level(level));
+        pkm.set_moves(self.moves());
+        pkm.set_relearn_moves(self.relearn_moves());
+        pkm.heal_pp();
+        pkm.set_language(language);
+        pkm.set_ball(match self.ball() {
+            0 => Ball::Poke as u8,
+            ball => ball,
+        });
+        let version = match self.origin_game() {
+            0 => trainer.version as u8,
+            version => version,
+        };
+        pkm.set_version(version);
+        let friendship = personal.friendship(GameVersion::from(version).generation());
+
+        // Eggs aren't met until they hatch, and count their egg cycles down in
+        // their friendship.
+        if is_egg {
+            pkm.set_met_level(1);
+            pkm.set_met_location(locations::NO_LOCATION);
+            pkm.set_egg_location(match self.egg_location() {
+                locations::NO_LOCATION => locations::LINK_TRADE,
+                location => location,
+            });
+            pkm.set_ot_friendship(personal.hatch_cycles());
+        } else {
+            pkm.set_met_level(self.met_level());
+            pkm.set_met_location(self.met_location());
+            pkm.set_egg_location(self.egg_location());
+            pkm.set_ot_friendship(friendship);
+        }
+
+        let ot_name = self.ot_name(language);
+        let (tid16, sid16) = if ot_name.is_empty() {
+            pkm.set_ot_name(&trainer.ot_name);
+            pkm.set_ot_gender(trainer.gender);
+            (trainer.tid16, trainer.sid16)
+        } else {
+            pkm.set_ot_name(&ot_name);
+            pkm.set_ot_gender(self.ot_gender());
+            pkm.set_ht_name(&trainer.ot_name);
+            pkm.set_ht_gender(trainer.gender);
+            pkm.set_ht_friendship(friendship);
+            pkm.set_current_handler(1);
+            (self.tid16(), self.sid16())
+        };
+        pkm.set_tid16(tid16);
+        pkm.set_sid16(sid16);
+        pkm.set_ot_intensity(self.ot_intensity());
+        pkm.set_ot_memory(self.ot_memory());
+        pkm.set_ot_text_var(self.ot_text_var());
+        pkm.set_ot_feeling(self.ot_feeling());
+
+        // Korean and Chinese names aren't known, so those fall back to English.
+        let nickname = self.nickname(language);
+        if is_egg {
+            let name = egg_name(Language::from(language))
+                .or_else(|| egg_name(Language::English))
+                .unwrap_or_default();
+            pkm.set_nickname(name);
+            pkm.set_is_nicknamed(true);
+        } else if nickname.is_empty() {
+            let name = species_name(species, Language::from(language))
+                .or_else(|| species_name(species, Language::English))
+                .unwrap_or_default();
+            pkm.set_nickname(name);
+        } else {
+            pkm.set_nickname(&nickname);
+            pkm.set_is_nicknamed(true);
+        }
+
+        pkm.set_encryption_constant(match self.encryption_constant() {
+            0 => rand(),
+            encryption_constant => encryption_constant,
+        });
+        pkm.set_pid(generate_pid(
+            self.gift_shiny(),
+            self.pid(),
+            tid16 ^ sid16,
+            rand,
+        ));
+
+        pkm.set_nature(match self.nature() {
+            nature if nature < 25 => nature,
+            _ => (rand() % 25) as u8,
+        });
+
+        let ability_number = match self.ability_type() {
+            ability_type @ 0..=2 => 1 << ability_type,
+            3 => 1 << (rand() & 1),
+            _ => 1 << (rand() % 3),
+        };
+        pkm.set_ability_number(ability_number);
+        pkm.set_ability(
+            personal
+                .ability(ability_number)
+                .map_or(0, |ability| ability as u16),
+        );
+
+        pkm.set_gender(match self.gender() {
+            gender @ 0..=2 => gender,
+            _ => random_gender(species.get_gender_ratio(), rand) as u8,
+        });
+
+        let [hp, atk, def, spe, spa, spd] = generate_ivs(self.ivs(), rand);
+        pkm.set_iv_hp(hp);
+        pkm.set_iv_atk(atk);
+        pkm.set_iv_def(def);
+        pkm.set_iv_spe(spe);
+        pkm.set_iv_spa(spa);
+        pkm.set_iv_spd(spd);
+
+        let [hp, atk, def, spe, spa, spd] = self.evs();
+        pkm.set_ev_hp(hp);
+        pkm.set_ev_atk(atk);
+        pkm.set_ev_def(def);
+        pkm.set_ev_spe(spe);
+        pkm.set_ev_spa(spa);
+        pkm.set_ev_spd(spd);
+
+        pkm.set_is_egg(is_egg);
+
+        self.apply_format(&mut pkm, rand);
+        pkm.refresh_checksum();
+
+        Ok(pkm)
+    }
+}
+
+fn generate_pid(
+    shiny: GiftShiny,
+    pid: u32,
+    tid_xor_sid: u16,
+    rand: &mut dyn FnMut() -> u32,
+) -> u32 {
+    let shiny_pid = |xor: u16, low: u32| {
+        let high = tid_xor_sid ^ (low as u16) ^ xor;
+        ((high as u32) << 16) | (low & 0xffff)
+    };
+
+    match shiny {
+        GiftShiny::Fixed => pid,
+        GiftShiny::Random => rand(),
+        GiftShiny::Never => {
+            let pid = rand();
+            let xor = tid_xor_sid ^ (pid >> 16) as u16 ^ pid as u16;
+            if xor < 16 {
+                pid ^ 0x1000_0000
+            } else {
+                pid
+            }
+        }
+        GiftShiny::Always | GiftShiny::AlwaysSquare => shiny_pid(0, rand()),
+        GiftShiny::AlwaysStar => shiny_pid(1, rand()),
+    }
+}
+
+fn generate_ivs(mut ivs: [u8; 6], rand: &mut dyn FnMut() -> u32) -> [u8; 6] {
+    if (0xfc..=0xfe).contains(&ivs[0]) {
+        let flawless = (ivs[0] - 0xfb) as usize;
+        let mut stats: Vec<usize> = (0..6).collect();
+        ivs = [0xff; 6];
+
+        for _ in 0..flawless {
+            let stat = stats.remove(rand() as usize % stats.len());
+            ivs[stat] = 31;
+        }
+    }
+
+    ivs.map(|iv| if iv > 31 { (rand() & 31) as u8 } else { iv })
+}
+
+fn random_gender(ratio: GenderRatio, rand: &mut dyn FnMut() -> u32) -> Gender {
+    let threshold = match ratio {
+        GenderRatio::Genderless => return Gender::Genderless,
+        GenderRatio::MaleOnly => return Gender::Male,
+        GenderRatio::FemaleOnly => return Gender::Female,
+        GenderRatio::OneToSeven => 31,
+        GenderRatio::OneToThree => 63,
+        GenderRatio::OneToOne => 127,
+        GenderRatio::ThreeToOne => 191,
+        GenderRatio::SevenToOne => 225,
+    };
+
+    if rand() % 253 + 1 < threshold {
+        Gender::Female
+    } else {
+        Gender::Male
+    }
+}
+
+/// Reads the `(item, quantity)` pairs starting at `offset`, up to the first
+/// empty one.
+fn read_items<T: Reader>(card: &T, offset: u64, count: u64) -> Vec<GiftItem> {
+    (0..count)
+        .map(|index| GiftItem {
+            item: card.read(offset + index * 4),
+            quantity: card.read(offset + index * 4 + 2),
+        })
+        .take_while(|gift| gift.item != 0)
+        .collect()
+}
+
+/// Reads the ribbon indexes starting at `offset`, up to the first 0xff.
+fn read_ribbons<T: Reader>(card: &T, offset: u64) -> Vec<u8> {
+    card.as_slice()[offset as usize..][..0x20]
+        .iter()
+        .copied()
+        .take_while(|ribbon| *ribbon != 0xff)
+        .collect()
+}
+
+/// Gen8 and later cards store a name per language, skipping the unused
+/// language 6.  Languages without a slot read the English one.
+fn language_slot(language: u8) -> usize {
+    match language {
+        1..=5 => language as usize - 1,
+        7..=10 => language as usize - 2,
+        _ => Language::English as usize - 1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn counter() -> impl FnMut() -> u32 {
+        let mut state = 0x1234_5678u32;
+        move || {
+            state = state.wrapping_mul(0x41c6_4e6d).wrapping_add(0x6073);
+            state
+        }
+    }
+
+    #[test]
+    fn should_generate_shiny_pids() {
+        let mut rand = counter();
+        let pid = generate_pid(GiftShiny::Always, 0, 0x1234, &mut rand);
+        assert_eq!(0x1234 ^ (pid >> 16) as u16 ^ pid as u16, 0);
+
+        let pid = generate_pid(GiftShiny::AlwaysStar, 0, 0x1234, &mut rand);
+        assert_eq!(0x1234 ^ (pid >> 16) as u16 ^ pid as u16, 1);
+    }
+
+    #[test]
+    fn should_never_generate_shiny_pids_when_locked() {
+        let mut rand = || 0x1234_1234;
+        let pid = generate_pid(GiftShiny::Never, 0, 0, &mut rand);
+        assert_eq!(pid, 0x0234_1234);
+    }
+
+    #[test]
+    fn should_generate_flawless_ivs() {
+        let mut rand = counter();
+        let ivs = generate_ivs([0xfe; 6], &mut rand);
+        assert!(ivs.iter().filter(|iv| **iv == 31).count() >= 3);
+        assert!(ivs.iter().all(|iv| *iv <= 31));
+
+        let ivs = generate_ivs([31, 0, 31, 0xff, 31, 0], &mut rand);
+        assert_eq!(ivs[..3], [31, 0, 31]);
+        assert!(ivs[3] <= 31);
+    }
+
+    #[test]
+    fn should_map_language_slots() {
+        assert_eq!(language_slot(1), 0);
+        assert_eq!(language_slot(2), 1);
+        assert_eq!(language_slot(7), 5);
+        assert_eq!(language_slot(10), 8);
+        assert_eq!(language_slot(0), 1);
+        assert_eq!(language_slot(6), 1);
+        assert_eq!(language_slot(11), 1);
+    }
+}
@@ -0,0 +1,243 @@
+use super::{read_items, GiftItem, GiftShiny, GiftType, Wondercard};
+use crate::impl_read_prop;
+use crate::pk6::Pk6;
+use crate::reader::Reader;
+use crate::strings::string_converter6;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A Gen6 wondercard, as stored in `.wc6` files.
+pub struct Wc6 {
+    data: [u8; Self::SIZE],
+}
+
+impl Reader for Wc6 {
+    fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Wc6 {
+    pub const SIZE: usize = 0x108;
+    /// The size of a `.wc6full` file, which stores the card after the
+    /// distribution text.
+    pub const FULL_SIZE: usize = 0x310;
+    const FULL_OFFSET: usize = 0x208;
+
+    pub fn new(data: [u8; Self::SIZE]) -> Self {
+        Self { data }
+    }
+
+    /// Reads a `.wc6` or `.wc6full` file.
+    pub fn from_slice(data: &[u8]) -> Option<Self> {
+        let card = match data.len() {
+            Self::SIZE => data,
+            Self::FULL_SIZE => &data[Self::FULL_OFFSET..],
+            _ => return None,
+        };
+
+        card.try_into().ok().map(Self::new)
+    }
+
+    impl_read_prop!(pub date: u32 = 0x4c);
+    impl_read_prop!(card_type: u8 = 0x51);
+    impl_read_prop!(pid_type: u8 = 0xa3);
+    impl_read_prop!(pub contest_cool: u8 = 0xa9);
+    impl_read_prop!(pub contest_beauty: u8 = 0xaa);
+    impl_read_prop!(pub contest_cute: u8 = 0xab);
+    impl_read_prop!(pub contest_smart: u8 = 0xac);
+    impl_read_prop!(pub contest_tough: u8 = 0xad);
+    impl_read_prop!(pub contest_sheen: u8 = 0xae);
+}
+
+impl Wondercard for Wc6 {
+    type Pkm = Pk6;
+
+    impl_read_prop!(card_id: u16 = 0x00);
+    impl_read_prop!(tid16: u16 = 0x68);
+    impl_read_prop!(sid16: u16 = 0x6a);
+    impl_read_prop!(origin_game: u8 = 0x6c);
+    impl_read_prop!(encryption_constant: u32 = 0x70);
+    impl_read_prop!(ball: u8 = 0x76);
+    impl_read_prop!(held_item: u16 = 0x78);
+    impl_read_prop!(move1: u16 = 0x7a);
+    impl_read_prop!(move2: u16 = 0x7c);
+    impl_read_prop!(move3: u16 = 0x7e);
+    impl_read_prop!(move4: u16 = 0x80);
+    impl_read_prop!(species: u16 = 0x82);
+    impl_read_prop!(form: u8 = 0x84);
+    impl_read_prop!(language: u8 = 0x85);
+    impl_read_prop!(nature: u8 = 0xa0);
+    impl_read_prop!(gender: u8 = 0xa1);
+    impl_read_prop!(ability_type: u8 = 0xa2);
+    impl_read_prop!(egg_location: u16 = 0xa4);
+    impl_read_prop!(met_location: u16 = 0xa6);
+    impl_read_prop!(met_level: u8 = 0xa8);
+    impl_read_prop!(ot_gender: u8 = 0xb5);
+    impl_read_prop!(level: u8 = 0xd0);
+    impl_read_prop!(pid: u32 = 0xd4);
+    impl_read_prop!(relearn_move1: u16 = 0xd8);
+    impl_read_prop!(relearn_move2: u16 = 0xda);
+    impl_read_prop!(relearn_move3: u16 = 0xdc);
+    impl_read_prop!(relearn_move4: u16 = 0xde);
+    impl_read_prop!(ot_intensity: u8 = 0xe0);
+    impl_read_prop!(ot_memory: u8 = 0xe1);
+    impl_read_prop!(ot_text_var: u16 = 0xe2);
+    impl_read_prop!(ot_feeling: u8 = 0xe4);
+
+    fn title(&self) -> Option<String> {
+        Some(string_converter6::get_string(&self.data[0x02..][..0x48]))
+    }
+
+    fn gift_type(&self) -> GiftType {
+        match self.card_type() {
+            0 => GiftType::Pokemon,
+            1 => GiftType::Item,
+            2 => GiftType::Power,
+            _ => GiftType::Unknown,
+        }
+    }
+
+    fn items(&self) -> Vec<GiftItem> {
+        if self.gift_type() != GiftType::Item {
+            return Vec::new();
+        }
+
+        read_items(self, 0x68, 1)
+    }
+
+    fn gift_shiny(&self) -> GiftShiny {
+        match self.pid_type() {
+            0 => GiftShiny::Fixed,
+            2 => GiftShiny::Always,
+            3 => GiftShiny::Never,
+            _ => GiftShiny::Random,
+        }
+    }
+
+    fn is_egg(&self) -> bool {
+        self.read::<u8>(0xd1) == 1
+    }
+
+    fn ivs(&self) -> [u8; 6] {
+        self.read_array(0xaf)
+    }
+
+    fn evs(&self) -> [u8; 6] {
+        self.read_array(0xe5)
+    }
+
+    fn nickname(&self, _language: u8) -> String {
+        string_converter6::get_string(&self.data[0x86..][..0x1a])
+    }
+
+    fn ot_name(&self, _language: u8) -> String {
+        string_converter6::get_string(&self.data[0xb6..][..0x1a])
+    }
+
+    fn apply_format(&self, pkm: &mut Pk6, _rand: &mut dyn FnMut() -> u32) {
+        pkm.set_contest_cool(self.contest_cool());
+        pkm.set_contest_beauty(self.contest_beauty());
+        pkm.set_contest_cute(self.contest_cute());
+        pkm.set_contest_smart(self.contest_smart());
+        pkm.set_contest_tough(self.contest_tough());
+        pkm.set_contest_sheen(self.contest_sheen());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkx::Pkx;
+    use crate::poke_crypto::PokeCrypto;
+    use crate::types::{Ability, GameVersion, Move, Species, TrainerInfo};
+    use crate::writer::Writer;
+    use alloc::string::ToString;
+
+    fn trainer() -> TrainerInfo {
+        TrainerInfo {
+            ot_name: "Calem".to_string(),
+            tid16: 12345,
+            sid16: 54321,
+            gender: 0,
+            language: 2,
+            version: GameVersion::X,
+        }
+    }
+
+    fn card() -> [u8; Wc6::SIZE] {
+        let mut data = [0u8; Wc6::SIZE];
+        Writer::write(&mut data, 0x00, 2048u16);
+        Writer::write(&mut data, 0x02, 0x0050u16);
+        Writer::write(&mut data, 0x82, Species::Pikachu as u16);
+        Writer::write(&mut data, 0x7a, Move::Thunderbolt as u16);
+        Writer::write(&mut data, 0xa0, 0xffu8);
+        Writer::write(&mut data, 0xa1, 3u8);
+        Writer::write(&mut data, 0xa2, 2u8);
+        Writer::write(&mut data, 0xa3, 3u8);
+        Writer::write(&mut data, 0xa6, 40005u16);
+        Writer::write(&mut data, 0xa8, 10u8);
+        Writer::write(&mut data, 0xa9, 20u8);
+        Writer::write_array(&mut data, 0xaf, &[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        Writer::write(&mut data, 0xd0, 10u8);
+        data
+    }
+
+    #[test]
+    fn should_read_cards() {
+        let wc6 = Wc6::new(card());
+        assert_eq!(wc6.card_id(), 2048);
+        assert_eq!(wc6.title(), Some("P".to_string()));
+        assert_eq!(wc6.gift_type(), GiftType::Pokemon);
+        assert_eq!(wc6.species_t(), Species::Pikachu);
+        assert_eq!(wc6.gift_shiny(), GiftShiny::Never);
+        assert!(wc6.items().is_empty());
+    }
+
+    #[test]
+    fn should_read_full_cards() {
+        let mut full = [0u8; Wc6::FULL_SIZE];
+        full[Wc6::FULL_OFFSET..].copy_from_slice(&card());
+        assert_eq!(Wc6::from_slice(&full).unwrap().card_id(), 2048);
+        assert!(Wc6::from_slice(&full[1..]).is_none());
+    }
+
+    #[test]
+    fn should_read_item_gifts() {
+        let mut data = [0u8; Wc6::SIZE];
+        Writer::write(&mut data, 0x51, 1u8);
+        Writer::write(&mut data, 0x68, 50u16);
+        Writer::write(&mut data, 0x6a, 3u16);
+        let wc6 = Wc6::new(data);
+        assert_eq!(
+            wc6.items(),
+            [GiftItem {
+                item: 50,
+                quantity: 3
+            }]
+        );
+        assert!(wc6.convert_to_pkm(&trainer(), &mut || 0).is_err());
+    }
+
+    #[test]
+    fn should_convert_to_pk6() {
+        let mut rand = || 0x0123_4567;
+        let pkm = Wc6::new(card())
+            .convert_to_pkm(&trainer(), &mut rand)
+            .unwrap();
+
+        assert!(pkm.is_valid());
+        assert_eq!(pkm.checksum(), pkm.calculate_checksum());
+        assert_eq!(pkm.species_t(), Species::Pikachu);
+        assert_eq!(pkm.exp(), 1000);
+        assert_eq!(pkm.ot_name(), "Calem");
+        assert_eq!(pkm.nickname(), "Pikachu");
+        assert_eq!(pkm.ability_t(), Ability::LightningRod);
+        assert_eq!(pkm.move1_t(), Move::Thunderbolt);
+        assert_eq!(pkm.met_location(), 40005);
+        assert_eq!(pkm.contest_cool(), 20);
+        assert_eq!(pkm.version_t(), GameVersion::X);
+        assert!(!pkm.is_shiny());
+        assert!([pkm.iv_hp(), pkm.iv_atk(), pkm.iv_def()].contains(&31));
+    }
+}
@@ -0,0 +1,295 @@
+use super::{language_slot, read_items, read_ribbons, GiftItem, GiftShiny, GiftType, Wondercard};
+use crate::impl_read_prop;
+use crate::pk8::Pk8;
+use crate::pkx::PkxMut;
+use crate::reader::Reader;
+use crate::strings::string_converter8;
+use crate::types;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A Sword and Shield wondercard, as stored in `.wc8` files.
+pub struct Wc8 {
+    data: [u8; Self::SIZE],
+}
+
+impl Reader for Wc8 {
+    fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Wc8 {
+    pub const SIZE: usize = 0x2d0;
+
+    pub fn new(data: [u8; Self::SIZE]) -> Self {
+        Self { data }
+    }
+
+    pub fn from_slice(data: &[u8]) -> Option<Self> {
+        data.try_into().ok().map(Self::new)
+    }
+
+    impl_read_prop!(pub card_flags: u8 = 0x10);
+    impl_read_prop!(card_type: u8 = 0x11);
+    impl_read_prop!(pub card_title_index: u8 = 0x15);
+    impl_read_prop!(shiny_type: u8 = 0x248);
+    impl_read_prop!(pub dynamax_level: u8 = 0x24a);
+
+    pub fn can_gigantamax(&self) -> bool {
+        self.read::<u8>(0x24b) != 0
+    }
+
+    pub fn ribbons(&self) -> impl Iterator<Item = types::Ribbon> {
+        read_ribbons(self, 0x24c)
+            .into_iter()
+            .filter_map(|ribbon| types::Ribbon::try_from(ribbon).ok())
+    }
+}
+
+impl Wondercard for Wc8 {
+    type Pkm = Pk8;
+
+    impl_read_prop!(card_id: u16 = 0x08);
+    impl_read_prop!(tid16: u16 = 0x20);
+    impl_read_prop!(sid16: u16 = 0x22);
+    impl_read_prop!(encryption_constant: u32 = 0x28);
+    impl_read_prop!(pid: u32 = 0x2c);
+    impl_read_prop!(egg_location: u16 = 0x228);
+    impl_read_prop!(met_location: u16 = 0x22a);
+    impl_read_prop!(held_item: u16 = 0x22e);
+    impl_read_prop!(move1: u16 = 0x230);
+    impl_read_prop!(move2: u16 = 0x232);
+    impl_read_prop!(move3: u16 = 0x234);
+    impl_read_prop!(move4: u16 = 0x236);
+    impl_read_prop!(relearn_move1: u16 = 0x238);
+    impl_read_prop!(relearn_move2: u16 = 0x23a);
+    impl_read_prop!(relearn_move3: u16 = 0x23c);
+    impl_read_prop!(relearn_move4: u16 = 0x23e);
+    impl_read_prop!(species: u16 = 0x240);
+    impl_read_prop!(form: u8 = 0x242);
+    impl_read_prop!(gender: u8 = 0x243);
+    impl_read_prop!(level: u8 = 0x244);
+    impl_read_prop!(nature: u8 = 0x246);
+    impl_read_prop!(ability_type: u8 = 0x247);
+    impl_read_prop!(met_level: u8 = 0x249);
+    impl_read_prop!(ot_gender: u8 = 0x272);
+    impl_read_prop!(ot_intensity: u8 = 0x279);
+    impl_read_prop!(ot_memory: u8 = 0x27a);
+    impl_read_prop!(ot_feeling: u8 = 0x27b);
+    impl_read_prop!(ot_text_var: u16 = 0x27c);
+
+    fn title(&self) -> Option<String> {
+        None
+    }
+
+    fn gift_type(&self) -> GiftType {
+        match self.card_type() {
+            1 => GiftType::Pokemon,
+            2 => GiftType::Item,
+            3 => GiftType::BattlePoints,
+            4 => GiftType::Clothing,
+            _ => GiftType::Unknown,
+        }
+    }
+
+    fn items(&self) -> Vec<GiftItem> {
+        match self.gift_type() {
+            GiftType::Item => read_items(self, 0x20, 6),
+            _ => Vec::new(),
+        }
+    }
+
+    fn battle_points(&self) -> u16 {
+        match self.gift_type() {
+            GiftType::BattlePoints => self.read(0x20),
+            _ => 0,
+        }
+    }
+
+    fn origin_game(&self) -> u8 {
+        self.read::<u32>(0x24) as u8
+    }
+
+    fn gift_shiny(&self) -> GiftShiny {
+        match self.shiny_type() {
+            0 => GiftShiny::Never,
+            2 => GiftShiny::AlwaysStar,
+            3 => GiftShiny::AlwaysSquare,
+            4 => GiftShiny::Fixed,
+            _ => GiftShiny::Random,
+        }
+    }
+
+    fn ball(&self) -> u8 {
+        self.read::<u16>(0x22c) as u8
+    }
+
+    fn is_egg(&self) -> bool {
+        self.read::<u8>(0x245) == 1
+    }
+
+    fn ivs(&self) -> [u8; 6] {
+        self.read_array(0x26c)
+    }
+
+    fn evs(&self) -> [u8; 6] {
+        self.read_array(0x273)
+    }
+
+    fn nickname(&self, language: u8) -> String {
+        let offset = 0x30 + language_slot(language) * 0x1c;
+        string_converter8::get_string(&self.data[offset..][..0x1a])
+    }
+
+    fn ot_name(&self, language: u8) -> String {
+        let offset = 0x12c + language_slot(language) * 0x1c;
+        string_converter8::get_string(&self.data[offset..][..0x1a])
+    }
+
+    fn apply_format(&self, pkm: &mut Pk8, _rand: &mut dyn FnMut() -> u32) {
+        pkm.set_dynamax_level(self.dynamax_level());
+        pkm.set_can_gigantamax(self.can_gigantamax());
+        for ribbon in self.ribbons() {
+            pkm.set_ribbon(ribbon, true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::locations;
+    use crate::pkx::Pkx;
+    use crate::poke_crypto::PokeCrypto;
+    use crate::strings::string_converter8;
+    use crate::types::{Ability, GameVersion, Species, TrainerInfo};
+    use crate::writer::Writer;
+    use alloc::string::ToString;
+
+    fn trainer() -> TrainerInfo {
+        TrainerInfo {
+            ot_name: "Gloria".to_string(),
+            tid16: 12345,
+            sid16: 54321,
+            gender: 1,
+            language: 2,
+            version: GameVersion::Shield,
+        }
+    }
+
+    fn card() -> [u8; Wc8::SIZE] {
+        let mut data = [0u8; Wc8::SIZE];
+        Writer::write(&mut data, 0x08, 9021u16);
+        Writer::write(&mut data, 0x11, 1u8);
+        Writer::write(&mut data, 0x20, 101u16);
+        Writer::write(&mut data, 0x22, 202u16);
+        Writer::write(&mut data, 0x24, GameVersion::Sword as u32);
+        string_converter8::set_string(&mut data[0x12c + 0x1c..][..0x1a], "HOME");
+        Writer::write(&mut data, 0x22c, 1u16);
+        Writer::write(&mut data, 0x240, Species::Eevee as u16);
+        Writer::write(&mut data, 0x243, 1u8);
+        Writer::write(&mut data, 0x244, 10u8);
+        Writer::write(&mut data, 0x246, 0xffu8);
+        Writer::write(&mut data, 0x247, 4u8);
+        Writer::write(&mut data, 0x248, 3u8);
+        Writer::write(&mut data, 0x249, 10u8);
+        Writer::write(&mut data, 0x24a, 5u8);
+        Writer::write(&mut data, 0x24b, 1u8);
+        data[0x24c..][..0x20].fill(0xff);
+        Writer::write(&mut data, 0x24c, types::Ribbon::Classic as u8);
+        Writer::write_array(&mut data, 0x26c, &[31, 31, 31, 0xff, 0xff, 0xff]);
+        data
+    }
+
+    #[test]
+    fn should_read_cards() {
+        let wc8 = Wc8::new(card());
+        assert_eq!(wc8.card_id(), 9021);
+        assert_eq!(wc8.title(), None);
+        assert_eq!(wc8.gift_type(), GiftType::Pokemon);
+        assert_eq!(wc8.species_t(), Species::Eevee);
+        assert_eq!(wc8.gift_shiny(), GiftShiny::AlwaysSquare);
+        assert_eq!(wc8.ot_name(2), "HOME");
+        assert_eq!(wc8.ot_name(1), "");
+        assert!(wc8.can_gigantamax());
+        assert_eq!(wc8.ribbons().collect::<Vec<_>>(), [types::Ribbon::Classic]);
+    }
+
+    #[test]
+    fn should_read_item_gifts() {
+        let mut data = [0u8; Wc8::SIZE];
+        Writer::write(&mut data, 0x11, 2u8);
+        Writer::write(&mut data, 0x20, 1u16);
+        Writer::write(&mut data, 0x22, 10u16);
+        let wc8 = Wc8::new(data);
+        assert_eq!(
+            wc8.items(),
+            [GiftItem {
+                item: 1,
+                quantity: 10
+            }]
+        );
+        assert!(Wc8::from_slice(&data[1..]).is_none());
+    }
+
+    #[test]
+    fn should_convert_to_pk8() {
+        let mut rand = || 0x0123_4567;
+        let pkm = Wc8::new(card())
+            .convert_to_pkm(&trainer(), &mut rand)
+            .unwrap();
+
+        assert!(pkm.is_valid());
+        assert_eq!(pkm.checksum(), pkm.calculate_checksum());
+        assert_eq!(pkm.species_t(), Species::Eevee);
+        assert_eq!(pkm.ot_name(), "HOME");
+        assert_eq!(pkm.ht_name(), "Gloria");
+        assert_eq!(pkm.ht_gender(), 1);
+        assert_eq!(pkm.ht_friendship(), 50);
+        assert_eq!(pkm.tid16(), 101);
+        assert_eq!(pkm.version_t(), GameVersion::Sword);
+        assert_eq!(pkm.gender_t(), types::Gender::Female);
+        assert_eq!(pkm.ability_t(), Ability::Adaptability);
+        assert_eq!(pkm.shiny_type(), Some(types::Shiny::Square));
+        assert_eq!(pkm.dynamax_level(), 5);
+        assert!(pkm.can_gigantamax());
+        assert!(pkm.has_ribbon(types::Ribbon::Classic));
+        assert_eq!([pkm.iv_hp(), pkm.iv_atk(), pkm.iv_def()], [31, 31, 31]);
+    }
+
+    #[test]
+    fn should_fill_in_species_defaults() {
+        let mut data = card();
+        Writer::write(&mut data, 0x230, types::Move::Tackle as u16);
+        let mut trainer = trainer();
+        trainer.language = types::Language::French as u8;
+
+        let pkm = Wc8::new(data)
+            .convert_to_pkm(&trainer, &mut || 0x0123_4567)
+            .unwrap();
+        assert_eq!(pkm.nickname(), "Évoli");
+        assert!(!pkm.is_nicknamed());
+        assert_eq!(pkm.move1_pp(), 35);
+        assert_eq!(pkm.move2_pp(), 0);
+        assert_eq!(pkm.ot_friendship(), 50);
+    }
+
+    #[test]
+    fn should_convert_eggs() {
+        let mut data = card();
+        Writer::write(&mut data, 0x245, 1u8);
+        let pkm = Wc8::new(data)
+            .convert_to_pkm(&trainer(), &mut || 0x0123_4567)
+            .unwrap();
+
+        assert!(pkm.is_egg());
+        assert_eq!(pkm.nickname(), "Egg");
+        assert!(pkm.is_nicknamed());
+        assert_eq!(pkm.met_level(), 1);
+        assert_eq!(pkm.exp(), 0);
+        assert_eq!(pkm.met_location(), locations::NO_LOCATION);
+        assert_eq!(pkm.egg_location(), locations::LINK_TRADE);
+        assert_eq!(pkm.ot_friendship(), 35);
+    }
+}
@@ -0,0 +1,227 @@
+use super::{language_slot, read_items, read_ribbons, GiftItem, GiftShiny, GiftType, Wondercard};
+use crate::impl_read_prop;
+use crate::pk9::Pk9;
+use crate::pkx::PkxMut;
+use crate::reader::Reader;
+use crate::strings::string_converter8;
+use crate::types;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A Scarlet and Violet wondercard, as stored in `.wc9` files.
+///
+/// The Pokemon fields sit 8 bytes earlier than in a [Wc8](crate::Wc8), and
+/// the species is stored with the Scarlet and Violet species IDs.
+pub struct Wc9 {
+    data: [u8; Self::SIZE],
+}
+
+impl Reader for Wc9 {
+    fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Wc9 {
+    pub const SIZE: usize = 0x2c8;
+
+    pub fn new(data: [u8; Self::SIZE]) -> Self {
+        Self { data }
+    }
+
+    pub fn from_slice(data: &[u8]) -> Option<Self> {
+        data.try_into().ok().map(Self::new)
+    }
+
+    impl_read_prop!(pub card_flags: u8 = 0x10);
+    impl_read_prop!(card_type: u8 = 0x11);
+    impl_read_prop!(pub card_title_index: u8 = 0x15);
+    impl_read_prop!(shiny_type: u8 = 0x240);
+    // Takes the place of the Gen8 Dynamax level.
+    impl_read_prop!(pub tera_type: u8 = 0x242);
+
+    pub fn tera_type_t(&self) -> types::TeraType {
+        self.tera_type().into()
+    }
+
+    pub fn ribbons(&self) -> impl Iterator<Item = types::Ribbon> {
+        read_ribbons(self, 0x244)
+            .into_iter()
+            .filter_map(|ribbon| types::Ribbon::try_from(ribbon).ok())
+    }
+}
+
+impl Wondercard for Wc9 {
+    type Pkm = Pk9;
+
+    impl_read_prop!(card_id: u16 = 0x08);
+    impl_read_prop!(tid16: u16 = 0x18);
+    impl_read_prop!(sid16: u16 = 0x1a);
+    impl_read_prop!(encryption_constant: u32 = 0x20);
+    impl_read_prop!(pid: u32 = 0x24);
+    impl_read_prop!(egg_location: u16 = 0x220);
+    impl_read_prop!(met_location: u16 = 0x222);
+    impl_read_prop!(held_item: u16 = 0x226);
+    impl_read_prop!(move1: u16 = 0x228);
+    impl_read_prop!(move2: u16 = 0x22a);
+    impl_read_prop!(move3: u16 = 0x22c);
+    impl_read_prop!(move4: u16 = 0x22e);
+    impl_read_prop!(relearn_move1: u16 = 0x230);
+    impl_read_prop!(relearn_move2: u16 = 0x232);
+    impl_read_prop!(relearn_move3: u16 = 0x234);
+    impl_read_prop!(relearn_move4: u16 = 0x236);
+    impl_read_prop!(species: u16 = 0x238);
+    impl_read_prop!(form: u8 = 0x23a);
+    impl_read_prop!(gender: u8 = 0x23b);
+    impl_read_prop!(level: u8 = 0x23c);
+    impl_read_prop!(nature: u8 = 0x23e);
+    impl_read_prop!(ability_type: u8 = 0x23f);
+    impl_read_prop!(met_level: u8 = 0x241);
+    impl_read_prop!(ot_gender: u8 = 0x26a);
+    impl_read_prop!(ot_intensity: u8 = 0x271);
+    impl_read_prop!(ot_memory: u8 = 0x272);
+    impl_read_prop!(ot_feeling: u8 = 0x273);
+    impl_read_prop!(ot_text_var: u16 = 0x274);
+
+    fn title(&self) -> Option<String> {
+        None
+    }
+
+    fn gift_type(&self) -> GiftType {
+        match self.card_type() {
+            1 => GiftType::Pokemon,
+            2 => GiftType::Item,
+            3 => GiftType::Clothing,
+            _ => GiftType::Unknown,
+        }
+    }
+
+    fn items(&self) -> Vec<GiftItem> {
+        match self.gift_type() {
+            GiftType::Item => read_items(self, 0x18, 6),
+            _ => Vec::new(),
+        }
+    }
+
+    fn origin_game(&self) -> u8 {
+        self.read::<u32>(0x1c) as u8
+    }
+
+    fn gift_shiny(&self) -> GiftShiny {
+        match self.shiny_type() {
+            0 => GiftShiny::Never,
+            2 => GiftShiny::AlwaysStar,
+            3 => GiftShiny::AlwaysSquare,
+            4 => GiftShiny::Fixed,
+            _ => GiftShiny::Random,
+        }
+    }
+
+    fn ball(&self) -> u8 {
+        self.read::<u16>(0x224) as u8
+    }
+
+    fn is_egg(&self) -> bool {
+        self.read::<u8>(0x23d) == 1
+    }
+
+    fn ivs(&self) -> [u8; 6] {
+        self.read_array(0x264)
+    }
+
+    fn evs(&self) -> [u8; 6] {
+        self.read_array(0x26b)
+    }
+
+    fn nickname(&self, language: u8) -> String {
+        let offset = 0x28 + language_slot(language) * 0x1c;
+        string_converter8::get_string(&self.data[offset..][..0x1a])
+    }
+
+    fn ot_name(&self, language: u8) -> String {
+        let offset = 0x124 + language_slot(language) * 0x1c;
+        string_converter8::get_string(&self.data[offset..][..0x1a])
+    }
+
+    fn apply_format(&self, pkm: &mut Pk9, rand: &mut dyn FnMut() -> u32) {
+        pkm.set_tera_type_original(self.tera_type());
+        pkm.set_tera_type_override(types::TeraType::OVERRIDE_NONE);
+        pkm.set_height_scalar(rand() as u8);
+        pkm.set_weight_scalar(rand() as u8);
+        pkm.set_scale(rand() as u8);
+        for ribbon in self.ribbons() {
+            pkm.set_ribbon(ribbon, true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkx::Pkx;
+    use crate::poke_crypto::PokeCrypto;
+    use crate::types::{GameVersion, Species, TrainerInfo};
+    use crate::writer::Writer;
+    use alloc::string::ToString;
+
+    fn trainer() -> TrainerInfo {
+        TrainerInfo {
+            ot_name: "Juliana".to_string(),
+            tid16: 12345,
+            sid16: 54321,
+            gender: 1,
+            language: 2,
+            version: GameVersion::Violet,
+        }
+    }
+
+    fn card() -> [u8; Wc9::SIZE] {
+        let mut data = [0u8; Wc9::SIZE];
+        Writer::write(&mut data, 0x08, 1u16);
+        Writer::write(&mut data, 0x11, 1u8);
+        string_converter8::set_string(&mut data[0x28 + 0x1c..][..0x1a], "Flash");
+        Writer::write(&mut data, 0x238, Species::Pikachu as u16);
+        Writer::write(&mut data, 0x23b, 3u8);
+        Writer::write(&mut data, 0x23c, 25u8);
+        Writer::write(&mut data, 0x23e, 5u8);
+        Writer::write(&mut data, 0x23f, 1u8);
+        Writer::write(&mut data, 0x241, 25u8);
+        Writer::write(&mut data, 0x242, types::TeraType::Flying as u8);
+        data[0x244..][..0x20].fill(0xff);
+        Writer::write_array(&mut data, 0x264, &[0xfc, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        data
+    }
+
+    #[test]
+    fn should_read_cards() {
+        let wc9 = Wc9::new(card());
+        assert_eq!(wc9.card_id(), 1);
+        assert_eq!(wc9.gift_type(), GiftType::Pokemon);
+        assert_eq!(wc9.species_t(), Species::Pikachu);
+        assert_eq!(wc9.tera_type_t(), types::TeraType::Flying);
+        assert_eq!(wc9.gift_shiny(), GiftShiny::Never);
+        assert_eq!(wc9.nickname(2), "Flash");
+        assert_eq!(wc9.ribbons().count(), 0);
+    }
+
+    #[test]
+    fn should_convert_to_pk9() {
+        let mut rand = || 0x0123_4567;
+        let pkm = Wc9::new(card())
+            .convert_to_pkm(&trainer(), &mut rand)
+            .unwrap();
+
+        assert!(pkm.is_valid());
+        assert_eq!(pkm.checksum(), pkm.calculate_checksum());
+        assert_eq!(pkm.species_t(), Species::Pikachu);
+        assert_eq!(pkm.nickname(), "Flash");
+        assert!(pkm.is_nicknamed());
+        assert_eq!(pkm.ot_name(), "Juliana");
+        assert_eq!(pkm.current_handler(), 0);
+        assert_eq!(pkm.nature_t(), types::Nature::Bold);
+        assert_eq!(pkm.ability_t(), types::Ability::Static);
+        assert_eq!(pkm.tera_type(), types::TeraType::Flying);
+        assert_eq!(pkm.version_t(), GameVersion::Violet);
+        assert!(!pkm.is_shiny());
+    }
+}
@@ -0,0 +1,91 @@
+use alloc::vec::Vec;
+use binrw::io::Cursor;
+use binrw::{BinWrite, BinWriterExt};
+
+pub trait Writer {
+    fn as_mut_slice(&mut self) -> &mut [u8];
+
+    fn write<T>(&mut self, offset: u64, value: T)
+    where
+        T: BinWrite,
+        for<'a> <T as BinWrite>::Args<'a>: Default,
+    {
+        let mut cursor = Cursor::new(Vec::new());
+        if cursor.write_le(&value).is_ok() {
+            self.write_array(offset, &cursor.into_inner());
+        }
+    }
+
+    /// Copies `value` to `offset`, leaving the data untouched if it doesn't fit.
+    fn write_array(&mut self, offset: u64, value: &[u8]) {
+        let start = offset as usize;
+        let end = start + value.len();
+        if let Some(dst) = self.as_mut_slice().get_mut(start..end) {
+            dst.copy_from_slice(value);
+        }
+    }
+
+    /// Sets bit `index` of a little endian bitfield starting at `offset`.
+    fn write_flag(&mut self, offset: u64, index: usize, value: bool) {
+        let data = self.as_mut_slice();
+        let Some(byte) = data.get_mut(offset as usize + (index >> 3)) else {
+            return;
+        };
+
+        let mask = 1 << (index & 7);
+        if value {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+}
+
+impl<T> Writer for T
+where
+    T: AsMut<[u8]>,
+{
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.as_mut()
+    }
+}
+
+#[macro_export]
+macro_rules! impl_write_prop {
+    ($vis:vis $name:ident : $type:ty = $address:expr) => {
+        $vis fn $name(&mut self, value: $type) {
+            self.write($address, value)
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reader::Reader;
+
+    #[test]
+    fn should_write_little_endian_values() {
+        let mut data = [0u8; 8];
+        Writer::write(&mut data, 2, 0x12345678u32);
+        assert_eq!(data, [0, 0, 0x78, 0x56, 0x34, 0x12, 0, 0]);
+    }
+
+    #[test]
+    fn should_ignore_out_of_bounds_writes() {
+        let mut data = [0u8; 4];
+        Writer::write(&mut data, 3, 0xffffu16);
+        Writer::write(&mut data, 10, 1u8);
+        assert_eq!(data, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn should_write_flags() {
+        let mut data = [0u8; 4];
+        data.write_flag(1, 9, true);
+        assert!(data.read_flag(1, 9));
+        assert_eq!(data, [0, 0, 2, 0]);
+        data.write_flag(1, 9, false);
+        assert_eq!(data, [0, 0, 0, 0]);
+    }
+}